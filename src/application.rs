@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
+use std::rc::Rc;
 use std::sync::OnceLock;
 
 use adw::prelude::*;
@@ -13,6 +14,8 @@ use log::debug;
 use log::info;
 
 use crate::config;
+use crate::model;
+use crate::utils;
 use crate::view;
 
 mod imp {
@@ -81,6 +84,16 @@ mod imp {
             app.main_window().present();
         }
 
+        fn open(&self, files: &[gio::File], _hint: &str) {
+            debug!("GtkApplication<Application>::open");
+
+            let app = self.obj();
+            app.activate();
+
+            let window = app.main_window();
+            files.iter().for_each(|file| window.open_uri(&file.uri()));
+        }
+
         fn startup(&self) {
             debug!("GtkApplication<Application>::startup");
             self.parent_startup();
@@ -109,7 +122,7 @@ impl Default for Application {
     fn default() -> Self {
         glib::Object::builder()
             .property("application-id", Some(config::APP_ID))
-            .property("flags", gio::ApplicationFlags::empty())
+            .property("flags", gio::ApplicationFlags::HANDLES_OPEN)
             .property("resource-base-path", Some("/com/github/marhkb/Pods/"))
             .build()
     }
@@ -154,6 +167,24 @@ impl Application {
                     app.show_about_dialog();
                 })
                 .build(),
+            // Show container (used by notifications)
+            gio::ActionEntry::builder("show-container")
+                .parameter_type(Some(&<(String, String)>::static_variant_type()))
+                .activate(|app: &Self, _, data| {
+                    let (connection_uuid, id) = data.unwrap().get::<(String, String)>().unwrap();
+                    app.activate();
+                    app.main_window().show_container(&connection_uuid, &id);
+                })
+                .build(),
+            // Show pod (used by notifications)
+            gio::ActionEntry::builder("show-pod")
+                .parameter_type(Some(&<(String, String)>::static_variant_type()))
+                .activate(|app: &Self, _, data| {
+                    let (connection_uuid, id) = data.unwrap().get::<(String, String)>().unwrap();
+                    app.activate();
+                    app.main_window().show_pod(&connection_uuid, &id);
+                })
+                .build(),
         ]);
     }
 
@@ -219,6 +250,102 @@ impl Application {
         dialog.present(Some(&self.main_window()));
     }
 
+    /// Handles the command line options that are processed without showing a window. Returns the
+    /// exit code or `-1` if the application should continue to start up.
+    pub(crate) fn handle_command_line_options(&self, dict: &glib::VariantDict) -> i32 {
+        let connection_name = dict.lookup::<String>("connection").unwrap();
+        let args = dict.lookup::<Vec<String>>("").unwrap().unwrap_or_default();
+
+        if dict.contains("list-connections") {
+            return list_connections();
+        }
+
+        if let Some(target) = dict.lookup::<String>("open").unwrap() {
+            return self.open_from_command_line(connection_name.as_deref(), &target);
+        }
+
+        let scheme = format!("{}:", model::DEEP_LINK_SCHEME);
+        if !args.is_empty() && args.iter().all(|arg| arg.starts_with(&scheme)) {
+            return self.open_uris_from_command_line(&args);
+        }
+
+        match args.as_slice() {
+            [] => -1,
+            [action, container] if matches!(action.as_str(), "start" | "stop" | "restart") => {
+                run_container_action(connection_name.as_deref(), action, container)
+            }
+            _ => {
+                eprintln!(
+                    "{}",
+                    gettext("Usage: pods [--connection NAME] start|stop|restart CONTAINER")
+                );
+                1
+            }
+        }
+    }
+
+    /// Asks the running instance (or this one, if there is none) to show the details page of a
+    /// container or pod, given as `container/ID` or `pod/ID`.
+    fn open_from_command_line(&self, connection_name: Option<&str>, target: &str) -> i32 {
+        let (action, id) = match target.split_once('/') {
+            Some(("container", id)) if !id.is_empty() => ("show-container", id),
+            Some(("pod", id)) if !id.is_empty() => ("show-pod", id),
+            _ => {
+                eprintln!("{}", gettext!("Invalid page '{}'", target));
+                eprintln!("{}", gettext("Expected 'container/ID' or 'pod/ID'"));
+                return 1;
+            }
+        };
+
+        let connection_manager = model::ConnectionManager::default();
+        let connection_uuid = match lookup_connection(&connection_manager, connection_name) {
+            Ok(connection) => connection.uuid(),
+            Err(code) => return code,
+        };
+
+        if let Err(e) = self.register(None::<&gio::Cancellable>) {
+            eprintln!("{}", gettext!("Error on registering application: {}", e));
+            return 1;
+        }
+
+        self.activate_action(action, Some(&(connection_uuid, id.to_owned()).to_variant()));
+
+        if self.is_remote() {
+            // Make sure the request reaches the running instance before this one exits.
+            if let Some(dbus_connection) = self.dbus_connection() {
+                let _ = dbus_connection.flush_sync(None::<&gio::Cancellable>);
+            }
+            0
+        } else {
+            -1
+        }
+    }
+
+    /// Hands `pods://` links over to the running instance (or this one, if there is none).
+    fn open_uris_from_command_line(&self, uris: &[String]) -> i32 {
+        if let Err(e) = self.register(None::<&gio::Cancellable>) {
+            eprintln!("{}", gettext!("Error on registering application: {}", e));
+            return 1;
+        }
+
+        self.open(
+            &uris
+                .iter()
+                .map(|uri| gio::File::for_uri(uri))
+                .collect::<Vec<_>>(),
+            "",
+        );
+
+        if self.is_remote() {
+            if let Some(dbus_connection) = self.dbus_connection() {
+                let _ = dbus_connection.flush_sync(None::<&gio::Cancellable>);
+            }
+            0
+        } else {
+            -1
+        }
+    }
+
     pub(crate) fn run(&self) {
         info!("Pods ({})", config::APP_ID);
         info!("Version: {} ({})", config::VERSION, config::PROFILE);
@@ -227,3 +354,126 @@ impl Application {
         ApplicationExtManual::run(self);
     }
 }
+
+/// Prints all connections, marking the one that was used last.
+fn list_connections() -> i32 {
+    let connection_manager = model::ConnectionManager::default();
+    if let Err(e) = connection_manager.load() {
+        eprintln!("{}", gettext!("Error on loading connections: {}", e));
+        return 1;
+    }
+
+    let last_used_connection = connection_manager.last_used_connection();
+
+    connection_manager
+        .iter::<model::Connection>()
+        .map(Result::unwrap)
+        .for_each(|connection| {
+            println!(
+                "{} {}\t{}",
+                if connection.uuid() == last_used_connection {
+                    '*'
+                } else {
+                    ' '
+                },
+                connection.name(),
+                connection.url()
+            );
+        });
+
+    0
+}
+
+/// Returns the connection with the given name or the last used one if no name is given. On
+/// failure, the error is printed and the exit code is returned.
+fn lookup_connection(
+    connection_manager: &model::ConnectionManager,
+    connection_name: Option<&str>,
+) -> Result<model::Connection, i32> {
+    if let Err(e) = connection_manager.load() {
+        eprintln!("{}", gettext!("Error on loading connections: {}", e));
+        return Err(1);
+    }
+
+    match connection_name {
+        Some(name) => connection_manager.connection_by_name(name).ok_or_else(|| {
+            eprintln!("{}", gettext!("Unknown connection '{}'", name));
+            1
+        }),
+        None => connection_manager
+            .connection_by_uuid(&connection_manager.last_used_connection())
+            .or_else(|| connection_manager.item(0).and_downcast())
+            .ok_or_else(|| {
+                eprintln!("{}", gettext("No connection has been set up yet"));
+                1
+            }),
+    }
+}
+
+/// Starts, stops or restarts a container and waits until Podman has finished.
+fn run_container_action(connection_name: Option<&str>, action: &str, container: &str) -> i32 {
+    let connection_manager = model::ConnectionManager::default();
+    let connection = match lookup_connection(&connection_manager, connection_name) {
+        Ok(connection) => connection,
+        Err(code) => return code,
+    };
+
+    let main_loop = glib::MainLoop::new(None, false);
+    let exit_code = Rc::new(Cell::new(0));
+
+    connection_manager.connect_to(
+        &connection.uuid(),
+        clone!(
+            @weak connection_manager,
+            @strong main_loop,
+            @strong exit_code,
+            @to-owned action,
+            @to-owned container
+            => move |result| {
+                let client = match result.and_then(|_| {
+                    connection_manager
+                        .client_by_uuid(&connection.uuid())
+                        .ok_or_else(|| anyhow::anyhow!("client not found"))
+                }) {
+                    Ok(client) => client,
+                    Err(e) => {
+                        eprintln!("{}", gettext!("Error on connecting to Podman: {}", e));
+                        exit_code.set(1);
+                        main_loop.quit();
+                        return;
+                    }
+                };
+
+                let api = client.podman().containers().get(&container);
+                let action = action.clone();
+
+                utils::do_async(
+                    async move {
+                        match action.as_str() {
+                            "start" => api.start(None).await,
+                            "stop" => api.stop(&Default::default()).await,
+                            _ => api.restart().await,
+                        }
+                    },
+                    clone!(@strong main_loop, @strong exit_code, @strong container => move |result| {
+                        match result {
+                            Ok(_) => println!("{container}"),
+                            Err(e) => {
+                                eprintln!(
+                                    "{}",
+                                    gettext!("Error on container '{}': {}", container, e)
+                                );
+                                exit_code.set(1);
+                            }
+                        }
+                        main_loop.quit();
+                    }),
+                );
+            }
+        ),
+    );
+
+    main_loop.run();
+
+    exit_code.get()
+}
@@ -32,10 +32,19 @@ fn runtime() -> &'static tokio::runtime::Runtime {
 }
 
 fn main() {
+    // Pods is its own `SSH_ASKPASS` program to hand stored passphrases over to ssh.
+    if let Some(socket_path) = std::env::var_os(model::SSH_ASKPASS_ENV) {
+        if let Err(e) = model::ssh_askpass(std::path::Path::new(&socket_path)) {
+            eprintln!("Error on reading SSH passphrase: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let app = setup_cli(Application::default());
 
     // Command line handling
-    app.connect_handle_local_options(|_, dict| {
+    app.connect_handle_local_options(|app, dict| {
         if dict.contains("version") {
             // Print version ...
             println!("pods {}", config::VERSION);
@@ -126,7 +135,7 @@ fn main() {
 
             APPLICATION_OPTS.set(ApplicationOptions::default()).unwrap();
 
-            -1
+            app.handle_command_line_options(dict)
         }
     });
 
@@ -174,6 +183,43 @@ fn setup_cli(app: Application) -> Application {
         Some("error|warn|info|debug|trace"),
     );
 
+    app.add_main_option(
+        "connection",
+        b'c'.into(),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("The connection to use instead of the last used one"),
+        Some("NAME"),
+    );
+
+    app.add_main_option(
+        "open",
+        b'\0'.into(),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::String,
+        &gettext("Show the details page of a container or pod"),
+        Some("container/ID|pod/ID"),
+    );
+
+    app.add_main_option(
+        "list-connections",
+        b'\0'.into(),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::None,
+        &gettext("List all connections and exit"),
+        None,
+    );
+
+    // The positional arguments are collected under the empty name (`G_OPTION_REMAINING`).
+    app.add_main_option(
+        "",
+        b'\0'.into(),
+        glib::OptionFlags::NONE,
+        glib::OptionArg::StringArray,
+        &gettext("Start, stop or restart a container and exit"),
+        Some("start|stop|restart CONTAINER"),
+    );
+
     app
 }
 
@@ -219,6 +265,10 @@ fn init() {
     view::ImagesRow::static_type();
     view::InfoPanel::static_type();
     view::InfoRow::static_type();
+    view::NetworkRow::static_type();
+    view::NetworksPanel::static_type();
+    view::NetworksPrunePage::static_type();
+    view::NetworksRow::static_type();
     view::PodMenuButton::static_type();
     view::PodRow::static_type();
     view::PodSelectionPage::static_type();
@@ -230,9 +280,13 @@ fn init() {
     view::RepoTagRow::static_type();
     view::RepoTagSelectionPage::static_type();
     view::RepoTagSimpleRow::static_type();
+    view::ResourcesHistory::static_type();
     view::ScalableTextViewPage::static_type();
     view::SearchPanel::static_type();
     view::SearchRow::static_type();
+    view::SecretRow::static_type();
+    view::SecretsPanel::static_type();
+    view::SecretsRow::static_type();
     view::VolumeRow::static_type();
     view::VolumesGroup::static_type();
     view::VolumesPanel::static_type();
@@ -251,6 +305,7 @@ fn init() {
     widget::ScalableTextView::static_type();
     widget::SourceViewSearchWidget::static_type();
     widget::Spinner::static_type();
+    widget::StatsChart::static_type();
     widget::TextSearchEntry::static_type();
     widget::ZoomControl::static_type();
 }
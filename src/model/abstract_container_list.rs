@@ -64,6 +64,9 @@ mod imp {
                     glib::ParamSpecUInt::builder("running").read_only().build(),
                     glib::ParamSpecUInt::builder("stopped").read_only().build(),
                     glib::ParamSpecUInt::builder("stopping").read_only().build(),
+                    glib::ParamSpecUInt::builder("unhealthy")
+                        .read_only()
+                        .build(),
                 ]
             })
         }
@@ -87,6 +90,11 @@ impl AbstractContainerList {
                 clone!(@weak list => move |_, _| Self::notify_num_containers(&list)),
             );
 
+            container.connect_notify_local(
+                Some("health-status"),
+                clone!(@weak list => move |_, _| list.notify("unhealthy")),
+            );
+
             container.connect_notify_local(
                 Some("name"),
                 clone!(@weak list => move |container, _| {
@@ -109,6 +117,7 @@ impl AbstractContainerList {
         list.notify("running");
         list.notify("stopped");
         list.notify("stopping");
+        list.notify("unhealthy");
     }
 }
 
@@ -2,22 +2,28 @@ use std::borrow::Cow;
 use std::cell::Cell;
 use std::cell::OnceCell;
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::ffi::OsStr;
 use std::mem;
 use std::path::PathBuf;
+use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use adw::prelude::*;
 use futures::stream;
+use futures::stream::BoxStream;
 use futures::StreamExt;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use gio::subclass::prelude::*;
 use glib::clone;
 use glib::Properties;
 use gtk::gio;
 use gtk::glib;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
 
 use crate::model;
 use crate::model::AbstractContainerListExt;
@@ -34,6 +40,8 @@ pub(crate) enum State {
     Failed,
 }
 
+type ComposeStep = Box<dyn FnOnce(&Action, &model::Client) -> Option<Action>>;
+
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
 #[enum_type(name = "ActionType")]
 pub(crate) enum Type {
@@ -48,8 +56,19 @@ pub(crate) enum Type {
     CopyFiles,
     PrunePods,
     Pod,
+    PlayKube,
     Volume,
     PruneVolumes,
+    Network,
+    PruneNetworks,
+    Secret,
+    Compose,
+    SaveImages,
+    LoadImages,
+    ExportContainer,
+    ImportImage,
+    Checkpoint,
+    Restore,
     #[default]
     Undefined,
 }
@@ -648,6 +667,85 @@ impl Action {
         })
     }
 
+    pub(crate) fn play_kubernetes_yaml(
+        num: u32,
+        file_name: &str,
+        client: model::Client,
+        opts: podman::opts::PlayKubernetesYamlOpts,
+        yaml: String,
+    ) -> Self {
+        let obj = Self::new(
+            num,
+            Type::PlayKube,
+            &gettext!("Play Kubernetes YAML <b>{}</b>", file_name),
+        );
+        let abort_registration = obj.setup_abort_handle();
+
+        obj.insert_line(&gettext("Creating pods, containers and volumes…"));
+
+        utils::do_async(
+            {
+                let podman = client.podman();
+                async move {
+                    stream::Abortable::new(
+                        podman.play_kubernetes_yaml(&opts, yaml),
+                        abort_registration,
+                    )
+                    .await
+                }
+            },
+            clone!(@weak obj, @weak client => move |result| if let Ok(result) = result {
+                match result {
+                    Ok(report) => {
+                        obj.insert_line(&serde_json::to_string_pretty(&report).unwrap());
+
+                        let pod_id = report
+                            .pods
+                            .unwrap_or_default()
+                            .into_iter()
+                            .find_map(|pod| pod.id);
+
+                        match pod_id {
+                            Some(id) => match client.pod_list().get_pod(&id) {
+                                Some(pod) => {
+                                    obj.set_artifact(pod.upcast_ref());
+                                    obj.set_state(State::Finished);
+                                }
+                                None => {
+                                    let pod_list = client.pod_list();
+                                    let handler = Rc::new(RefCell::new(None));
+                                    handler.replace(Some(pod_list.connect_pod_added(
+                                        clone!(@weak obj, @strong id, @strong handler => move |pod_list, pod| {
+                                            if pod.id() == id.as_str() {
+                                                obj.set_artifact(pod.upcast_ref());
+                                                obj.set_state(State::Finished);
+
+                                                if let Some(handler) = handler.take() {
+                                                    pod_list.disconnect(handler);
+                                                }
+                                            }
+                                        }),
+                                    )));
+                                }
+                            },
+                            None => {
+                                obj.insert_line(&gettext("Finished"));
+                                obj.set_state(State::Finished);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        log::error!("Error on playing Kubernetes YAML: {e}");
+                        obj.insert_line(&e.to_string());
+                        obj.set_state(State::Failed);
+                    }
+                }
+            }),
+        );
+
+        obj
+    }
+
     pub(crate) fn prune_pods(num: u32, client: model::Client) -> Self {
         let obj = Self::new(num, Type::PrunePods, &gettext("Prune stopped pods"));
         let abort_registration = obj.setup_abort_handle();
@@ -916,6 +1014,656 @@ impl Action {
 
         obj
     }
+
+    pub(crate) fn create_network(
+        num: u32,
+        name: &str,
+        client: model::Client,
+        opts: podman::opts::NetworkCreateOpts,
+    ) -> Self {
+        let obj = Self::new(
+            num,
+            Type::Network,
+            &gettext!("Create network <b>{}</b>", name),
+        );
+
+        let abort_registration = obj.setup_abort_handle();
+        utils::do_async(
+            {
+                let podman = client.podman();
+                async move {
+                    stream::Abortable::new(podman.networks().create(&opts), abort_registration)
+                        .await
+                }
+            },
+            clone!(@weak obj, @weak client => move |result| if let Ok(result) = result {
+                match result.map(|network| network.name.unwrap_or_default()) {
+                    Ok(name) => {
+                        match client.network_list().get_network(&name) {
+                            Some(network) => {
+                                obj.set_artifact(network.upcast_ref());
+                                obj.set_state(State::Finished);
+                            }
+                            None => {
+                                client.network_list().connect_network_added(
+                                    clone!(@weak obj, @strong name => move |_, network| {
+                                        if network.name() == name {
+                                            obj.set_artifact(network.upcast_ref());
+                                            obj.set_state(State::Finished);
+                                        }
+                                    }),
+                                );
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        log::error!("Error on creating network: {e}");
+                        obj.insert_line(&e.to_string());
+                        obj.set_state(State::Failed);
+                    }
+                }
+            }),
+        );
+
+        obj
+    }
+
+    pub(crate) fn prune_networks(
+        num: u32,
+        client: model::Client,
+        opts: podman::opts::NetworkPruneOpts,
+    ) -> Self {
+        let obj = Self::new(num, Type::PruneNetworks, &gettext("Prune unused networks"));
+        let abort_registration = obj.setup_abort_handle();
+
+        utils::do_async(
+            {
+                let podman = client.podman();
+                async move {
+                    stream::Abortable::new(podman.networks().prune(&opts), abort_registration).await
+                }
+            },
+            clone!(@weak obj => move |result| if let Ok(result) = result {
+                let output = obj.output();
+                let mut start_iter = output.start_iter();
+                match result.as_ref() {
+                    Ok(report) => {
+                        output.insert(&mut start_iter, &serde_json::to_string_pretty(&report).unwrap());
+                        obj.set_state(State::Finished);
+                    },
+                    Err(e) => {
+                        output.insert(&mut start_iter, &e.to_string());
+                        obj.set_state(State::Failed);
+                    }
+                }
+            }),
+        );
+
+        obj
+    }
+
+    pub(crate) fn create_secret(
+        num: u32,
+        name: &str,
+        client: model::Client,
+        opts: podman::opts::SecretCreateOpts,
+        data: String,
+    ) -> Self {
+        let obj = Self::new(
+            num,
+            Type::Secret,
+            &gettext!("Create secret <b>{}</b>", name),
+        );
+
+        let abort_registration = obj.setup_abort_handle();
+        utils::do_async(
+            {
+                let podman = client.podman();
+                async move {
+                    stream::Abortable::new(podman.secrets().create(&opts, data), abort_registration)
+                        .await
+                }
+            },
+            clone!(@weak obj, @weak client => move |result| if let Ok(result) = result {
+                match result.map(|secret| secret.id().to_string()) {
+                    Ok(id) => {
+                        match client.secret_list().get_secret(&id) {
+                            Some(secret) => {
+                                obj.set_artifact(secret.upcast_ref());
+                                obj.set_state(State::Finished);
+                            }
+                            None => {
+                                client.secret_list().connect_secret_added(
+                                    clone!(@weak obj, @strong id => move |_, secret| {
+                                        if secret.id() == id {
+                                            obj.set_artifact(secret.upcast_ref());
+                                            obj.set_state(State::Finished);
+                                        }
+                                    }),
+                                );
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        log::error!("Error on creating secret: {e}");
+                        obj.insert_line(&e.to_string());
+                        obj.set_state(State::Failed);
+                    }
+                }
+            }),
+        );
+
+        obj
+    }
+}
+
+impl Action {
+    pub(crate) fn save_images(
+        num: u32,
+        client: model::Client,
+        references: Vec<String>,
+        format: &str,
+        path: PathBuf,
+    ) -> Self {
+        let obj = Self::new(
+            num,
+            Type::SaveImages,
+            &match references.as_slice() {
+                [reference] => gettext!(
+                    "Save image <b>{}</b> to <b>{}</b>",
+                    reference,
+                    path.display()
+                ),
+                _ => ngettext!(
+                    "Save {} image to <b>{}</b>",
+                    "Save {} images to <b>{}</b>",
+                    references.len() as u32,
+                    references.len(),
+                    path.display()
+                ),
+            },
+        );
+
+        let opts = podman::opts::ImagesExportOpts::builder()
+            .references(references)
+            .format(format)
+            .build();
+
+        obj.write_to_file_(
+            client.podman().images(),
+            move |images| images.export(&opts).boxed(),
+            path,
+        )
+    }
+
+    pub(crate) fn load_images(num: u32, client: model::Client, path: PathBuf) -> Self {
+        let obj = Self::new(
+            num,
+            Type::LoadImages,
+            &gettext!("Load images from <b>{}</b>", path.display()),
+        );
+
+        obj.insert_line(&gettext("Loading images…"));
+
+        obj.upload_archive_(
+            &client,
+            "images/load",
+            None,
+            path,
+            |obj, report: podman::models::ImageLoadReport| {
+                report.names.unwrap_or_default().iter().for_each(|name| {
+                    obj.insert_line(&gettext!("Loaded image {}", name));
+                });
+                obj.insert_line(&gettext("Finished"));
+                obj.set_state(State::Finished);
+            },
+        )
+    }
+
+    pub(crate) fn export_container(num: u32, container: &model::Container, path: PathBuf) -> Self {
+        Self::new(
+            num,
+            Type::ExportContainer,
+            &gettext!(
+                "Export container <b>{}</b> to <b>{}</b>",
+                container.name(),
+                path.display()
+            ),
+        )
+        .write_to_file_(
+            container.api().unwrap(),
+            |container| container.export().boxed(),
+            path,
+        )
+    }
+
+    pub(crate) fn import_image(
+        num: u32,
+        client: model::Client,
+        path: PathBuf,
+        reference: Option<String>,
+    ) -> Self {
+        let obj = Self::new(
+            num,
+            Type::ImportImage,
+            &gettext!("Import image from <b>{}</b>", path.display()),
+        );
+
+        obj.insert_line(&gettext("Importing filesystem archive…"));
+
+        let mut opts = podman::opts::ImageImportOpts::builder();
+        if let Some(reference) = reference {
+            opts = opts.reference(reference);
+        }
+        let image_list = client.image_list();
+
+        obj.upload_archive_(
+            &client,
+            "images/import",
+            opts.build().serialize(),
+            path,
+            move |obj, report: podman::models::ImageImportReport| {
+                let id = report.id.unwrap_or_default();
+                match image_list.get_image(&id) {
+                    Some(image) => {
+                        obj.set_artifact(image.upcast_ref());
+                        obj.set_state(State::Finished);
+                    }
+                    None => {
+                        image_list.connect_image_added(
+                            clone!(@weak obj, @strong id => move |_, image| {
+                                if image.id() == id.as_str() {
+                                    obj.set_artifact(image.upcast_ref());
+                                    obj.set_state(State::Finished);
+                                }
+                            }),
+                        );
+                    }
+                }
+            },
+        )
+    }
+
+    pub(crate) fn checkpoint_container(
+        num: u32,
+        container: &model::Container,
+        path: PathBuf,
+        opts: podman::opts::ContainerCheckpointOpts,
+    ) -> Self {
+        Self::new(
+            num,
+            Type::Checkpoint,
+            &gettext!(
+                "Checkpoint container <b>{}</b> to <b>{}</b>",
+                container.name(),
+                path.display()
+            ),
+        )
+        .write_to_file_(
+            container.api().unwrap(),
+            move |container| container.checkpoint_export(&opts).boxed(),
+            path,
+        )
+    }
+
+    pub(crate) fn restore_container(
+        num: u32,
+        name: &str,
+        client: model::Client,
+        path: PathBuf,
+        opts: podman::opts::ContainerRestoreOpts,
+    ) -> Self {
+        let obj = Self::new(
+            num,
+            Type::Restore,
+            &gettext!(
+                "Restore container <b>{}</b> from <b>{}</b>",
+                name,
+                path.display()
+            ),
+        );
+
+        obj.insert_line(&gettext("Restoring checkpoint…"));
+
+        let name = name.to_owned();
+        let container_list = client.container_list();
+
+        obj.upload_archive_(
+            &client,
+            "containers/import",
+            opts.serialize(),
+            path,
+            move |obj, _: serde_json::Value| {
+                let container = container_list
+                    .iter::<model::Container>()
+                    .map(Result::unwrap)
+                    .find(|container| container.name() == name);
+
+                match container {
+                    Some(container) => {
+                        obj.set_artifact(container.upcast_ref());
+                        obj.set_state(State::Finished);
+                    }
+                    None => {
+                        container_list.connect_container_added(
+                            clone!(@weak obj, @strong name => move |_, container| {
+                                if container.name() == name {
+                                    obj.set_artifact(container.upcast_ref());
+                                    obj.set_state(State::Finished);
+                                }
+                            }),
+                        );
+                    }
+                }
+            },
+        )
+    }
+
+    /// Sends the archive file to the given libpod endpoint and reports the progress. `op` is called
+    /// with the response once Podman has received the whole archive.
+    fn upload_archive_<T, F>(
+        self,
+        client: &model::Client,
+        endpoint_path: &'static str,
+        query: Option<String>,
+        path: PathBuf,
+        op: F,
+    ) -> Self
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: FnOnce(&Self, T) + 'static,
+    {
+        let endpoint = match client.archive_upload_endpoint() {
+            Ok(endpoint) => endpoint,
+            Err(e) => {
+                self.insert_line(&e.to_string());
+                self.set_state(State::Failed);
+                return self;
+            }
+        };
+
+        let abort_registration = self.setup_abort_handle();
+
+        self.insert_line(&gettext!("Sent: {}", glib::format_size(0)));
+
+        let op = RefCell::new(Some(op));
+
+        utils::run_stream(
+            endpoint,
+            move |endpoint| {
+                stream::Abortable::new(
+                    model::upload_archive(endpoint.clone(), endpoint_path, query, path),
+                    abort_registration,
+                )
+                .boxed()
+            },
+            clone!(
+                @weak self as obj => @default-return glib::ControlFlow::Break,
+                move |result: anyhow::Result<model::ArchiveUploadEvent<T>>|
+            {
+                match result {
+                    Ok(model::ArchiveUploadEvent::Sent(sent)) => {
+                        obj.replace_last_line(&gettext!("Sent: {}", glib::format_size(sent)));
+                        glib::ControlFlow::Continue
+                    }
+                    Ok(model::ArchiveUploadEvent::Finished(response)) => {
+                        if let Some(op) = op.take() {
+                            op(&obj, response);
+                        }
+                        glib::ControlFlow::Break
+                    }
+                    Err(e) => {
+                        log::error!("Error on sending archive: {e}");
+                        obj.insert_line(&e.to_string());
+                        obj.set_state(State::Failed);
+                        glib::ControlFlow::Break
+                    }
+                }
+            }),
+        );
+
+        self
+    }
+
+    /// Writes the archive produced by the stream to the given file. The file is removed again if
+    /// the action fails or is aborted.
+    fn write_to_file_<A, P>(self, api_entity: A, stream_producer: P, path: PathBuf) -> Self
+    where
+        A: Send + 'static,
+        for<'r> P: FnOnce(&'r A) -> BoxStream<'r, podman::Result<Vec<u8>>> + Send + 'static,
+    {
+        let abort_registration = self.setup_abort_handle();
+
+        self.insert_line(&gettext!("Size: {}", glib::format_size(0)));
+
+        let size = Cell::new(0_u64);
+
+        utils::run_stream_with_finish_handler(
+            api_entity,
+            {
+                let path = path.clone();
+                move |api_entity| {
+                    let archive =
+                        stream::Abortable::new(stream_producer(api_entity), abort_registration);
+
+                    // The file is created off the main thread just before the archive is written.
+                    stream::once(async move { (tokio::fs::File::create(path).await, archive) })
+                        .flat_map(|(file, archive)| match file {
+                            Ok(file) => {
+                                let file = Arc::new(tokio::sync::Mutex::new(file));
+
+                                archive
+                                    .then({
+                                        let file = file.clone();
+                                        move |result| {
+                                            let file = file.clone();
+                                            async move {
+                                                let chunk = result.map_err(|e| e.to_string())?;
+                                                file.lock()
+                                                    .await
+                                                    .write_all(&chunk)
+                                                    .await
+                                                    .map_err(|e| e.to_string())?;
+                                                Ok(chunk.len())
+                                            }
+                                        }
+                                    })
+                                    .chain(stream::once(async move {
+                                        file.lock()
+                                            .await
+                                            .flush()
+                                            .await
+                                            .map(|_| 0)
+                                            .map_err(|e| e.to_string())
+                                    }))
+                                    .boxed()
+                            }
+                            Err(e) => stream::iter([Err(e.to_string())]).boxed(),
+                        })
+                        .boxed()
+                }
+            },
+            clone!(
+                @weak self as obj => @default-return glib::ControlFlow::Break,
+                move |result: Result<usize, String>|
+            {
+                match result {
+                    Ok(len) => {
+                        size.set(size.get() + len as u64);
+                        obj.replace_last_line(&gettext!("Size: {}", glib::format_size(size.get())));
+                        glib::ControlFlow::Continue
+                    }
+                    Err(e) => {
+                        log::error!("Error on writing archive: {e}");
+                        obj.insert_line(&e);
+                        obj.set_state(State::Failed);
+                        glib::ControlFlow::Break
+                    }
+                }
+            }),
+            clone!(@weak self as obj => move || {
+                if obj.state() == State::Ongoing {
+                    obj.insert_line(&gettext("Finished"));
+                    obj.set_state(State::Finished);
+                } else {
+                    let path = path.clone();
+                    crate::runtime().spawn(async move { tokio::fs::remove_file(path).await });
+                }
+            }),
+        );
+
+        self
+    }
+}
+
+impl Action {
+    /// Brings up a compose project. The volumes, networks, the pod and the containers are each
+    /// created by their own action. These actions are run one after another so that every
+    /// service can rely on the entities it depends on.
+    pub(crate) fn compose_up(
+        num: u32,
+        client: model::Client,
+        project: model::ComposeProject,
+        start: bool,
+    ) -> Self {
+        let obj = Self::new(
+            num,
+            Type::Compose,
+            &gettext!("Bring up compose stack <b>{}</b>", project.name),
+        );
+        // The registration is never used. The handle only serves to make the action cancelable.
+        obj.setup_abort_handle();
+
+        let mut steps: VecDeque<ComposeStep> = VecDeque::new();
+
+        project.volumes.iter().for_each(|volume| {
+            let name = project.scoped_name(volume);
+            let opts = project.volume_opts(volume);
+            steps.push_back(Box::new(move |obj, client| {
+                if client.volume_list().get_volume(&name).is_some() {
+                    obj.insert_line(&gettext!("Volume {} already exists", name));
+                    None
+                } else {
+                    Some(client.action_list().create_volume(&name, opts))
+                }
+            }));
+        });
+
+        project.networks.iter().for_each(|network| {
+            let name = project.scoped_name(&network.name);
+            let opts = project.network_opts(network);
+            steps.push_back(Box::new(move |obj, client| {
+                if client.network_list().get_network(&name).is_some() {
+                    obj.insert_line(&gettext!("Network {} already exists", name));
+                    None
+                } else {
+                    Some(client.action_list().create_network(&name, opts))
+                }
+            }));
+        });
+
+        {
+            let name = project.pod_name();
+            let opts = project.pod_opts();
+            steps.push_back(Box::new(move |_, client| {
+                Some(client.action_list().create_pod(&name, opts))
+            }));
+        }
+
+        project.services.iter().for_each(|service| {
+            let name = project.container_name(service);
+            let pull_opts = podman::opts::PullOpts::builder()
+                .reference(service.image.as_str())
+                .policy(podman::opts::PullPolicy::Missing)
+                .build();
+            let create_opts = project.container_opts(service);
+            steps.push_back(Box::new(move |_, client| {
+                Some(client.action_list().create_container_download_image(
+                    &name,
+                    pull_opts,
+                    create_opts,
+                    start,
+                ))
+            }));
+        });
+
+        // The first step must not be issued before this action has been added to the action list.
+        glib::idle_add_local_once(clone!(@weak obj, @weak client => move || {
+            obj.compose_next_step(client, project.pod_name(), steps);
+        }));
+
+        obj
+    }
+
+    fn compose_next_step(
+        &self,
+        client: model::Client,
+        pod_name: String,
+        mut steps: VecDeque<ComposeStep>,
+    ) {
+        if self.state() != State::Ongoing {
+            return;
+        }
+
+        let step = match steps.pop_front() {
+            Some(step) => step,
+            None => {
+                let pod = client
+                    .pod_list()
+                    .iter::<model::Pod>()
+                    .map(Result::unwrap)
+                    .find(|pod| pod.name() == pod_name);
+
+                match pod {
+                    Some(pod) => self.set_artifact(pod.upcast_ref()),
+                    None => self.insert_line(&gettext("Finished")),
+                }
+                self.set_state(State::Finished);
+
+                return;
+            }
+        };
+
+        let action = match step(self, &client) {
+            Some(action) => action,
+            None => {
+                self.compose_next_step(client, pod_name, steps);
+                return;
+            }
+        };
+
+        self.insert_line(&gettext!(
+            "Started action #{}: {}",
+            action.num(),
+            action.description().replace("<b>", "").replace("</b>", "")
+        ));
+
+        self.connect_notify_local(
+            Some("state"),
+            clone!(@weak action => move |obj, _| {
+                if obj.state() == State::Aborted && action.state() == State::Ongoing {
+                    action.cancel();
+                }
+            }),
+        );
+
+        let steps = RefCell::new(Some(steps));
+        action.connect_notify_local(
+            Some("state"),
+            clone!(@weak self as obj, @weak client => move |action, _| match action.state() {
+                State::Ongoing => {}
+                State::Finished => {
+                    if let Some(steps) = steps.take() {
+                        obj.compose_next_step(client, pod_name.clone(), steps);
+                    }
+                }
+                State::Aborted | State::Failed => {
+                    obj.insert_line(&gettext!("Action #{} did not succeed", action.num()));
+                    obj.set_state(State::Failed);
+                }
+            }),
+        );
+    }
 }
 
 impl Action {
@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cell::RefCell;
+use std::path::PathBuf;
 use std::sync::OnceLock;
 
 use gio::prelude::*;
@@ -334,6 +335,21 @@ impl ActionList {
         ))
     }
 
+    pub(crate) fn play_kubernetes_yaml(
+        &self,
+        file_name: &str,
+        opts: podman::opts::PlayKubernetesYamlOpts,
+        yaml: String,
+    ) -> model::Action {
+        self.insert_action(model::Action::play_kubernetes_yaml(
+            self.imp().action_counter.get(),
+            file_name,
+            self.client().unwrap(),
+            opts,
+            yaml,
+        ))
+    }
+
     pub(crate) fn create_volume(
         &self,
         name: &str,
@@ -355,6 +371,124 @@ impl ActionList {
         ))
     }
 
+    pub(crate) fn create_network(
+        &self,
+        name: &str,
+        opts: podman::opts::NetworkCreateOpts,
+    ) -> model::Action {
+        self.insert_action(model::Action::create_network(
+            self.imp().action_counter.get(),
+            name,
+            self.client().unwrap(),
+            opts,
+        ))
+    }
+
+    pub(crate) fn prune_networks(&self, opts: podman::opts::NetworkPruneOpts) -> model::Action {
+        self.insert_action(model::Action::prune_networks(
+            self.imp().action_counter.get(),
+            self.client().unwrap(),
+            opts,
+        ))
+    }
+
+    pub(crate) fn create_secret(
+        &self,
+        name: &str,
+        opts: podman::opts::SecretCreateOpts,
+        data: String,
+    ) -> model::Action {
+        self.insert_action(model::Action::create_secret(
+            self.imp().action_counter.get(),
+            name,
+            self.client().unwrap(),
+            opts,
+            data,
+        ))
+    }
+
+    pub(crate) fn compose_up(&self, project: model::ComposeProject, start: bool) -> model::Action {
+        self.insert_action(model::Action::compose_up(
+            self.imp().action_counter.get(),
+            self.client().unwrap(),
+            project,
+            start,
+        ))
+    }
+
+    pub(crate) fn save_images(
+        &self,
+        references: Vec<String>,
+        format: &str,
+        path: PathBuf,
+    ) -> model::Action {
+        self.insert_action(model::Action::save_images(
+            self.imp().action_counter.get(),
+            self.client().unwrap(),
+            references,
+            format,
+            path,
+        ))
+    }
+
+    pub(crate) fn load_images(&self, path: PathBuf) -> model::Action {
+        self.insert_action(model::Action::load_images(
+            self.imp().action_counter.get(),
+            self.client().unwrap(),
+            path,
+        ))
+    }
+
+    pub(crate) fn export_container(
+        &self,
+        container: &model::Container,
+        path: PathBuf,
+    ) -> model::Action {
+        self.insert_action(model::Action::export_container(
+            self.imp().action_counter.get(),
+            container,
+            path,
+        ))
+    }
+
+    pub(crate) fn import_image(&self, path: PathBuf, reference: Option<String>) -> model::Action {
+        self.insert_action(model::Action::import_image(
+            self.imp().action_counter.get(),
+            self.client().unwrap(),
+            path,
+            reference,
+        ))
+    }
+
+    pub(crate) fn checkpoint_container(
+        &self,
+        container: &model::Container,
+        path: PathBuf,
+        opts: podman::opts::ContainerCheckpointOpts,
+    ) -> model::Action {
+        self.insert_action(model::Action::checkpoint_container(
+            self.imp().action_counter.get(),
+            container,
+            path,
+            opts,
+        ))
+    }
+
+    pub(crate) fn restore_container(
+        &self,
+        name: &str,
+        path: PathBuf,
+        opts: podman::opts::ContainerRestoreOpts,
+    ) -> model::Action {
+        self.insert_action(model::Action::restore_container(
+            self.imp().action_counter.get(),
+            name,
+            self.client().unwrap(),
+            path,
+            opts,
+        ))
+    }
+
     fn insert_action(&self, action: model::Action) -> model::Action {
         let imp = self.imp();
 
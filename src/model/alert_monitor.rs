@@ -0,0 +1,382 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+
+use gettextrs::gettext;
+use gio::prelude::*;
+use glib::clone;
+use glib::subclass::prelude::*;
+use glib::subclass::Signal;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use indexmap::IndexMap;
+
+use crate::model;
+use crate::utils;
+
+const ACTION_SHOW_CONTAINER: &str = "app.show-container";
+const ACTION_SHOW_POD: &str = "app.show-pod";
+
+/// The file that keeps the rules of all connections.
+const FILE_NAME: &str = "alert-rules.json";
+
+/// The evaluation state of a rule for a single container or pod.
+#[derive(Debug, Default)]
+pub(super) struct RuleState {
+    since: Option<i64>,
+    fired: bool,
+    starts: VecDeque<i64>,
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::AlertMonitor)]
+    pub(crate) struct AlertMonitor {
+        pub(super) rules: RefCell<Vec<model::AlertRule>>,
+        pub(super) states: RefCell<HashMap<(String, String), RuleState>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for AlertMonitor {
+        const NAME: &'static str = "AlertMonitor";
+        type Type = super::AlertMonitor;
+    }
+
+    impl ObjectImpl for AlertMonitor {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("rules-changed").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+            let client = obj.client().unwrap();
+
+            match load_from_disk(&client.connection().uuid()) {
+                Ok(rules) => {
+                    self.rules.replace(rules);
+                }
+                Err(e) => log::error!("Error on loading alert rules: {e}"),
+            }
+
+            client.container_list().connect_container_added(
+                clone!(@weak obj => move |_, container| {
+                    obj.watch_container(container);
+                }),
+            );
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct AlertMonitor(ObjectSubclass<imp::AlertMonitor>);
+}
+
+impl From<&model::Client> for AlertMonitor {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl AlertMonitor {
+    pub(crate) fn rules_of(
+        &self,
+        subject: model::AlertSubject,
+        name: &str,
+    ) -> Vec<model::AlertRule> {
+        self.imp()
+            .rules
+            .borrow()
+            .iter()
+            .filter(|rule| rule.subject == subject && rule.name == name)
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn add_rule(&self, rule: model::AlertRule) {
+        self.imp().rules.borrow_mut().push(rule);
+        self.on_rules_changed();
+    }
+
+    pub(crate) fn remove_rule(&self, id: &str) {
+        let imp = self.imp();
+        imp.rules.borrow_mut().retain(|rule| rule.id != id);
+        imp.states
+            .borrow_mut()
+            .retain(|(rule_id, _), _| rule_id != id);
+        self.on_rules_changed();
+    }
+
+    pub(crate) fn connect_rules_changed<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("rules-changed", true, move |values| {
+            f(&values[0].get::<Self>().unwrap());
+            None
+        })
+    }
+
+    fn on_rules_changed(&self) {
+        self.emit_by_name::<()>("rules-changed", &[]);
+        self.sync_to_disk();
+    }
+
+    fn sync_to_disk(&self) {
+        if let Some(client) = self.client() {
+            let uuid = client.connection().uuid();
+            let rules = self.imp().rules.borrow().clone();
+
+            utils::do_async(
+                // Other connections keep their rules in the same file.
+                utils::update_config_map(
+                    FILE_NAME,
+                    move |all_rules: &mut IndexMap<String, Vec<model::AlertRule>>| {
+                        if rules.is_empty() {
+                            all_rules.shift_remove(&uuid);
+                        } else {
+                            all_rules.insert(uuid, rules);
+                        }
+                    },
+                ),
+                |result| {
+                    if let Err(e) = result {
+                        log::error!("Error on saving alert rules: {e}");
+                    }
+                },
+            );
+        }
+    }
+
+    fn watch_container(&self, container: &model::Container) {
+        container.connect_notify_local(
+            Some("stats"),
+            clone!(@weak self as obj => move |container, _| obj.check_usage(container)),
+        );
+        container.connect_notify_local(
+            Some("health-status"),
+            clone!(@weak self as obj => move |container, _| obj.check_health(container)),
+        );
+        container.connect_notify_local(
+            Some("status"),
+            clone!(@weak self as obj => move |container, _| match container.status() {
+                model::ContainerStatus::Exited if container.exit_code() != 0 => {
+                    obj.check_events(container, |condition| {
+                        matches!(condition, model::AlertCondition::NonZeroExit)
+                    });
+                }
+                model::ContainerStatus::Running => obj.check_restarts(container),
+                _ => {}
+            }),
+        );
+    }
+
+    fn matching_rules(&self, container: &model::Container) -> Vec<model::AlertRule> {
+        self.imp()
+            .rules
+            .borrow()
+            .iter()
+            .filter(|rule| rule.matches(container))
+            .cloned()
+            .collect()
+    }
+
+    fn check_usage(&self, container: &model::Container) {
+        let now = glib::real_time() / 1_000_000;
+
+        self.matching_rules(container).into_iter().for_each(|rule| {
+            let (key, cpu, mem_usage) = match rule.subject {
+                model::AlertSubject::Container => (
+                    container.id(),
+                    container.stats().and_then(|stats| stats.cpu),
+                    container.stats().and_then(|stats| stats.mem_usage),
+                ),
+                // A pod is measured by the sum of its containers.
+                model::AlertSubject::Pod => {
+                    let pod = container.pod().unwrap();
+                    let stats = pod
+                        .container_list()
+                        .iter::<model::Container>()
+                        .map(Result::unwrap)
+                        .filter_map(|container| container.stats())
+                        .collect::<Vec<_>>();
+
+                    (
+                        pod.id(),
+                        Some(stats.iter().filter_map(|stats| stats.cpu).sum()),
+                        Some(stats.iter().filter_map(|stats| stats.mem_usage).sum()),
+                    )
+                }
+            };
+
+            // Podman sums up the usage of all cores, whereas the threshold refers to the whole
+            // machine.
+            let cpus = self
+                .client()
+                .map(|client| client.cpus())
+                .filter(|cpus| *cpus > 0)
+                .unwrap_or(1) as f64;
+
+            let (exceeded, min_seconds) = match rule.condition {
+                model::AlertCondition::Cpu { percent, seconds } => {
+                    (cpu.is_some_and(|cpu| cpu / cpus > percent), seconds as i64)
+                }
+                model::AlertCondition::Memory { bytes } => {
+                    (mem_usage.is_some_and(|mem_usage| mem_usage > bytes), 0)
+                }
+                _ => return,
+            };
+
+            let fire = {
+                let mut states = self.imp().states.borrow_mut();
+                let state = states.entry((rule.id.clone(), key.clone())).or_default();
+
+                if exceeded {
+                    let since = *state.since.get_or_insert(now);
+                    if !state.fired && now - since >= min_seconds {
+                        state.fired = true;
+                        true
+                    } else {
+                        false
+                    }
+                } else {
+                    state.since = None;
+                    state.fired = false;
+                    false
+                }
+            };
+
+            if fire {
+                self.send_notification(&rule, &key, container);
+            }
+        });
+    }
+
+    fn check_events<P>(&self, container: &model::Container, predicate: P)
+    where
+        P: Fn(&model::AlertCondition) -> bool,
+    {
+        self.matching_rules(container)
+            .into_iter()
+            .filter(|rule| predicate(&rule.condition))
+            .for_each(|rule| self.send_notification(&rule, &container.id(), container));
+    }
+
+    /// The health status is notified after every health check, so only the transition to
+    /// unhealthy is reported.
+    fn check_health(&self, container: &model::Container) {
+        let unhealthy = container.health_status() == model::ContainerHealthStatus::Unhealthy;
+
+        self.matching_rules(container)
+            .into_iter()
+            .filter(|rule| matches!(rule.condition, model::AlertCondition::Unhealthy))
+            .for_each(|rule| {
+                let fire = {
+                    let mut states = self.imp().states.borrow_mut();
+                    let state = states.entry((rule.id.clone(), container.id())).or_default();
+
+                    let fire = unhealthy && !state.fired;
+                    state.fired = unhealthy;
+                    fire
+                };
+
+                if fire {
+                    self.send_notification(&rule, &container.id(), container);
+                }
+            });
+    }
+
+    fn check_restarts(&self, container: &model::Container) {
+        let now = glib::real_time() / 1_000_000;
+
+        self.matching_rules(container).into_iter().for_each(|rule| {
+            if let model::AlertCondition::RestartLoop { restarts, seconds } = rule.condition {
+                let fire = {
+                    let mut states = self.imp().states.borrow_mut();
+                    let state = states.entry((rule.id.clone(), container.id())).or_default();
+
+                    state.starts.push_back(now);
+                    while state
+                        .starts
+                        .front()
+                        .is_some_and(|start| now - start > seconds as i64)
+                    {
+                        state.starts.pop_front();
+                    }
+
+                    if state.starts.len() >= restarts as usize {
+                        state.starts.clear();
+                        true
+                    } else {
+                        false
+                    }
+                };
+
+                if fire {
+                    self.send_notification(&rule, &container.id(), container);
+                }
+            }
+        });
+    }
+
+    fn send_notification(&self, rule: &model::AlertRule, key: &str, container: &model::Container) {
+        let Some(client) = self.client() else {
+            return;
+        };
+
+        let (title, action, id) = match rule.subject {
+            model::AlertSubject::Container => (
+                gettext!("Container {}", container.name()),
+                ACTION_SHOW_CONTAINER,
+                container.id(),
+            ),
+            model::AlertSubject::Pod => (
+                gettext!("Pod {}", rule.name),
+                ACTION_SHOW_POD,
+                container.pod().map(|pod| pod.id()).unwrap_or_default(),
+            ),
+        };
+
+        let notification = gio::Notification::new(&title);
+        notification.set_body(Some(&gettext!(
+            "{} on {}",
+            rule.condition,
+            client.connection().name()
+        )));
+        notification.set_icon(&gio::ThemedIcon::new("dialog-warning-symbolic"));
+        notification.set_priority(gio::NotificationPriority::High);
+
+        let target = (client.connection().uuid(), id).to_variant();
+        notification.set_default_action_and_target_value(action, Some(&target));
+        notification.add_button_with_target_value(&gettext("Show Details"), action, Some(&target));
+
+        if let Some(app) = gio::Application::default() {
+            app.send_notification(Some(&format!("alert-{}-{key}", rule.id)), &notification);
+        }
+    }
+}
+
+fn load_from_disk(connection_uuid: &str) -> anyhow::Result<Vec<model::AlertRule>> {
+    utils::load_config_map::<Vec<model::AlertRule>>(FILE_NAME)
+        .map(|mut all_rules| all_rules.shift_remove(connection_uuid).unwrap_or_default())
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use gtk::glib;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::model;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum AlertSubject {
+    Container,
+    Pod,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub(crate) enum AlertCondition {
+    /// The CPU usage, relative to all cores of the machine, is above `percent` for at least
+    /// `seconds`.
+    Cpu { percent: f64, seconds: u32 },
+    /// The memory usage is above `bytes`.
+    Memory { bytes: u64 },
+    /// The health check reported an unhealthy container.
+    Unhealthy,
+    /// A container exited with a non-zero exit code.
+    NonZeroExit,
+    /// A container was started at least `restarts` times within `seconds`.
+    RestartLoop { restarts: u32, seconds: u32 },
+}
+
+impl fmt::Display for AlertCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cpu { percent, seconds } => write!(
+                f,
+                "{}",
+                ngettext!(
+                    "CPU above {} % for {} second",
+                    "CPU above {} % for {} seconds",
+                    *seconds,
+                    percent,
+                    seconds
+                )
+            ),
+            Self::Memory { bytes } => write!(
+                f,
+                "{}",
+                gettext!("Memory above {}", glib::format_size(*bytes))
+            ),
+            Self::Unhealthy => write!(f, "{}", gettext("Health check failed")),
+            Self::NonZeroExit => write!(f, "{}", gettext("Exited with an error")),
+            Self::RestartLoop { restarts, seconds } => write!(
+                f,
+                "{}",
+                ngettext!(
+                    "Started {} times within {} second",
+                    "Started {} times within {} seconds",
+                    *seconds,
+                    restarts,
+                    seconds
+                )
+            ),
+        }
+    }
+}
+
+/// A user defined rule that triggers a desktop notification for a container or a pod. Rules
+/// refer to names instead of ids so that they survive the recreation of a container.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AlertRule {
+    pub(crate) id: String,
+    pub(crate) subject: AlertSubject,
+    pub(crate) name: String,
+    pub(crate) condition: AlertCondition,
+}
+
+impl AlertRule {
+    pub(crate) fn new(subject: AlertSubject, name: String, condition: AlertCondition) -> Self {
+        Self {
+            id: glib::uuid_string_random().to_string(),
+            subject,
+            name,
+            condition,
+        }
+    }
+
+    pub(crate) fn matches(&self, container: &model::Container) -> bool {
+        match self.subject {
+            AlertSubject::Container => container.name() == self.name,
+            AlertSubject::Pod => container.pod().is_some_and(|pod| pod.name() == self.name),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use futures::stream;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use openssl::ssl::SslConnector;
+use openssl::ssl::SslFiletype;
+use openssl::ssl::SslMethod;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::io::AsyncReadExt;
+
+use crate::model;
+use crate::podman;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Where the Podman service of a client can be reached. podman-api only accepts request bodies
+/// that are completely held in memory, so archives are sent without it.
+#[derive(Clone, Debug)]
+pub(crate) enum ArchiveUploadEndpoint {
+    Unix(PathBuf),
+    Tcp(String),
+    Tls {
+        authority: String,
+        cert_dir: PathBuf,
+    },
+}
+
+impl ArchiveUploadEndpoint {
+    /// Parses URLs like `unix:///run/podman/podman.sock` and `tcp://localhost:8080`.
+    pub(crate) fn new(url: &str, cert_dir: Option<PathBuf>) -> anyhow::Result<Self> {
+        if let Some(path) = url.strip_prefix("unix://") {
+            Ok(Self::Unix(PathBuf::from(path)))
+        } else if let Some(authority) = url.strip_prefix("tcp://") {
+            let authority = authority.trim_end_matches('/').to_owned();
+            Ok(match cert_dir {
+                Some(cert_dir) => Self::Tls {
+                    authority,
+                    cert_dir,
+                },
+                None => Self::Tcp(authority),
+            })
+        } else {
+            Err(anyhow!("Unsupported URL '{url}'"))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum ArchiveUploadEvent<T> {
+    /// The number of bytes of the archive that have been sent so far.
+    Sent(u64),
+    /// The response of Podman after the whole archive has been received.
+    Finished(T),
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
+/// Posts the archive to the given libpod endpoint, e.g. `images/load`. The file is read chunk by
+/// chunk while it is sent. Dropping the stream cancels the upload.
+pub(crate) fn upload_archive<T>(
+    endpoint: ArchiveUploadEndpoint,
+    path: &str,
+    query: Option<String>,
+    archive: PathBuf,
+) -> BoxStream<'static, anyhow::Result<ArchiveUploadEvent<T>>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    // Podman only serves versioned paths, so use the same version as podman-api does.
+    let path_and_query = format!("/v{}/libpod/{path}", podman::LATEST_API_VERSION);
+    let path_and_query = match query {
+        Some(query) => format!("{path_and_query}?{query}"),
+        None => path_and_query,
+    };
+
+    let (tx_sent, rx_sent) = tokio::sync::mpsc::unbounded_channel();
+
+    let sent = stream::unfold(rx_sent, |mut rx_sent| async move {
+        rx_sent
+            .recv()
+            .await
+            .map(|sent| (Ok(ArchiveUploadEvent::Sent(sent)), rx_sent))
+    });
+
+    let finished = stream::once(post(endpoint, path_and_query, archive, tx_sent))
+        .map(|result| result.map(ArchiveUploadEvent::Finished));
+
+    stream::select(sent, finished).boxed()
+}
+
+async fn post<T: DeserializeOwned>(
+    endpoint: ArchiveUploadEndpoint,
+    path_and_query: String,
+    archive: PathBuf,
+    tx_sent: tokio::sync::mpsc::UnboundedSender<u64>,
+) -> anyhow::Result<T> {
+    let file = tokio::fs::File::open(&archive).await?;
+    let len = file.metadata().await?.len();
+
+    let body = stream::unfold((file, 0_u64), move |(mut file, sent)| {
+        let tx_sent = tx_sent.clone();
+        async move {
+            let mut buf = vec![0; CHUNK_SIZE];
+            match file.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(n) => {
+                    buf.truncate(n);
+                    let sent = sent + n as u64;
+                    _ = tx_sent.send(sent);
+                    Some((Ok::<_, std::io::Error>(buf), (file, sent)))
+                }
+                Err(e) => Some((Err(e), (file, sent))),
+            }
+        }
+    });
+
+    let request = hyper::Request::post(uri(&endpoint, &path_and_query)?)
+        .header(hyper::header::CONTENT_TYPE, "application/x-tar")
+        .header(hyper::header::CONTENT_LENGTH, len)
+        .body(hyper::Body::wrap_stream(body))?;
+
+    let response = send(&endpoint, request).await?;
+    let status = response.status();
+    let buf = hyper::body::to_bytes(response.into_body()).await?;
+
+    if status.is_success() {
+        serde_json::from_slice(&buf).map_err(anyhow::Error::from)
+    } else {
+        Err(match serde_json::from_slice::<ErrorResponse>(&buf) {
+            Ok(error) => anyhow!(error.message),
+            Err(_) => anyhow!("{status}"),
+        })
+    }
+}
+
+fn uri(endpoint: &ArchiveUploadEndpoint, path_and_query: &str) -> anyhow::Result<hyper::Uri> {
+    Ok(match endpoint {
+        ArchiveUploadEndpoint::Unix(socket_path) => {
+            hyperlocal::Uri::new(socket_path, path_and_query).into()
+        }
+        ArchiveUploadEndpoint::Tcp(authority) => {
+            format!("http://{authority}{path_and_query}").parse()?
+        }
+        ArchiveUploadEndpoint::Tls { authority, .. } => {
+            format!("https://{authority}{path_and_query}").parse()?
+        }
+    })
+}
+
+async fn send(
+    endpoint: &ArchiveUploadEndpoint,
+    request: hyper::Request<hyper::Body>,
+) -> anyhow::Result<hyper::Response<hyper::Body>> {
+    let response = match endpoint {
+        ArchiveUploadEndpoint::Unix(_) => {
+            hyper::Client::builder()
+                .build::<_, hyper::Body>(hyperlocal::UnixConnector)
+                .request(request)
+                .await
+        }
+        ArchiveUploadEndpoint::Tcp(_) => hyper::Client::new().request(request).await,
+        ArchiveUploadEndpoint::Tls { cert_dir, .. } => {
+            // The same certificates that podman-api has been set up with.
+            let mut ssl = SslConnector::builder(SslMethod::tls())?;
+            ssl.set_ca_file(cert_dir.join("ca.pem"))?;
+            ssl.set_certificate_file(cert_dir.join("cert.pem"), SslFiletype::PEM)?;
+            ssl.set_private_key_file(cert_dir.join("key.pem"), SslFiletype::PEM)?;
+
+            let mut http = hyper::client::HttpConnector::new();
+            http.enforce_http(false);
+
+            hyper::Client::builder()
+                .build::<_, hyper::Body>(hyper_openssl::HttpsConnector::with_connector(http, ssl)?)
+                .request(request)
+                .await
+        }
+    };
+
+    response.map_err(model::connect_error)
+}
@@ -1,4 +1,7 @@
+use std::cell::Cell;
 use std::cell::OnceCell;
+use std::cell::RefCell;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
@@ -10,6 +13,9 @@ use glib::subclass::prelude::*;
 use glib::Properties;
 use gtk::gio;
 use gtk::glib;
+use gtk::prelude::GtkApplicationExt;
+use gtk::prelude::GtkWindowExt;
+use gtk::prelude::WidgetExt;
 
 use crate::model;
 use crate::model::AbstractContainerListExt;
@@ -17,8 +23,8 @@ use crate::monad_boxed_type;
 use crate::podman;
 use crate::utils;
 
-/// Sync interval in seconds
-const SYNC_INTERVAL: u32 = 15;
+/// The maximum delay in seconds between two reconnection attempts.
+const MAX_RECONNECT_DELAY: u32 = 60;
 
 monad_boxed_type!(pub(crate) BoxedPodman(podman::Podman) impls Debug);
 
@@ -28,6 +34,8 @@ pub(crate) enum ClientError {
     Containers,
     Pods,
     Volumes,
+    Networks,
+    Secrets,
 }
 
 mod imp {
@@ -36,6 +44,9 @@ mod imp {
     #[derive(Debug, Default, Properties)]
     #[properties(wrapper_type = super::Client)]
     pub(crate) struct Client {
+        pub(super) settings: utils::PodsSettings,
+        pub(super) reconciliation_source: RefCell<Option<glib::SourceId>>,
+        pub(super) ssh_tunnel: RefCell<Option<model::SshTunnel>>,
         #[property(get, set, construct_only)]
         pub(super) connection: OnceCell<model::Connection>,
         #[property(get, set, construct_only)]
@@ -44,6 +55,18 @@ mod imp {
         pub(super) version: OnceCell<Option<String>>,
         #[property(get = Self::cpus, nullable)]
         pub(super) cpus: OnceCell<i64>,
+        #[property(get)]
+        pub(super) rootless: Cell<bool>,
+        /// Whether the lists have been loaded and the event listener has been started.
+        #[property(get)]
+        pub(super) started: Cell<bool>,
+        /// Whether the event stream was lost and the client tries to reconnect.
+        #[property(get)]
+        pub(super) reconnecting: Cell<bool>,
+        /// The seconds until the next reconnection attempt. It is 0 while an attempt is running.
+        #[property(get)]
+        pub(super) reconnect_countdown: Cell<u32>,
+        pub(super) reconnect_attempts: Cell<u32>,
         #[property(get = Self::image_list)]
         pub(super) image_list: OnceCell<model::ImageList>,
         #[property(get = Self::container_list)]
@@ -52,8 +75,14 @@ mod imp {
         pub(super) pod_list: OnceCell<model::PodList>,
         #[property(get = Self::volume_list)]
         pub(super) volume_list: OnceCell<model::VolumeList>,
+        #[property(get = Self::network_list)]
+        pub(super) network_list: OnceCell<model::NetworkList>,
+        #[property(get = Self::secret_list)]
+        pub(super) secret_list: OnceCell<model::SecretList>,
         #[property(get = Self::action_list)]
         pub(super) action_list: OnceCell<model::ActionList>,
+        #[property(get = Self::alert_monitor)]
+        pub(super) alert_monitor: OnceCell<model::AlertMonitor>,
     }
 
     #[glib::object_subclass]
@@ -106,6 +135,26 @@ mod imp {
                         pod.container_list().add_container(container);
                     }
 
+                    container
+                        .networks()
+                        .iter()
+                        .filter_map(|name| obj.network_list().get_network(name))
+                        .for_each(|network| network.container_list().add_container(container));
+                    container.connect_networks_changed(clone!(@weak obj => move |container| {
+                        obj.network_list()
+                            .iter::<model::Network>()
+                            .map(Result::unwrap)
+                            .for_each(|network| {
+                                let container_list = network.container_list();
+                                let id = container.id();
+                                if !container.networks().contains(&network.name()) {
+                                    container_list.remove_container(id.as_str());
+                                } else if container_list.get_container(id.as_str()).is_none() {
+                                    container_list.add_container(container);
+                                }
+                            });
+                    }));
+
                     if !container.mounts().is_empty() {
                         container.inspect(clone!(@weak obj => move |result| {
                             if let Ok(container) = result {
@@ -142,6 +191,13 @@ mod imp {
                         pod.container_list().remove_container(container.id().as_str());
                     }
 
+                    obj.network_list()
+                        .iter::<model::Network>()
+                        .map(|network| network.unwrap())
+                        .for_each(|network| {
+                            network.container_list().remove_container(container.id().as_str());
+                        });
+
                     container
                         .volume_list()
                         .iter::<model::ContainerVolume>()
@@ -168,6 +224,15 @@ mod imp {
                         });
                 }));
 
+            obj.network_list()
+                .connect_network_added(clone!(@weak obj => move |_, network| {
+                    obj.container_list()
+                        .iter::<model::Container>()
+                        .map(|container| container.unwrap())
+                        .filter(|container| container.networks().contains(&network.name()))
+                        .for_each(|container| network.container_list().add_container(&container));
+                }));
+
             obj.volume_list()
                 .connect_volume_added(clone!(@weak obj => move |_, volume| {
                     let container_list: Vec<_> = obj.container_list().iter::<model::Container>()
@@ -215,6 +280,15 @@ mod imp {
                             }));
                         });
                 }));
+
+            // The monitor must watch the containers from the very first listing on.
+            obj.alert_monitor();
+        }
+
+        fn dispose(&self) {
+            if let Some(source_id) = self.reconciliation_source.take() {
+                source_id.remove();
+            }
         }
     }
 
@@ -251,11 +325,29 @@ mod imp {
                 .to_owned()
         }
 
+        fn network_list(&self) -> model::NetworkList {
+            self.network_list
+                .get_or_init(|| model::NetworkList::from(&*self.obj()))
+                .to_owned()
+        }
+
+        fn secret_list(&self) -> model::SecretList {
+            self.secret_list
+                .get_or_init(|| model::SecretList::from(&*self.obj()))
+                .to_owned()
+        }
+
         fn action_list(&self) -> model::ActionList {
             self.action_list
                 .get_or_init(|| model::ActionList::from(&*self.obj()))
                 .to_owned()
         }
+
+        fn alert_monitor(&self) -> model::AlertMonitor {
+            self.alert_monitor
+                .get_or_init(|| model::AlertMonitor::from(&*self.obj()))
+                .to_owned()
+        }
     }
 }
 
@@ -263,38 +355,87 @@ glib::wrapper! {
     pub(crate) struct Client(ObjectSubclass<imp::Client>);
 }
 
+/// Creates a client for a connection that neither needs an SSH tunnel nor TLS certificates.
 impl TryFrom<&model::Connection> for Client {
-    type Error = podman::Error;
+    type Error = anyhow::Error;
 
     fn try_from(connection: &model::Connection) -> Result<Self, Self::Error> {
-        podman::Podman::new(connection.url()).map(|podman| {
-            let obj: Self = glib::Object::builder()
-                .property("connection", connection)
-                .property("podman", BoxedPodman::from(podman.clone()))
-                .build();
-
-            utils::do_async(
-                async move { podman.info().await },
-                clone!(@weak obj => move |info| match info {
-                    Ok(info) => {
-                        obj.set_version(info.version.unwrap().version);
-                        obj.set_cpus(info.host.unwrap().cpus);
-                    }
-                    Err(e) => {
-                        log::error!("Error on retrieving podmnan info: {e}");
-
-                        obj.set_version(None);
-                        obj.set_cpus(None);
-                    }
-                }),
-            );
-
-            obj
-        })
+        podman::Podman::new(connection.url())
+            .map(|podman| Self::new(connection, podman))
+            .map_err(anyhow::Error::from)
     }
 }
 
 impl Client {
+    fn new(connection: &model::Connection, podman: podman::Podman) -> Self {
+        let obj: Self = glib::Object::builder()
+            .property("connection", connection)
+            .property("podman", BoxedPodman::from(podman.clone()))
+            .build();
+
+        utils::do_async(
+            async move { podman.info().await },
+            clone!(@weak obj => move |info| match info {
+                Ok(info) => {
+                    obj.set_version(info.version.unwrap().version);
+
+                    let host = info.host.unwrap();
+                    obj.set_cpus(host.cpus);
+                    obj.set_rootless(
+                        host.security
+                            .and_then(|security| security.rootless)
+                            .unwrap_or_default(),
+                    );
+                }
+                Err(e) => {
+                    log::error!("Error on retrieving podmnan info: {e}");
+
+                    obj.set_version(None);
+                    obj.set_cpus(None);
+                }
+            }),
+        );
+
+        obj
+    }
+
+    /// Creates a client that talks to the remote Podman instance through the given tunnel. The
+    /// tunnel is closed together with the client.
+    pub(crate) fn with_ssh_tunnel(
+        connection: &model::Connection,
+        ssh_tunnel: model::SshTunnel,
+    ) -> anyhow::Result<Self> {
+        let obj = Self::new(connection, podman::Podman::new(ssh_tunnel.url())?);
+        obj.imp().ssh_tunnel.replace(Some(ssh_tunnel));
+        Ok(obj)
+    }
+
+    /// Creates a client that authenticates with the certificates in the given directory, which has
+    /// been prepared by [`model::TlsInfo::prepare_cert_dir`].
+    pub(crate) fn with_tls(
+        connection: &model::Connection,
+        cert_dir: PathBuf,
+    ) -> anyhow::Result<Self> {
+        podman::Podman::tls(connection.url(), cert_dir, true)
+            .map(|podman| Self::new(connection, podman))
+            .map_err(anyhow::Error::from)
+    }
+
+    /// Where archives are sent to, see [`model::upload_archive`].
+    pub(crate) fn archive_upload_endpoint(&self) -> anyhow::Result<model::ArchiveUploadEndpoint> {
+        if let Some(ssh_tunnel) = self.imp().ssh_tunnel.borrow().as_ref() {
+            return model::ArchiveUploadEndpoint::new(&ssh_tunnel.url(), None);
+        }
+
+        let connection = self.connection();
+        model::ArchiveUploadEndpoint::new(
+            &connection.url(),
+            connection
+                .tls()
+                .map(|_| model::TlsInfo::cert_dir(&connection.uuid())),
+        )
+    }
+
     fn set_version(&self, value: Option<String>) {
         self.imp().version.set(value).unwrap();
         self.notify_version();
@@ -305,11 +446,73 @@ impl Client {
         self.notify_cpus();
     }
 
-    pub(crate) fn check_service<T, E, F>(&self, op: T, err_op: E, finish_op: F)
+    fn set_rootless(&self, value: bool) {
+        if self.rootless() == value {
+            return;
+        }
+        self.imp().rootless.set(value);
+        self.notify_rootless();
+    }
+
+    fn set_started(&self, value: bool) {
+        if self.started() == value {
+            return;
+        }
+        self.imp().started.set(value);
+        self.notify_started();
+    }
+
+    fn set_reconnecting(&self, value: bool) {
+        if self.reconnecting() == value {
+            return;
+        }
+        self.imp().reconnecting.set(value);
+        self.notify_reconnecting();
+    }
+
+    fn set_reconnect_countdown(&self, value: u32) {
+        if self.reconnect_countdown() == value {
+            return;
+        }
+        self.imp().reconnect_countdown.set(value);
+        self.notify_reconnect_countdown();
+    }
+
+    /// Retrieves the disk space in bytes used by the images, containers and volumes.
+    pub(crate) fn data_usage<F>(&self, op: F)
+    where
+        F: FnOnce(podman::Result<u64>) + 'static,
+    {
+        utils::do_async(
+            {
+                let podman = self.podman();
+                async move { podman.data_usage().await }
+            },
+            move |result| {
+                op(result.map(|report| {
+                    let containers = report
+                        .containers
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|container| container.rw_size)
+                        .sum::<i64>();
+                    let volumes = report
+                        .volumes
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|volume| volume.size)
+                        .sum::<i64>();
+
+                    (report.images_size.unwrap_or_default() + containers + volumes).max(0) as u64
+                }))
+            },
+        );
+    }
+
+    pub(crate) fn check_service<T, E>(&self, op: T, err_op: E)
     where
         T: FnOnce() + 'static,
         E: FnOnce(ClientError) + Clone + 'static,
-        F: FnOnce(podman::Error) + Clone + 'static,
     {
         utils::do_async(
             {
@@ -318,32 +521,13 @@ impl Client {
             },
             clone!(@weak self as obj => move |result| match result {
                 Ok(_) => {
-                    obj.image_list().refresh({
-                        let err_op = err_op.clone();
-                        |_| err_op(ClientError::Images)
-                    });
-                    obj.container_list().refresh(
-                        None,
-                        {
-                            let err_op = err_op.clone();
-                            |_| err_op(ClientError::Containers)
-                        }
-                    );
-                    obj.pod_list().refresh(
-                        None,
-                        {
-                            let err_op = err_op.clone();
-                            |_| err_op(ClientError::Pods)
-                        }
-                    );
-                    obj.volume_list().refresh({
-                        let err_op = err_op.clone();
-                        |_| err_op(ClientError::Volumes)
-                    });
+                    obj.refresh_lists(err_op.clone());
+
+                    obj.set_started(true);
 
                     op();
-                    obj.start_event_listener(err_op, finish_op);
-                    obj.start_refresh_interval();
+                    obj.start_event_listener(err_op);
+                    obj.start_reconciliation();
                 }
                 Err(e) => {
                     log::error!("Could not connect to Podman: {e}");
@@ -353,12 +537,42 @@ impl Client {
         );
     }
 
-    fn start_event_listener<E, F>(&self, err_op: E, finish_op: F)
+    fn refresh_lists<E>(&self, err_op: E)
+    where
+        E: FnOnce(ClientError) + Clone + 'static,
+    {
+        self.image_list().refresh(None, {
+            let err_op = err_op.clone();
+            |_| err_op(ClientError::Images)
+        });
+        self.container_list().refresh(None, {
+            let err_op = err_op.clone();
+            |_| err_op(ClientError::Containers)
+        });
+        self.pod_list().refresh(None, {
+            let err_op = err_op.clone();
+            |_| err_op(ClientError::Pods)
+        });
+        self.volume_list().refresh({
+            let err_op = err_op.clone();
+            |_| err_op(ClientError::Volumes)
+        });
+        self.network_list().refresh({
+            let err_op = err_op.clone();
+            |_| err_op(ClientError::Networks)
+        });
+        self.secret_list().refresh({
+            let err_op = err_op.clone();
+            |_| err_op(ClientError::Secrets)
+        });
+    }
+
+    /// Listens for events until the stream breaks. Then, reconnection is attempted.
+    fn start_event_listener<E>(&self, err_op: E)
     where
         E: FnOnce(ClientError) + Clone + 'static,
-        F: FnOnce(podman::Error) + Clone + 'static,
     {
-        utils::run_stream(
+        utils::run_stream_with_finish_handler(
             self.podman(),
             |podman| {
                 podman
@@ -366,7 +580,7 @@ impl Client {
                     .boxed()
             },
             clone!(
-                @weak self as obj => @default-return glib::ControlFlow::Break,
+                @weak self as obj, @strong err_op => @default-return glib::ControlFlow::Break,
                 move |result: podman::Result<podman::models::Event>|
             {
                 match result {
@@ -389,36 +603,248 @@ impl Client {
                                 let err_op = err_op.clone();
                                 |_| err_op(ClientError::Volumes)
                             }),
+                            "network" => obj.network_list().handle_event(event, {
+                                let err_op = err_op.clone();
+                                |_| err_op(ClientError::Networks)
+                            }),
+                            "secret" => obj.secret_list().handle_event(event, {
+                                let err_op = err_op.clone();
+                                |_| err_op(ClientError::Secrets)
+                            }),
                             other => log::warn!("Unhandled event type: {other}"),
                         }
                         glib::ControlFlow::Continue
                     }
                     Err(e) => {
-                        log::error!("Stopping image event stream due to error: {e}");
-                        finish_op.clone()(e);
+                        log::error!("Stopping event stream due to error: {e}");
                         glib::ControlFlow::Break
                     }
                 }
             }),
+            // The stream also ends without an error if the connection is closed.
+            clone!(@weak self as obj => move || obj.start_reconnecting(err_op.clone())),
         );
     }
 
-    /// This is needed to keep track of images and containers that are managed by Buildah.
-    /// See https://github.com/marhkb/pods/issues/306
-    fn start_refresh_interval(&self) {
+    /// Retries to ping Podman and to subscribe to its events with exponential backoff. SSH
+    /// tunnels are reopened on each attempt. All lists are refreshed afterwards because events may
+    /// have been missed in the meantime.
+    fn start_reconnecting<E>(&self, err_op: E)
+    where
+        E: FnOnce(ClientError) + Clone + 'static,
+    {
+        if self.reconnecting() {
+            return;
+        }
+
+        log::warn!("Lost connection to Podman. Trying to reconnect");
+
+        self.imp().reconnect_attempts.set(0);
+        self.set_reconnecting(true);
+        self.schedule_reconnect_attempt();
+
         glib::timeout_add_seconds_local(
-            SYNC_INTERVAL,
+            1,
             clone!(@weak self as obj => @default-return glib::ControlFlow::Break, move || {
-                log::debug!("Syncing images, containers and pods");
-
-                obj.image_list().refresh(|_| {});
-                obj.container_list().refresh(None, |_| {});
-                obj.pod_list().refresh(None, |_| {});
+                if !obj.reconnecting() {
+                    return glib::ControlFlow::Break;
+                }
 
-                log::debug!("Sleeping for {SYNC_INTERVAL} until next sync");
+                match obj.reconnect_countdown() {
+                    // An attempt is still running.
+                    0 => {}
+                    1 => {
+                        obj.set_reconnect_countdown(0);
+                        obj.try_reconnect(err_op.clone());
+                    }
+                    countdown => obj.set_reconnect_countdown(countdown - 1),
+                }
 
                 glib::ControlFlow::Continue
             }),
         );
     }
+
+    fn schedule_reconnect_attempt(&self) {
+        let attempts = self.imp().reconnect_attempts.get();
+        self.imp().reconnect_attempts.set(attempts + 1);
+
+        self.set_reconnect_countdown((1 << attempts.min(6)).min(MAX_RECONNECT_DELAY));
+    }
+
+    fn try_reconnect<E>(&self, err_op: E)
+    where
+        E: FnOnce(ClientError) + Clone + 'static,
+    {
+        let connection = self.connection();
+        let ssh = connection.ssh();
+
+        // The new tunnel listens on the same socket, so the old one must be closed first. The
+        // client doesn't need to be recreated then.
+        if ssh.is_some() {
+            drop(self.imp().ssh_tunnel.take());
+        }
+
+        utils::do_async(
+            {
+                let podman = self.podman();
+                let uuid = connection.uuid();
+                async move {
+                    let ssh_tunnel = match ssh {
+                        Some(ssh) => {
+                            Some(model::SshTunnel::open_with_stored_passphrase(uuid, ssh).await?)
+                        }
+                        None => None,
+                    };
+                    podman.ping().await?;
+
+                    Ok::<_, anyhow::Error>(ssh_tunnel)
+                }
+            },
+            clone!(@weak self as obj => move |result| match result {
+                Ok(ssh_tunnel) => {
+                    log::info!("Reconnected to Podman");
+
+                    if ssh_tunnel.is_some() {
+                        obj.imp().ssh_tunnel.replace(ssh_tunnel);
+                    }
+                    obj.set_reconnecting(false);
+                    obj.refresh_lists(err_op.clone());
+                    obj.start_event_listener(err_op);
+                }
+                Err(e) => {
+                    log::warn!("Error on reconnecting to Podman: {e}");
+                    obj.schedule_reconnect_attempt();
+                }
+            }),
+        );
+    }
+
+    /// Skips the remaining time until the next reconnection attempt.
+    pub(crate) fn reconnect_now(&self) {
+        if self.reconnect_countdown() > 1 {
+            self.set_reconnect_countdown(1);
+        }
+    }
+
+    /// Images and containers that are managed by Buildah don't cause events, and events can get
+    /// lost. To keep track of them, the number of images reported by Podman and the listings of
+    /// containers and pods are compared to our lists from time to time. The lists are only
+    /// refreshed if they differ. See https://github.com/marhkb/pods/issues/306
+    fn start_reconciliation(&self) {
+        self.schedule_reconciliation();
+
+        self.imp().settings.connect_changed(
+            Some("reconciliation-interval"),
+            clone!(@weak self as obj => move |_, _| obj.schedule_reconciliation()),
+        );
+    }
+
+    fn schedule_reconciliation(&self) {
+        let imp = self.imp();
+
+        if let Some(source_id) = imp.reconciliation_source.take() {
+            source_id.remove();
+        }
+
+        let interval = imp.settings.uint("reconciliation-interval");
+        if interval == 0 {
+            return;
+        }
+
+        imp.reconciliation_source
+            .replace(Some(glib::timeout_add_seconds_local(
+                interval,
+                clone!(@weak self as obj => @default-return glib::ControlFlow::Break, move || {
+                    if !obj.reconnecting() && is_window_visible() {
+                        obj.reconcile();
+                    }
+                    glib::ControlFlow::Continue
+                }),
+            )));
+    }
+
+    fn reconcile(&self) {
+        // The container counts serve as a cheap digest, so the containers and pods are only
+        // listed when they differ from the ones of Podman.
+        let container_list = self.container_list();
+        let digest = (
+            Some(container_list.len() as i64),
+            Some(container_list.running() as i64),
+            Some(container_list.paused() as i64),
+        );
+
+        utils::do_async(
+            {
+                let podman = self.podman();
+                async move {
+                    let info = podman.info().await?;
+
+                    let in_sync = info
+                        .store
+                        .as_ref()
+                        .and_then(|store| store.container_store.as_ref())
+                        .is_some_and(|store| (store.number, store.running, store.paused) == digest);
+
+                    let listings = if in_sync {
+                        None
+                    } else {
+                        // The same listings as the ones the lists are refreshed from, which don't
+                        // include external containers like the ones of Buildah.
+                        let list_containers = podman
+                            .containers()
+                            .list(&podman::opts::ContainerListOpts::builder().all(true).build())
+                            .await?;
+                        let reports = podman
+                            .pods()
+                            .list(&podman::opts::PodListOpts::builder().build())
+                            .await?;
+
+                        Some((list_containers, reports))
+                    };
+
+                    podman::Result::Ok((info, listings))
+                }
+            },
+            clone!(@weak self as obj => move |result| match result {
+                Ok((info, listings)) => {
+                    let image_list = obj.image_list();
+                    let images = info
+                        .store
+                        .and_then(|store| store.image_store)
+                        .and_then(|store| store.number);
+                    if images.is_some_and(|images| images != image_list.len() as i64) {
+                        log::debug!("Image list is out of sync. Refreshing");
+                        image_list.refresh(None, |_| {});
+                    }
+
+                    if let Some((list_containers, reports)) = listings {
+                        let container_list = obj.container_list();
+                        if !container_list.is_in_sync(&list_containers) {
+                            log::debug!("Container list is out of sync. Refreshing");
+                            container_list.refresh(None, |_| {});
+                        }
+
+                        let pod_list = obj.pod_list();
+                        if !pod_list.is_in_sync(&reports) {
+                            log::debug!("Pod list is out of sync. Refreshing");
+                            pod_list.refresh(None, |_| {});
+                        }
+                    }
+                }
+                Err(e) => log::warn!("Error on reconciling lists: {e}"),
+            }),
+        );
+    }
+}
+
+/// Whether any window of the application is shown on the screen.
+fn is_window_visible() -> bool {
+    gio::Application::default()
+        .and_downcast::<gtk::Application>()
+        .is_some_and(|app| {
+            app.windows()
+                .iter()
+                .any(|window| window.is_visible() && !window.is_suspended())
+        })
 }
@@ -0,0 +1,598 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use gtk::glib;
+use serde::Deserialize;
+
+use crate::podman;
+
+/// The label used to group all entities that belong to one compose stack. The same label is used
+/// by podman-compose, so stacks brought up by either tool can be torn down by the other.
+pub(crate) const PROJECT_LABEL: &str = "io.podman.compose.project";
+pub(crate) const SERVICE_LABEL: &str = "io.podman.compose.service";
+
+/// A compose file reduced to what is needed to bring it up as a pod.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ComposeProject {
+    pub(crate) name: String,
+    pub(crate) dir: PathBuf,
+    pub(crate) services: Vec<ComposeService>,
+    pub(crate) volumes: Vec<String>,
+    pub(crate) networks: Vec<ComposeNetwork>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ComposeService {
+    pub(crate) name: String,
+    pub(crate) image: String,
+    pub(crate) container_name: Option<String>,
+    pub(crate) command: Vec<String>,
+    pub(crate) entrypoint: Vec<String>,
+    pub(crate) environment: Vec<(String, String)>,
+    pub(crate) labels: Vec<(String, String)>,
+    pub(crate) ports: Vec<ComposePort>,
+    pub(crate) volumes: Vec<ComposeMount>,
+    pub(crate) depends_on: Vec<String>,
+    pub(crate) working_dir: Option<String>,
+    pub(crate) restart: Option<String>,
+    pub(crate) privileged: bool,
+    pub(crate) tty: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ComposeNetwork {
+    pub(crate) name: String,
+    pub(crate) driver: Option<String>,
+    pub(crate) internal: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ComposePort {
+    pub(crate) host_ip: Option<String>,
+    pub(crate) host_port: u16,
+    pub(crate) container_port: u16,
+    pub(crate) protocol: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ComposeMount {
+    Volume {
+        name: String,
+        target: String,
+        read_only: bool,
+    },
+    Bind {
+        source: PathBuf,
+        target: String,
+        read_only: bool,
+    },
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Document {
+    name: Option<String>,
+    services: BTreeMap<String, Service>,
+    volumes: BTreeMap<String, serde_yaml::Value>,
+    networks: BTreeMap<String, Option<Network>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Service {
+    image: Option<String>,
+    build: Option<serde_yaml::Value>,
+    container_name: Option<String>,
+    command: Option<StringOrList>,
+    entrypoint: Option<StringOrList>,
+    environment: Option<MapOrList>,
+    labels: Option<MapOrList>,
+    ports: Vec<Port>,
+    volumes: Vec<Mount>,
+    depends_on: Option<ListOrMap>,
+    working_dir: Option<String>,
+    restart: Option<String>,
+    privileged: bool,
+    tty: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Network {
+    driver: Option<String>,
+    internal: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum StringOrList {
+    String(String),
+    List(Vec<String>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum MapOrList {
+    Map(BTreeMap<String, Option<serde_yaml::Value>>),
+    List(Vec<String>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ListOrMap {
+    List(Vec<String>),
+    Map(BTreeMap<String, serde_yaml::Value>),
+}
+
+// `Long` must come first, since `Short` would accept any value including mappings.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Port {
+    Long {
+        target: u16,
+        published: Option<serde_yaml::Value>,
+        host_ip: Option<String>,
+        protocol: Option<String>,
+    },
+    Short(serde_yaml::Value),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Mount {
+    Short(String),
+    Long {
+        #[serde(rename = "type")]
+        type_: String,
+        source: Option<String>,
+        target: String,
+        #[serde(default)]
+        read_only: bool,
+    },
+}
+
+impl StringOrList {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            // Compose splits string commands like a shell would. Splitting on whitespace is
+            // enough for the vast majority of files.
+            Self::String(s) => s.split_whitespace().map(str::to_owned).collect(),
+            Self::List(list) => list,
+        }
+    }
+}
+
+impl MapOrList {
+    fn into_pairs(self) -> Vec<(String, String)> {
+        match self {
+            Self::Map(map) => map
+                .into_iter()
+                .map(|(key, value)| (key, value.map(yaml_to_string).unwrap_or_default()))
+                .collect(),
+            Self::List(list) => list
+                .into_iter()
+                .map(|entry| match entry.split_once('=') {
+                    Some((key, value)) => (key.to_owned(), value.to_owned()),
+                    None => (entry, String::new()),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ListOrMap {
+    fn into_keys(self) -> Vec<String> {
+        match self {
+            Self::List(list) => list,
+            Self::Map(map) => map.into_keys().collect(),
+        }
+    }
+}
+
+fn yaml_to_string(value: serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s,
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        _ => String::new(),
+    }
+}
+
+impl ComposeProject {
+    /// Parses a compose file. If the file does not set a project name, it is derived from the
+    /// name of the directory containing the file, just as `podman-compose` does.
+    pub(crate) fn parse(yaml: &str, path: &Path) -> anyhow::Result<Self> {
+        let document: Document = serde_yaml::from_str(yaml)?;
+
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        let name = document
+            .name
+            .or_else(|| {
+                dir.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+            })
+            .map(|name| normalize_name(&name))
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| anyhow!("Could not determine the project name"))?;
+
+        if document.services.is_empty() {
+            return Err(anyhow!("The file does not define any services"));
+        }
+
+        let mut services = document
+            .services
+            .into_iter()
+            .map(|(service_name, service)| Self::service(&service_name, service, &dir))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        sort_by_dependencies(&mut services)?;
+        check_container_ports(&services)?;
+
+        let networks = document
+            .networks
+            .into_iter()
+            .map(|(network_name, network)| {
+                let network = network.unwrap_or_default();
+                ComposeNetwork {
+                    name: network_name,
+                    driver: network.driver,
+                    internal: network.internal,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            name,
+            dir,
+            services,
+            volumes: document.volumes.into_keys().collect(),
+            networks,
+        })
+    }
+
+    fn service(service_name: &str, service: Service, dir: &Path) -> anyhow::Result<ComposeService> {
+        let image = match service.image {
+            Some(image) => image,
+            None if service.build.is_some() => {
+                return Err(anyhow!(
+                    "Service '{service_name}' must be built first, which is not supported yet"
+                ))
+            }
+            None => {
+                return Err(anyhow!(
+                    "Service '{service_name}' does not specify an image"
+                ))
+            }
+        };
+
+        let ports = service
+            .ports
+            .into_iter()
+            .map(|port| parse_port(port).ok_or_else(|| anyhow!("Invalid port in '{service_name}'")))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let volumes = service
+            .volumes
+            .into_iter()
+            .filter_map(|mount| parse_mount(mount, dir))
+            .collect();
+
+        Ok(ComposeService {
+            name: service_name.to_owned(),
+            image,
+            container_name: service.container_name,
+            command: service
+                .command
+                .map(StringOrList::into_vec)
+                .unwrap_or_default(),
+            entrypoint: service
+                .entrypoint
+                .map(StringOrList::into_vec)
+                .unwrap_or_default(),
+            environment: service
+                .environment
+                .map(MapOrList::into_pairs)
+                .unwrap_or_default(),
+            labels: service
+                .labels
+                .map(MapOrList::into_pairs)
+                .unwrap_or_default(),
+            ports,
+            volumes,
+            depends_on: service
+                .depends_on
+                .map(ListOrMap::into_keys)
+                .unwrap_or_default(),
+            working_dir: service.working_dir,
+            restart: service.restart,
+            privileged: service.privileged,
+            tty: service.tty,
+        })
+    }
+
+    pub(crate) fn pod_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Named volumes and networks are prefixed with the project name to avoid clashes between
+    /// stacks.
+    pub(crate) fn scoped_name(&self, name: &str) -> String {
+        format!("{}_{name}", self.name)
+    }
+
+    pub(crate) fn container_name(&self, service: &ComposeService) -> String {
+        service
+            .container_name
+            .clone()
+            .unwrap_or_else(|| format!("{}_{}_1", self.name, service.name))
+    }
+
+    pub(crate) fn labels(&self) -> HashMap<String, String> {
+        HashMap::from([(PROJECT_LABEL.to_owned(), self.name.clone())])
+    }
+
+    pub(crate) fn volume_opts(&self, name: &str) -> podman::opts::VolumeCreateOpts {
+        podman::opts::VolumeCreateOpts::builder()
+            .name(self.scoped_name(name))
+            .labels(self.labels())
+            .build()
+    }
+
+    pub(crate) fn network_opts(&self, network: &ComposeNetwork) -> podman::opts::NetworkCreateOpts {
+        let mut opts = podman::opts::NetworkCreateOpts::builder()
+            .name(self.scoped_name(&network.name))
+            .internal(network.internal)
+            .labels(self.labels());
+        if let Some(ref driver) = network.driver {
+            opts = opts.driver(driver.as_str());
+        }
+        opts.build()
+    }
+
+    /// All services share the network namespace of the pod, so the pod publishes the ports of
+    /// every service and joins every network of the project. Services reach each other by their
+    /// names, which therefore resolve to the loopback address of the pod.
+    pub(crate) fn pod_opts(&self) -> podman::opts::PodCreateOpts {
+        podman::opts::PodCreateOpts::builder()
+            .name(self.pod_name())
+            .labels(self.labels())
+            .add_hosts(
+                self.services
+                    .iter()
+                    .map(|service| format!("{}:127.0.0.1", service.name)),
+            )
+            .portmappings(
+                self.services
+                    .iter()
+                    .flat_map(|service| service.ports.iter())
+                    .map(|port| podman::models::PortMapping {
+                        container_port: Some(port.container_port),
+                        host_ip: port.host_ip.clone(),
+                        host_port: Some(port.host_port),
+                        protocol: Some(port.protocol.clone()),
+                        range: None,
+                    }),
+            )
+            .networks(self.networks.iter().map(|network| {
+                (
+                    self.scoped_name(&network.name),
+                    podman::models::PerNetworkOptions {
+                        aliases: None,
+                        interface_name: None,
+                        static_ips: None,
+                        static_mac: None,
+                    },
+                )
+            }))
+            .build()
+    }
+
+    pub(crate) fn container_opts(
+        &self,
+        service: &ComposeService,
+    ) -> podman::opts::ContainerCreateOptsBuilder {
+        let mut opts = podman::opts::ContainerCreateOpts::builder()
+            .name(self.container_name(service))
+            .image(service.image.as_str())
+            .pod(Some(self.pod_name()))
+            .terminal(service.tty)
+            .privileged(service.privileged)
+            .env(service.environment.clone())
+            .labels(
+                service
+                    .labels
+                    .iter()
+                    .cloned()
+                    .chain(self.labels())
+                    .chain(Some((SERVICE_LABEL.to_owned(), service.name.clone()))),
+            )
+            .mounts(service.volumes.iter().filter_map(|mount| match mount {
+                ComposeMount::Bind {
+                    source,
+                    target,
+                    read_only,
+                } => Some(podman::models::ContainerMount {
+                    destination: Some(target.clone()),
+                    source: Some(source.to_string_lossy().into_owned()),
+                    _type: Some("bind".to_owned()),
+                    options: Some(vec![if *read_only { "ro" } else { "rw" }.to_owned()]),
+                    uid_mappings: None,
+                    gid_mappings: None,
+                }),
+                ComposeMount::Volume { .. } => None,
+            }))
+            .volumes(service.volumes.iter().filter_map(|mount| match mount {
+                ComposeMount::Volume {
+                    name,
+                    target,
+                    read_only,
+                } => Some(podman::models::NamedVolume {
+                    dest: Some(target.clone()),
+                    is_anonymous: None,
+                    name: Some(self.scoped_name(name)),
+                    options: Some(vec![if *read_only { "ro" } else { "rw" }.to_owned()]),
+                }),
+                ComposeMount::Bind { .. } => None,
+            }));
+
+        if !service.command.is_empty() {
+            opts = opts.command(service.command.clone());
+        }
+        if !service.entrypoint.is_empty() {
+            opts = opts.entrypoint(service.entrypoint.clone());
+        }
+        if let Some(ref working_dir) = service.working_dir {
+            opts = opts.work_dir(working_dir.as_str());
+        }
+        if let Some(ref restart) = service.restart {
+            opts = opts.restart_policy(restart.as_str());
+        }
+
+        opts
+    }
+}
+
+fn normalize_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+        .collect()
+}
+
+fn parse_port(port: Port) -> Option<ComposePort> {
+    match port {
+        Port::Short(value) => {
+            let spec = yaml_to_string(value);
+            let (spec, protocol) = match spec.split_once('/') {
+                Some((spec, protocol)) => (spec.to_owned(), protocol.to_owned()),
+                None => (spec, "tcp".to_owned()),
+            };
+
+            // Possible forms: "CONTAINER", "HOST:CONTAINER" and "IP:HOST:CONTAINER".
+            let parts = spec.rsplitn(3, ':').collect::<Vec<_>>();
+            let container_port = parts[0].parse().ok()?;
+            let host_port = match parts.get(1) {
+                Some(host_port) if !host_port.is_empty() => host_port.parse().ok()?,
+                _ => container_port,
+            };
+            let host_ip = parts
+                .get(2)
+                .map(|ip| ip.trim_matches(['[', ']']).to_owned());
+
+            Some(ComposePort {
+                host_ip,
+                host_port,
+                container_port,
+                protocol,
+            })
+        }
+        Port::Long {
+            target,
+            published,
+            host_ip,
+            protocol,
+        } => Some(ComposePort {
+            host_ip,
+            host_port: match published.map(yaml_to_string) {
+                Some(published) => published.parse().ok()?,
+                None => target,
+            },
+            container_port: target,
+            protocol: protocol.unwrap_or_else(|| "tcp".to_owned()),
+        }),
+    }
+}
+
+fn parse_mount(mount: Mount, dir: &Path) -> Option<ComposeMount> {
+    let (source, target, read_only) = match mount {
+        Mount::Short(spec) => {
+            let mut parts = spec.splitn(3, ':');
+            let source = parts.next()?.to_owned();
+            // Anonymous volumes are not supported.
+            let target = parts.next()?.to_owned();
+            let read_only = parts
+                .next()
+                .is_some_and(|options| options.split(',').any(|option| option == "ro"));
+            (source, target, read_only)
+        }
+        Mount::Long {
+            type_,
+            source,
+            target,
+            read_only,
+        } => match type_.as_str() {
+            "bind" | "volume" => (source?, target, read_only),
+            _ => return None,
+        },
+    };
+
+    Some(
+        if source.starts_with('.') || source.starts_with('/') || source.starts_with('~') {
+            let source = match source.strip_prefix("~/") {
+                Some(rest) => glib::home_dir().join(rest),
+                None => dir.join(&source),
+            };
+            ComposeMount::Bind {
+                source,
+                target,
+                read_only,
+            }
+        } else {
+            ComposeMount::Volume {
+                name: source,
+                target,
+                read_only,
+            }
+        },
+    )
+}
+
+/// Services share the network namespace of the pod, so no two of them can listen on the same
+/// container port.
+fn check_container_ports(services: &[ComposeService]) -> anyhow::Result<()> {
+    let mut used = HashMap::new();
+
+    services.iter().try_for_each(|service| {
+        service.ports.iter().try_for_each(|port| {
+            match used.insert(
+                (port.container_port, port.protocol.as_str()),
+                service.name.as_str(),
+            ) {
+                Some(other) if other != service.name => Err(anyhow!(
+                    "Services '{other}' and '{}' both use container port {}/{}, which is not possible within a pod",
+                    service.name,
+                    port.container_port,
+                    port.protocol
+                )),
+                _ => Ok(()),
+            }
+        })
+    })
+}
+
+/// Orders the services so that every service comes after the services it depends on.
+fn sort_by_dependencies(services: &mut Vec<ComposeService>) -> anyhow::Result<()> {
+    let mut sorted = Vec::with_capacity(services.len());
+
+    while !services.is_empty() {
+        let position = services
+            .iter()
+            .position(|service| {
+                service.depends_on.iter().all(|dependency| {
+                    sorted
+                        .iter()
+                        .any(|other: &ComposeService| &other.name == dependency)
+                })
+            })
+            .ok_or_else(|| anyhow!("The services have unresolvable dependencies"))?;
+
+        sorted.push(services.remove(position));
+    }
+
+    *services = sorted;
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
+use std::cell::RefCell;
 use std::sync::OnceLock;
 
 use glib::prelude::*;
@@ -12,12 +13,19 @@ use serde::Serialize;
 
 use crate::model;
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub(crate) struct ConnectionInfo {
     pub(super) uuid: String,
     pub(super) name: String,
     pub(super) url: String,
     pub(super) rgb: Option<(f32, f32, f32)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) ssh: Option<model::SshInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) tls: Option<model::TlsInfo>,
+    /// The name of the Podman connection this connection was imported from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) podman_connection: Option<String>,
 }
 
 mod imp {
@@ -26,11 +34,15 @@ mod imp {
     #[derive(Debug, Default, Properties)]
     #[properties(wrapper_type = super::Connection)]
     pub(crate) struct Connection {
+        pub(super) ssh: OnceCell<Option<model::SshInfo>>,
+        pub(super) tls: OnceCell<Option<model::TlsInfo>>,
         #[property(get, set, construct_only, nullable)]
         pub(super) manager: glib::WeakRef<model::ConnectionManager>,
         #[property(get, set)]
         pub(super) connecting: Cell<bool>,
         #[property(get, set)]
+        pub(super) connected: Cell<bool>,
+        #[property(get, set)]
         pub(super) active: Cell<bool>,
         #[property(get, set, construct_only)]
         pub(super) uuid: OnceCell<String>,
@@ -40,6 +52,12 @@ mod imp {
         pub(super) url: OnceCell<String>,
         #[property(get, set, construct_only, nullable)]
         pub(super) rgb: Cell<Option<gdk::RGBA>>,
+        /// The name of the Podman connection this connection was imported from.
+        #[property(get, set, nullable)]
+        pub(super) podman_connection: RefCell<Option<String>>,
+        /// Whether the connection is provided by the system administrator and can't be removed.
+        #[property(get, set)]
+        pub(super) managed: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -100,6 +118,9 @@ impl From<&Connection> for ConnectionInfo {
             rgb: connection
                 .rgb()
                 .map(|rgb| (rgb.red(), rgb.green(), rgb.blue())),
+            ssh: connection.ssh(),
+            tls: connection.tls(),
+            podman_connection: connection.podman_connection(),
         }
     }
 }
@@ -109,15 +130,19 @@ impl Connection {
         connection_info: &ConnectionInfo,
         manager: &model::ConnectionManager,
     ) -> Self {
-        Self::new(
+        let obj = Self::new(
             &connection_info.uuid,
             &connection_info.name,
             &connection_info.url,
             connection_info
                 .rgb
                 .map(|(r, g, b)| gdk::RGBA::new(r, g, b, 1.0)),
+            connection_info.ssh.clone(),
+            connection_info.tls.clone(),
             manager,
-        )
+        );
+        obj.set_podman_connection(connection_info.podman_connection.as_deref());
+        obj
     }
 
     pub(crate) fn new(
@@ -125,15 +150,31 @@ impl Connection {
         name: &str,
         url: &str,
         rgb: Option<gdk::RGBA>,
+        ssh: Option<model::SshInfo>,
+        tls: Option<model::TlsInfo>,
         manager: &model::ConnectionManager,
     ) -> Self {
-        glib::Object::builder()
+        let obj: Self = glib::Object::builder()
             .property("manager", manager)
             .property("uuid", uuid)
             .property("name", name)
             .property("url", url)
             .property("rgb", rgb)
-            .build()
+            .build();
+        let imp = obj.imp();
+        imp.ssh.set(ssh).unwrap();
+        imp.tls.set(tls).unwrap();
+        obj
+    }
+
+    /// Returns the SSH settings if this connection is tunneled over SSH.
+    pub(crate) fn ssh(&self) -> Option<model::SshInfo> {
+        self.imp().ssh.get().cloned().flatten()
+    }
+
+    /// Returns the certificates if this connection is secured by mutual TLS.
+    pub(crate) fn tls(&self) -> Option<model::TlsInfo> {
+        self.imp().tls.get().cloned().flatten()
     }
 
     pub(crate) fn is_local(&self) -> bool {
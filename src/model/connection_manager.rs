@@ -2,6 +2,7 @@ use std::cell::Cell;
 use std::cell::RefCell;
 use std::io::Read;
 use std::path::PathBuf;
+use std::rc::Rc;
 use std::sync::OnceLock;
 
 use futures::future;
@@ -9,6 +10,7 @@ use gettextrs::gettext;
 use gio::prelude::*;
 use gio::subclass::prelude::*;
 use glib::clone;
+use glib::subclass::Signal;
 use glib::Properties;
 use gtk::gdk;
 use gtk::gio;
@@ -21,6 +23,17 @@ use crate::podman;
 use crate::utils;
 use crate::utils::config_dir;
 
+/// How to handle imported connections whose name or UUID is already in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ImportConflictResolution {
+    /// Keep the existing connection and ignore the imported one.
+    Skip,
+    /// Remove the existing connection in favor of the imported one.
+    Replace,
+    /// Add the imported connection under a new name and UUID.
+    KeepBoth,
+}
+
 mod imp {
     use super::*;
 
@@ -29,8 +42,17 @@ mod imp {
     pub(crate) struct ConnectionManager {
         pub(super) settings: utils::PodsSettings,
         pub(super) connections: RefCell<IndexMap<String, model::Connection>>,
+        /// The user's connections that are hidden by a managed connection with the same UUID or
+        /// name. They are kept so that they are written back to disk.
+        pub(super) shadowed_connections: RefCell<IndexMap<String, model::ConnectionInfo>>,
+        /// All clients that are currently connected, keyed by the connection uuid.
+        pub(super) clients: RefCell<IndexMap<String, model::Client>>,
+        /// The client that is currently shown.
         #[property(get)]
         pub(super) client: RefCell<Option<model::Client>>,
+        /// The connections that are defined by Podman itself.
+        pub(super) podman_connections: RefCell<Vec<model::PodmanConnection>>,
+        pub(super) podman_connection_monitors: RefCell<Vec<gio::FileMonitor>>,
         pub(super) creating_new_connection: Cell<bool>,
         pub(super) connect_abort_handle: RefCell<Option<future::AbortHandle>>,
     }
@@ -43,6 +65,18 @@ mod imp {
     }
 
     impl ObjectImpl for ConnectionManager {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                vec![
+                    Signal::builder("client-added")
+                        .param_types([model::Client::static_type()])
+                        .build(),
+                    Signal::builder("podman-connections-changed").build(),
+                ]
+            })
+        }
+
         fn properties() -> &'static [glib::ParamSpec] {
             static PROPERTIES: OnceLock<Vec<glib::ParamSpec>> = OnceLock::new();
             PROPERTIES.get_or_init(|| {
@@ -101,22 +135,68 @@ impl ConnectionManager {
     where
         F: Fn(anyhow::Result<()>) + 'static,
     {
-        let connections = match self.load_from_disk().map_err(anyhow::Error::from) {
-            Ok(connections) => connections,
-            Err(e) => {
-                op(Err(e));
-                return;
-            }
-        };
+        if let Err(e) = self.load() {
+            op(Err(e));
+            return;
+        }
+
+        self.load_podman_connections();
+        self.monitor_podman_connections();
+
+        if self.n_items() > 0 {
+            self.set_client_from(self.last_used_connection().as_str(), op);
+        } else {
+            op(Ok(()));
+        }
+    }
+
+    /// Loads the user's and the managed connections without connecting to any of them.
+    pub(crate) fn load(&self) -> anyhow::Result<()> {
+        let connections = self.load_from_disk()?;
+        let managed_connections = load_managed().unwrap_or_else(|e| {
+            log::error!("Error on loading managed connections: {e}");
+            IndexMap::default()
+        });
+
+        // Managed connections take precedence over the user's connections with the same UUID
+        // or name.
+        let mut shadowed_connections = IndexMap::new();
+        let connections = managed_connections
+            .into_iter()
+            .map(|(uuid, conn)| (uuid, conn, true))
+            .chain(
+                connections
+                    .into_iter()
+                    .map(|(uuid, conn)| (uuid, conn, false)),
+            )
+            .fold(
+                IndexMap::<String, model::Connection>::new(),
+                |mut connections, (uuid, conn, managed)| {
+                    if connections.contains_key(&uuid)
+                        || connections.values().any(|c| c.name() == conn.name)
+                    {
+                        if !managed {
+                            log::warn!(
+                                "Connection '{}' is hidden by a managed connection",
+                                conn.name
+                            );
+                            shadowed_connections.insert(uuid, conn);
+                        }
+                    } else {
+                        let connection = model::Connection::from_connection_info(&conn, self);
+                        connection.set_managed(managed);
+                        connections.insert(uuid, connection);
+                    }
+                    connections
+                },
+            );
         let connections_len = connections.len();
 
         let imp = self.imp();
 
-        imp.connections.borrow_mut().extend(
-            connections
-                .into_iter()
-                .map(|(uuid, conn)| (uuid, model::Connection::from_connection_info(&conn, self))),
-        );
+        imp.shadowed_connections.replace(shadowed_connections);
+
+        imp.connections.borrow_mut().extend(connections);
 
         self.items_changed(
             (imp.connections.borrow().len() - connections_len) as u32,
@@ -124,12 +204,12 @@ impl ConnectionManager {
             connections_len as u32,
         );
 
-        if self.n_items() > 0 {
-            let last_used_connection = imp.settings.string("last-used-connection");
-            self.set_client_from(last_used_connection.as_str(), op);
-        } else {
-            op(Ok(()));
-        }
+        Ok(())
+    }
+
+    /// Returns the uuid of the connection that was shown the last time.
+    pub(crate) fn last_used_connection(&self) -> String {
+        self.imp().settings.string("last-used-connection").into()
     }
 
     fn load_from_disk(&self) -> anyhow::Result<IndexMap<String, model::ConnectionInfo>> {
@@ -157,15 +237,7 @@ impl ConnectionManager {
     where
         F: FnOnce(anyhow::Result<()>) + 'static,
     {
-        let value = self
-            .imp()
-            .connections
-            .borrow()
-            .iter()
-            .map(|(key, connection)| (key.to_owned(), model::ConnectionInfo::from(connection)))
-            .collect::<IndexMap<_, _>>();
-
-        let buf = serde_json::to_vec_pretty(&value).unwrap();
+        let buf = self.export_connections();
 
         utils::do_async(
             async move {
@@ -186,80 +258,393 @@ impl ConnectionManager {
         );
     }
 
+    /// Serializes the user's connections. Managed connections are left out because they are
+    /// provided by the system, but the user's connections that are hidden by them are kept.
+    pub(crate) fn export_connections(&self) -> Vec<u8> {
+        let imp = self.imp();
+
+        let mut value = imp
+            .connections
+            .borrow()
+            .iter()
+            .filter(|(_, connection)| !connection.managed())
+            .map(|(key, connection)| (key.to_owned(), model::ConnectionInfo::from(connection)))
+            .collect::<IndexMap<_, _>>();
+
+        imp.shadowed_connections
+            .borrow()
+            .iter()
+            .for_each(|(key, info)| {
+                value.entry(key.to_owned()).or_insert_with(|| info.clone());
+            });
+
+        serde_json::to_vec_pretty(&value).unwrap()
+    }
+
+    /// Returns the number of connections whose name or UUID is already in use.
+    pub(crate) fn import_conflicts(&self, connection_infos: &[model::ConnectionInfo]) -> usize {
+        connection_infos
+            .iter()
+            .filter(|info| !self.conflicting_connections(info).is_empty())
+            .count()
+    }
+
+    /// Adds the given connections and returns how many of them were added. Managed connections
+    /// are never replaced.
+    pub(crate) fn import_connections(
+        &self,
+        connection_infos: Vec<model::ConnectionInfo>,
+        resolution: ImportConflictResolution,
+    ) -> usize {
+        let mut imported = 0;
+
+        for mut info in connection_infos {
+            let conflicts = self.conflicting_connections(&info);
+
+            if !conflicts.is_empty() {
+                match resolution {
+                    ImportConflictResolution::Skip => continue,
+                    ImportConflictResolution::Replace => {
+                        if conflicts.iter().any(model::Connection::managed) {
+                            continue;
+                        }
+                        conflicts.iter().for_each(|connection| {
+                            self.take_connection(&connection.uuid());
+                        });
+                    }
+                    ImportConflictResolution::KeepBoth => {
+                        if self.connection_by_uuid(&info.uuid).is_some() {
+                            info.uuid = glib::uuid_string_random().to_string();
+                        }
+                        info.name = self.unique_name(&info.name);
+                    }
+                }
+            }
+
+            let (position, _) = self.imp().connections.borrow_mut().insert_full(
+                info.uuid.clone(),
+                model::Connection::from_connection_info(&info, self),
+            );
+            self.items_changed(position as u32, 0, 1);
+
+            imported += 1;
+        }
+
+        if imported > 0 {
+            self.sync_to_disk(|_| {});
+        }
+
+        imported
+    }
+
+    fn conflicting_connections(&self, info: &model::ConnectionInfo) -> Vec<model::Connection> {
+        self.imp()
+            .connections
+            .borrow()
+            .values()
+            .filter(|connection| connection.uuid() == info.uuid || connection.name() == info.name)
+            .cloned()
+            .collect()
+    }
+
+    /// Appends a number to the name until it isn't used by any connection.
+    fn unique_name(&self, name: &str) -> String {
+        let connections = self.imp().connections.borrow();
+        let is_used = |name: &str| connections.values().any(|c| c.name() == name);
+
+        if !is_used(name) {
+            return name.to_owned();
+        }
+
+        (2..)
+            .map(|n| format!("{name} ({n})"))
+            .find(|name| !is_used(name))
+            .unwrap()
+    }
+
     pub(crate) fn try_connect<F>(
         &self,
         name: &str,
         url: &str,
         rgb: Option<gdk::RGBA>,
+        ssh: Option<model::SshInfo>,
+        tls: Option<model::TlsInfo>,
         op: F,
     ) -> anyhow::Result<()>
     where
-        F: FnOnce(podman::Result<podman::models::LibpodPingInfo>) + 'static,
+        F: FnOnce(anyhow::Result<podman::models::LibpodPingInfo>) + 'static,
     {
-        let imp = self.imp();
+        self.add_connection(
+            model::Connection::new(
+                glib::uuid_string_random().as_str(),
+                name,
+                url,
+                rgb,
+                ssh,
+                tls,
+                self,
+            ),
+            op,
+        )
+    }
 
-        if imp.connections.borrow().values().any(|c| c.name() == name) {
+    /// Imports a connection that is defined by Podman. The connection is kept in sync with
+    /// Podman's configuration files afterwards.
+    pub(crate) fn import_podman_connection<F>(&self, name: &str, op: F) -> anyhow::Result<()>
+    where
+        F: FnOnce(anyhow::Result<podman::models::LibpodPingInfo>) + 'static,
+    {
+        let podman_connection = self
+            .imp()
+            .podman_connections
+            .borrow()
+            .iter()
+            .find(|podman_connection| podman_connection.name == name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Podman connection not found"))?;
+
+        let connection = model::Connection::new(
+            glib::uuid_string_random().as_str(),
+            name,
+            &podman_connection.url()?,
+            None,
+            podman_connection.ssh_info()?,
+            None,
+            self,
+        );
+        connection.set_podman_connection(Some(name));
+
+        self.add_connection(connection, op)
+    }
+
+    /// Adds the connection after it could be pinged successfully.
+    fn add_connection<F>(&self, connection: model::Connection, op: F) -> anyhow::Result<()>
+    where
+        F: FnOnce(anyhow::Result<podman::models::LibpodPingInfo>) + 'static,
+    {
+        if self
+            .imp()
+            .connections
+            .borrow()
+            .values()
+            .any(|c| c.name() == connection.name())
+        {
             return Err(anyhow::anyhow!(gettext!(
                 "Connection '{}' already exists",
-                name
+                connection.name()
             )));
         }
 
-        let connection =
-            model::Connection::new(glib::uuid_string_random().as_str(), name, url, rgb, self);
+        self.set_creating_new_connection(true);
 
-        let client = model::Client::try_from(&connection)?;
+        self.create_client(
+            &connection.clone(),
+            clone!(@weak self as obj => move |result| match result {
+                Ok(client) => utils::do_async(
+                    {
+                        let podman = client.podman();
+                        let abort_registration = obj.abort_registration();
+                        async move {
+                            future::Abortable::new(podman.ping(), abort_registration).await
+                        }
+                    },
+                    clone!(@weak obj => move |result| {
+                        if let Ok(result) = result {
+                            match &result {
+                                Ok(_) => {
+                                    let (position, _) = obj.imp()
+                                        .connections
+                                        .borrow_mut()
+                                        .insert_full(connection.uuid(), connection.clone());
+
+                                    obj.items_changed(position as u32, 0, 1);
+
+                                    obj.add_client(&client);
+                                    obj.set_client(Some(client));
+
+                                    obj.sync_to_disk(|_| {});
+                                }
+                                Err(e) => {
+                                    log::error!("Error on pinging connection: {e}");
+                                    model::TlsInfo::remove_cert_dir(&connection.uuid());
+                                }
+                            }
+                            op(result.map_err(model::connect_error));
+                        }
+                        obj.set_creating_new_connection(false);
+                    }),
+                ),
+                Err(e) => {
+                    log::error!("Error on creating client: {e}");
+                    op(Err(e));
+                    obj.set_creating_new_connection(false);
+                }
+            }),
+        );
 
-        self.set_creating_new_connection(true);
+        Ok(())
+    }
 
-        utils::do_async(
-            {
-                let podman = client.podman();
-                let abort_registration = self.abort_registration();
-                async move { future::Abortable::new(podman.ping(), abort_registration).await }
-            },
-            clone!(@weak self as obj => move |result| {
-                if let Ok(result) = result {
-                    match &result {
-                        Ok(_) => {
-                            let (position, _) = obj.imp()
-                                .connections
-                                .borrow_mut()
-                                .insert_full(connection.uuid(), connection.clone());
+    /// Returns the Podman connections that haven't been imported yet.
+    pub(crate) fn importable_podman_connections(&self) -> Vec<model::PodmanConnection> {
+        let connections = self.imp().connections.borrow();
 
-                            obj.items_changed(position as u32, 0, 1);
+        self.imp()
+            .podman_connections
+            .borrow()
+            .iter()
+            .filter(|podman_connection| {
+                !connections.values().any(|connection| {
+                    connection.podman_connection().as_ref() == Some(&podman_connection.name)
+                })
+            })
+            .cloned()
+            .collect()
+    }
 
-                            obj.set_client(Some(client));
+    pub(crate) fn connect_podman_connections_changed<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("podman-connections-changed", true, move |values| {
+            f(&values[0].get::<Self>().unwrap());
+            None
+        })
+    }
 
-                            obj.sync_to_disk(|_| {});
-                        }
-                        Err(e) => log::error!("Error on pinging connection: {e}"),
+    fn load_podman_connections(&self) {
+        let podman_connections = match model::load_podman_connections() {
+            Ok(podman_connections) => podman_connections,
+            Err(e) => {
+                log::warn!("Error on reading Podman connections: {e}");
+                return;
+            }
+        };
+
+        let imp = self.imp();
+        if *imp.podman_connections.borrow() == podman_connections {
+            return;
+        }
+        imp.podman_connections.replace(podman_connections);
+
+        self.update_imported_connections();
+        self.emit_by_name::<()>("podman-connections-changed", &[]);
+    }
+
+    fn monitor_podman_connections(&self) {
+        let monitors = model::podman_connection_files()
+            .into_iter()
+            .filter_map(|path| {
+                match gio::File::for_path(&path)
+                    .monitor_file(gio::FileMonitorFlags::NONE, gio::Cancellable::NONE)
+                {
+                    Ok(monitor) => {
+                        monitor.connect_changed(
+                            clone!(@weak self as obj => move |_, _, _, event| {
+                                if matches!(
+                                    event,
+                                    gio::FileMonitorEvent::ChangesDoneHint
+                                        | gio::FileMonitorEvent::Created
+                                        | gio::FileMonitorEvent::Deleted
+                                ) {
+                                    obj.load_podman_connections();
+                                }
+                            }),
+                        );
+                        Some(monitor)
+                    }
+                    Err(e) => {
+                        log::warn!("Error on monitoring '{}': {e}", path.display());
+                        None
                     }
-                    op(result);
                 }
-                obj.set_creating_new_connection(false);
-            }),
-        );
+            })
+            .collect();
 
-        Ok(())
+        self.imp().podman_connection_monitors.replace(monitors);
+    }
+
+    /// Applies changes of Podman's configuration files to the imported connections. Connections
+    /// whose Podman connection was removed are kept as they are.
+    fn update_imported_connections(&self) {
+        let imp = self.imp();
+
+        let outdated = {
+            let podman_connections = imp.podman_connections.borrow();
+
+            imp.connections
+                .borrow()
+                .values()
+                .filter_map(|connection| {
+                    let name = connection.podman_connection()?;
+                    let podman_connection = podman_connections
+                        .iter()
+                        .find(|podman_connection| podman_connection.name == name)?;
+
+                    let url = podman_connection.url().ok()?;
+                    let ssh = podman_connection.ssh_info().ok()?;
+
+                    (connection.url() != url || connection.ssh() != ssh).then(|| {
+                        let updated = model::Connection::new(
+                            &connection.uuid(),
+                            &connection.name(),
+                            &url,
+                            connection.rgb(),
+                            ssh,
+                            connection.tls(),
+                            self,
+                        );
+                        updated.set_podman_connection(Some(name.as_str()));
+                        updated.set_managed(connection.managed());
+                        updated
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if outdated.is_empty() {
+            return;
+        }
+
+        outdated.into_iter().for_each(|connection| {
+            let uuid = connection.uuid();
+
+            // The client still uses the old settings.
+            self.disconnect(&uuid);
+
+            let (position, _) = imp.connections.borrow_mut().insert_full(uuid, connection);
+            self.items_changed(position as u32, 1, 1);
+        });
+
+        self.sync_to_disk(|_| {});
     }
 
     pub(crate) fn remove_connection(&self, uuid: &str) {
+        if self
+            .connection_by_uuid(uuid)
+            .is_some_and(|connection| connection.managed())
+        {
+            return;
+        }
+
+        if self.take_connection(uuid) {
+            self.sync_to_disk(|_| {});
+        }
+    }
+
+    fn take_connection(&self, uuid: &str) -> bool {
         let mut connections = self.imp().connections.borrow_mut();
-        if let Some((position, _, _)) = connections.shift_remove_full(uuid) {
-            drop(connections);
+        match connections.shift_remove_full(uuid) {
+            Some((position, _, _)) => {
+                drop(connections);
 
-            self.items_changed(position as u32, 1, 0);
+                self.items_changed(position as u32, 1, 0);
 
-            if self
-                .client()
-                .map(|client| client.connection().uuid() == uuid)
-                .unwrap_or(false)
-            {
-                self.set_client(None);
-            }
+                self.disconnect(uuid);
 
-            self.sync_to_disk(|_| {});
+                true
+            }
+            None => false,
         }
     }
 
@@ -271,6 +656,8 @@ impl ConnectionManager {
             .any(model::Connection::is_local)
     }
 
+    /// Shows the client of the given connection. A client that is already connected is reused,
+    /// otherwise a new one is connected first.
     pub(crate) fn set_client_from<F>(&self, connection_uuid: &str, op: F)
     where
         F: Fn(anyhow::Result<()>) + 'static,
@@ -283,6 +670,42 @@ impl ConnectionManager {
             return;
         }
 
+        if let Some(client) = self.client_by_uuid(connection_uuid) {
+            self.set_client(Some(client));
+            op(Ok(()));
+            return;
+        }
+
+        self.connect_client(
+            connection_uuid,
+            Some(self.abort_registration()),
+            clone!(@weak self as obj => move |result| {
+                op(result.map(|client| obj.set_client(Some(client))));
+            }),
+        );
+    }
+
+    /// Connects to the given connection in the background without showing its client.
+    pub(crate) fn connect_to<F>(&self, connection_uuid: &str, op: F)
+    where
+        F: Fn(anyhow::Result<()>) + 'static,
+    {
+        if self.client_by_uuid(connection_uuid).is_some() {
+            op(Ok(()));
+            return;
+        }
+
+        self.connect_client(connection_uuid, None, move |result| op(result.map(|_| ())));
+    }
+
+    fn connect_client<F>(
+        &self,
+        connection_uuid: &str,
+        abort_registration: Option<future::AbortRegistration>,
+        op: F,
+    ) where
+        F: Fn(anyhow::Result<model::Client>) + 'static,
+    {
         let connection = match self
             .connection_by_uuid(connection_uuid)
             .ok_or_else(|| anyhow::anyhow!("connection not found"))
@@ -294,42 +717,159 @@ impl ConnectionManager {
             }
         };
 
+        // Another caller is already connecting, so wait for its result instead of connecting twice.
         if connection.connecting() {
+            let handler = Rc::new(RefCell::new(None));
+            handler.replace(Some(connection.connect_connecting_notify(
+                clone!(@weak self as obj, @strong handler => move |connection| {
+                    if connection.connecting() {
+                        return;
+                    }
+                    if let Some(handler) = handler.take() {
+                        connection.disconnect(handler);
+                    }
+                    op(obj.client_by_uuid(&connection.uuid()).ok_or_else(|| {
+                        anyhow::anyhow!("connecting to '{}' has failed", connection.name())
+                    }));
+                }),
+            )));
             return;
         }
         connection.set_connecting(true);
 
-        let client = match model::Client::try_from(&connection).map_err(anyhow::Error::from) {
-            Ok(client) => client,
-            Err(e) => {
-                op(Err(e));
-                return;
-            }
-        };
-
-        utils::do_async(
-            {
-                let podman = client.podman();
-                let abort_registration = self.abort_registration();
-                async move { future::Abortable::new(podman.ping(), abort_registration).await }
-            },
-            clone!(@weak self as obj => move |result| {
-                if let Ok(result) = result {
-                    match result {
-                        Ok(_) => {
-                            obj.set_client(Some(client));
+        self.create_client(
+            &connection.clone(),
+            clone!(@weak self as obj => move |result| match result {
+                Ok(client) => utils::do_async(
+                    {
+                        let podman = client.podman();
+                        async move {
+                            match abort_registration {
+                                Some(abort_registration) => {
+                                    future::Abortable::new(podman.ping(), abort_registration).await
+                                }
+                                None => Ok(podman.ping().await),
+                            }
                         }
-                        Err(ref e) => {
-                            log::error!("Failed to search for images: {}", e);
+                    },
+                    clone!(@weak obj => move |result| {
+                        if let Ok(result) = result {
+                            match result {
+                                Ok(_) => {
+                                    obj.add_client(&client);
+                                    op(Ok(client));
+                                }
+                                Err(e) => {
+                                    log::error!("Failed to connect to Podman: {}", e);
+                                    model::TlsInfo::remove_cert_dir(&connection.uuid());
+                                    op(Err(model::connect_error(e)));
+                                }
+                            }
                         }
-                    }
-                    op(result.map(|_| ()).map_err(anyhow::Error::from));
+                        connection.set_connecting(false);
+                    }),
+                ),
+                Err(e) => {
+                    log::error!("Failed to create client: {e}");
+                    connection.set_connecting(false);
+                    op(Err(e));
                 }
-                connection.set_connecting(false);
             }),
         );
     }
 
+    /// Creates a client for the connection. SSH connections need their tunnel to be opened first.
+    fn create_client<F>(&self, connection: &model::Connection, op: F)
+    where
+        F: FnOnce(anyhow::Result<model::Client>) + 'static,
+    {
+        match connection.ssh() {
+            Some(ssh) => {
+                let uuid = connection.uuid();
+                let connection = connection.clone();
+
+                utils::do_async(
+                    model::SshTunnel::open_with_stored_passphrase(uuid, ssh),
+                    move |result| {
+                        op(result.and_then(|ssh_tunnel| {
+                            model::Client::with_ssh_tunnel(&connection, ssh_tunnel)
+                        }))
+                    },
+                );
+            }
+            None => match connection.tls() {
+                Some(tls) => {
+                    let connection = connection.clone();
+
+                    utils::do_async(tls.prepare_cert_dir(connection.uuid()), move |result| {
+                        let result = result
+                            .and_then(|cert_dir| model::Client::with_tls(&connection, cert_dir));
+                        if result.is_err() {
+                            model::TlsInfo::remove_cert_dir(&connection.uuid());
+                        }
+                        op(result)
+                    });
+                }
+                None => op(model::Client::try_from(connection)),
+            },
+        }
+    }
+
+    fn add_client(&self, client: &model::Client) {
+        let connection = client.connection();
+
+        self.imp()
+            .clients
+            .borrow_mut()
+            .insert(connection.uuid(), client.clone());
+        connection.set_connected(true);
+
+        self.emit_by_name::<()>("client-added", &[client]);
+    }
+
+    /// Closes the client of the given connection. If it is currently shown, no client is shown
+    /// afterwards.
+    pub(crate) fn disconnect(&self, connection_uuid: &str) {
+        let client = self
+            .imp()
+            .clients
+            .borrow_mut()
+            .shift_remove(connection_uuid);
+
+        if let Some(client) = client {
+            client.connection().set_connected(false);
+
+            if self.client().as_ref() == Some(&client) {
+                self.set_client(None);
+            }
+        }
+
+        // The copy contains the private key of the client certificate.
+        model::TlsInfo::remove_cert_dir(connection_uuid);
+    }
+
+    pub(crate) fn client_by_uuid(&self, connection_uuid: &str) -> Option<model::Client> {
+        self.imp().clients.borrow().get(connection_uuid).cloned()
+    }
+
+    /// Returns all connected clients.
+    pub(crate) fn clients(&self) -> Vec<model::Client> {
+        self.imp().clients.borrow().values().cloned().collect()
+    }
+
+    pub(crate) fn connect_client_added<F: Fn(&Self, &model::Client) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("client-added", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            let client = values[1].get::<model::Client>().unwrap();
+            f(&obj, &client);
+
+            None
+        })
+    }
+
     fn set_client(&self, value: Option<model::Client>) {
         if self.client() == value {
             return;
@@ -356,10 +896,6 @@ impl ConnectionManager {
         self.notify_client();
     }
 
-    pub(crate) fn unset_client(&self) {
-        self.set_client(None);
-    }
-
     pub(crate) fn is_connecting(&self) -> bool {
         let imp = self.imp();
         imp.creating_new_connection.get()
@@ -398,6 +934,15 @@ impl ConnectionManager {
         self.imp().connections.borrow().get(uuid).cloned()
     }
 
+    pub(crate) fn connection_by_name(&self, name: &str) -> Option<model::Connection> {
+        self.imp()
+            .connections
+            .borrow()
+            .values()
+            .find(|connection| connection.name() == name)
+            .cloned()
+    }
+
     pub(crate) fn position_by_uuid(&self, uuid: &str) -> u32 {
         self.imp()
             .connections
@@ -411,3 +956,40 @@ impl ConnectionManager {
 fn path() -> PathBuf {
     utils::config_dir().join("connections.json")
 }
+
+/// Parses connections in the format of [`ConnectionManager::export_connections`].
+pub(crate) fn connection_infos_from_slice(
+    buf: &[u8],
+) -> anyhow::Result<Vec<model::ConnectionInfo>> {
+    let connection_infos = serde_json::from_slice::<IndexMap<String, model::ConnectionInfo>>(buf)?
+        .into_values()
+        .collect::<Vec<_>>();
+
+    connection_infos
+        .iter()
+        .filter_map(|info| info.ssh.as_ref())
+        .try_for_each(model::SshInfo::validate)?;
+
+    Ok(connection_infos)
+}
+
+/// Loads the read-only connections that are provisioned by the system administrator, e.g. in
+/// `/etc/xdg/pods/connections.json`.
+fn load_managed() -> anyhow::Result<IndexMap<String, model::ConnectionInfo>> {
+    glib::system_config_dirs()
+        .into_iter()
+        .map(|dir| dir.join("pods").join("connections.json"))
+        .filter(|path| path.exists())
+        .try_fold(IndexMap::new(), |mut connections, path| {
+            let buf = std::fs::read(path)?;
+            serde_json::from_slice::<IndexMap<String, model::ConnectionInfo>>(&buf)
+                .map(|managed| {
+                    // Directories listed first take precedence.
+                    managed.into_iter().for_each(|(uuid, info)| {
+                        connections.entry(uuid).or_insert(info);
+                    });
+                    connections
+                })
+                .map_err(anyhow::Error::from)
+        })
+}
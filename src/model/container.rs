@@ -140,6 +140,9 @@ mod imp {
             >,
         >,
         pub(super) mounts: OnceCell<HashSet<String>>,
+        pub(super) networks: RefCell<HashSet<String>>,
+        /// The shells that have been detected for the terminal.
+        pub(super) shells: OnceCell<Vec<&'static str>>,
         #[property(get, set, construct_only, nullable)]
         pub(super) container_list: glib::WeakRef<model::ContainerList>,
         #[property(get, set)]
@@ -168,8 +171,11 @@ mod imp {
         pub(super) pod_id: OnceCell<Option<String>>,
         #[property(get = Self::ports, set, construct_only, nullable)]
         pub(super) ports: OnceCell<model::PortMappingList>,
-        #[property(get, set, nullable)]
+        #[property(get, set = Self::set_stats, explicit_notify, nullable)]
         pub(super) stats: RefCell<Option<BoxedContainerStats>>,
+        pub(super) stats_history: RefCell<model::StatsHistory>,
+        #[property(get, set, construct)]
+        pub(super) exit_code: Cell<i32>,
         #[property(get, set = Self::set_status, construct, explicit_notify, builder(Status::default()))]
         pub(super) status: Cell<Status>,
         #[property(get, set, construct)]
@@ -192,7 +198,12 @@ mod imp {
     impl ObjectImpl for Container {
         fn signals() -> &'static [Signal] {
             static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
-            SIGNALS.get_or_init(|| vec![Signal::builder("deleted").build()])
+            SIGNALS.get_or_init(|| {
+                vec![
+                    Signal::builder("deleted").build(),
+                    Signal::builder("networks-changed").build(),
+                ]
+            })
         }
 
         fn properties() -> &'static [glib::ParamSpec] {
@@ -257,6 +268,27 @@ mod imp {
             obj.notify_status();
         }
 
+        pub(super) fn set_stats(&self, value: Option<BoxedContainerStats>) {
+            let obj = &*self.obj();
+            if obj.stats() == value {
+                return;
+            }
+            if let Some(ref stats) = value {
+                let mut history = self.stats_history.borrow_mut();
+                history.set_duration(
+                    obj.container_list()
+                        .map(|list| list.stats_history_duration())
+                        .unwrap_or(model::DEFAULT_STATS_HISTORY_DURATION),
+                );
+                history.push(model::StatsSample::new(
+                    glib::real_time() / 1_000_000,
+                    stats,
+                ));
+            }
+            self.stats.replace(value);
+            obj.notify_stats();
+        }
+
         pub(super) fn volume_list(&self) -> model::ContainerVolumeList {
             self.volume_list.get_or_init(Default::default).to_owned()
         }
@@ -291,6 +323,7 @@ impl Container {
                 "health-status",
                 health_status(list_container.status.as_deref()),
             )
+            .property("exit-code", list_container.exit_code.unwrap_or_default())
             .property("id", list_container.id)
             .property("image-id", list_container.image_id)
             .property("image-name", list_container.image)
@@ -311,6 +344,9 @@ impl Container {
                 list_container.mounts.unwrap_or_default(),
             ))
             .unwrap();
+        obj.imp().networks.replace(HashSet::from_iter(
+            list_container.networks.unwrap_or_default(),
+        ));
         obj
     }
 
@@ -318,13 +354,49 @@ impl Container {
         self.imp().mounts.get().unwrap()
     }
 
+    /// The networks the container was connected to when it was last listed.
+    pub(crate) fn networks(&self) -> std::cell::Ref<'_, HashSet<String>> {
+        self.imp().networks.borrow()
+    }
+
+    fn set_networks(&self, value: HashSet<String>) {
+        if *self.networks() == value {
+            return;
+        }
+        self.imp().networks.replace(value);
+        self.emit_by_name::<()>("networks-changed", &[]);
+    }
+
+    /// The shells that have been detected in this container. Detection only runs once.
+    pub(crate) fn shells(&self) -> Option<&[&'static str]> {
+        self.imp().shells.get().map(Vec::as_slice)
+    }
+
+    pub(crate) fn set_shells(&self, shells: Vec<&'static str>) {
+        _ = self.imp().shells.set(shells);
+    }
+
+    /// Returns the recorded resource usage of this container as chart points.
+    pub(crate) fn stats_points(&self) -> Vec<model::StatsPoint> {
+        self.imp().stats_history.borrow().points()
+    }
+
+    /// Whether the container is in the state that is reported by the listing.
+    pub(crate) fn is_in_sync(&self, list_container: &podman::models::ListContainer) -> bool {
+        self.status() == status(list_container.state.as_deref())
+    }
+
     pub(crate) fn update(&self, list_container: podman::models::ListContainer) {
         self.set_action_ongoing(false);
         self.set_health_status(health_status(list_container.status.as_deref()));
         self.set_image_name(list_container.image);
         self.set_name(list_container.names.unwrap()[0].clone());
+        self.set_exit_code(list_container.exit_code.unwrap_or_default());
         self.set_status(status(list_container.state.as_deref()));
         self.set_up_since(list_container.started_at.unwrap());
+        self.set_networks(HashSet::from_iter(
+            list_container.networks.unwrap_or_default(),
+        ));
     }
 
     pub(crate) fn inspect<F>(&self, op: F)
@@ -532,6 +604,17 @@ impl Container {
         })
     }
 
+    pub(crate) fn connect_networks_changed<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("networks-changed", true, move |values| {
+            f(&values[0].get::<Self>().unwrap());
+
+            None
+        })
+    }
+
     pub(crate) fn has_pod(&self) -> bool {
         self.pod_id().filter(|id| !id.is_empty()).is_some()
     }
@@ -567,6 +650,15 @@ impl Container {
         matches!(self.status(), Status::Paused)
     }
 
+    /// Checkpointing relies on CRIU, which is only available for rootful Podman.
+    pub(crate) fn can_checkpoint(&self) -> bool {
+        matches!(self.status(), Status::Running)
+            && self
+                .container_list()
+                .and_then(|list| list.client())
+                .is_some_and(|client| !client.rootless())
+    }
+
     pub(crate) fn can_delete(&self) -> bool {
         !matches!(self.status(), Status::Running | Status::Paused)
     }
@@ -35,6 +35,9 @@ mod imp {
         pub(super) initialized: OnceCell<()>,
         #[property(get, set)]
         pub(super) selection_mode: Cell<bool>,
+        #[property(get, set)]
+        pub(super) stats_history_duration: Cell<u32>,
+        pub(super) settings: utils::PodsSettings,
     }
 
     #[glib::object_subclass]
@@ -71,6 +74,9 @@ mod imp {
                         glib::ParamSpecUInt::builder("running").read_only().build(),
                         glib::ParamSpecUInt::builder("stopped").read_only().build(),
                         glib::ParamSpecUInt::builder("stopping").read_only().build(),
+                        glib::ParamSpecUInt::builder("unhealthy")
+                            .read_only()
+                            .build(),
                         glib::ParamSpecUInt::builder("num-selected")
                             .read_only()
                             .build(),
@@ -97,6 +103,7 @@ mod imp {
                 "running" => obj.running().to_value(),
                 "stopped" => obj.stopped().to_value(),
                 "stopping" => obj.stopping().to_value(),
+                "unhealthy" => obj.unhealthy().to_value(),
                 "num-selected" => obj.num_selected().to_value(),
                 _ => self.derived_property(id, pspec),
             }
@@ -109,6 +116,11 @@ mod imp {
             model::AbstractContainerList::bootstrap(obj.upcast_ref());
             model::SelectableList::bootstrap(obj.upcast_ref());
 
+            self.settings
+                .bind("stats-history-duration", obj, "stats-history-duration")
+                .get()
+                .build();
+
             utils::run_stream(
                 obj.client().unwrap().podman().containers(),
                 |containers| {
@@ -216,6 +228,20 @@ impl ContainerList {
         self.n_items()
     }
 
+    /// Whether the list contains exactly the listed containers in their listed states.
+    pub(crate) fn is_in_sync(&self, list_containers: &[podman::models::ListContainer]) -> bool {
+        let list = self.imp().list.borrow();
+
+        list.len() == list_containers.len()
+            && list_containers.iter().all(|list_container| {
+                list_container
+                    .id
+                    .as_ref()
+                    .and_then(|id| list.get(id))
+                    .is_some_and(|container| container.is_in_sync(list_container))
+            })
+    }
+
     pub(crate) fn containers(&self) -> u32 {
         self.imp()
             .list
@@ -257,6 +283,18 @@ impl ContainerList {
         self.num_containers_of_status(model::ContainerStatus::Stopping)
     }
 
+    /// The number of containers whose health check is failing.
+    pub(crate) fn unhealthy(&self) -> u32 {
+        self.imp()
+            .list
+            .borrow()
+            .values()
+            .filter(|container| {
+                container.health_status() == model::ContainerHealthStatus::Unhealthy
+            })
+            .count() as u32
+    }
+
     pub(crate) fn num_containers_of_status(&self, status: model::ContainerStatus) -> u32 {
         self.imp()
             .list
@@ -376,10 +414,8 @@ impl ContainerList {
                     container.inspect(|_| {});
                 }
             }
-            _ => self.refresh(
-                self.get_container(&container_id).map(|_| container_id),
-                err_op,
-            ),
+            // A targeted listing also picks up containers that are not known yet.
+            _ => self.refresh(Some(container_id), err_op),
         }
     }
 }
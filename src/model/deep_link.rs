@@ -0,0 +1,156 @@
+use std::fmt;
+
+use gettextrs::gettext;
+use gtk::glib;
+
+/// The URI scheme of links that point to objects of a connection.
+pub(crate) const DEEP_LINK_SCHEME: &str = "pods";
+
+/// The kind of object a deep link points to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DeepLinkKind {
+    Container,
+    Pod,
+    Image,
+    Volume,
+    Network,
+}
+
+impl DeepLinkKind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Container => "container",
+            Self::Pod => "pod",
+            Self::Image => "image",
+            Self::Volume => "volume",
+            Self::Network => "network",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "container" => Self::Container,
+            "pod" => Self::Pod,
+            "image" => Self::Image,
+            "volume" => Self::Volume,
+            "network" => Self::Network,
+            _ => return None,
+        })
+    }
+}
+
+/// The page that is shown for the object a deep link points to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum DeepLinkPage {
+    #[default]
+    Details,
+    Logs,
+    Terminal,
+    Top,
+}
+
+impl DeepLinkPage {
+    fn name(self) -> Option<&'static str> {
+        match self {
+            Self::Details => None,
+            Self::Logs => Some("logs"),
+            Self::Terminal => Some("terminal"),
+            Self::Top => Some("top"),
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "logs" => Self::Logs,
+            "terminal" => Self::Terminal,
+            "top" => Self::Top,
+            _ => return None,
+        })
+    }
+
+    fn is_supported_by(self, kind: DeepLinkKind) -> bool {
+        match self {
+            Self::Details => true,
+            Self::Logs | Self::Terminal => kind == DeepLinkKind::Container,
+            Self::Top => matches!(kind, DeepLinkKind::Container | DeepLinkKind::Pod),
+        }
+    }
+}
+
+/// A link of the form `pods://<connection>/<kind>/<id>[/<page>]`. The connection is given by its
+/// name or its uuid and the object by its id, its name or a prefix of its id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DeepLink {
+    pub(crate) connection: String,
+    pub(crate) kind: DeepLinkKind,
+    pub(crate) id: String,
+    pub(crate) page: DeepLinkPage,
+}
+
+impl DeepLink {
+    pub(crate) fn new(connection: &str, kind: DeepLinkKind, id: &str) -> Self {
+        Self {
+            connection: connection.to_owned(),
+            kind,
+            id: id.to_owned(),
+            page: DeepLinkPage::Details,
+        }
+    }
+
+    pub(crate) fn parse(uri: &str) -> anyhow::Result<Self> {
+        let invalid = || anyhow::anyhow!(gettext!("Invalid link '{}'", uri));
+
+        // Connection names are not host names and must not be normalized as such.
+        let uri = glib::Uri::parse(uri, glib::UriFlags::ENCODED_PATH | glib::UriFlags::NON_DNS)?;
+        if uri.scheme() != DEEP_LINK_SCHEME {
+            return Err(invalid());
+        }
+
+        let connection = uri
+            .host()
+            .filter(|host| !host.is_empty())
+            .ok_or_else(invalid)?;
+
+        let path = uri.path();
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| glib::Uri::unescape_string(segment, None::<&str>).ok_or_else(invalid))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let (kind, id, page) = match segments.as_slice() {
+            [kind, id] => (kind, id, DeepLinkPage::Details),
+            [kind, id, page] => (kind, id, DeepLinkPage::from_name(page).ok_or_else(invalid)?),
+            _ => return Err(invalid()),
+        };
+        let kind = DeepLinkKind::from_name(kind).ok_or_else(invalid)?;
+
+        if !page.is_supported_by(kind) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            connection: connection.to_string(),
+            kind,
+            id: id.to_string(),
+            page,
+        })
+    }
+}
+
+impl fmt::Display for DeepLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{DEEP_LINK_SCHEME}://{}/{}/{}",
+            glib::Uri::escape_string(&self.connection, None, false),
+            self.kind.name(),
+            glib::Uri::escape_string(&self.id, None, false),
+        )?;
+
+        match self.page.name() {
+            Some(page) => write!(f, "/{page}"),
+            None => Ok(()),
+        }
+    }
+}
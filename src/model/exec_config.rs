@@ -0,0 +1,139 @@
+use futures::StreamExt;
+use gettextrs::gettext;
+use gtk::glib;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::podman;
+use crate::utils;
+
+/// The file that keeps the last used configs of all images.
+const FILE_NAME: &str = "exec-defaults.json";
+
+/// The shells that are offered for a terminal, in the order of preference.
+pub(crate) const SHELLS: &[&str] = &["/bin/bash", "/bin/zsh", "/bin/sh"];
+
+/// The options of an interactive session in a running container.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ExecConfig {
+    /// The command line, e.g. `/bin/bash` or `python3 -i`.
+    pub(crate) command: String,
+    /// The user as `name`, `uid` or `uid:gid`. The user of the container is used if it is empty.
+    pub(crate) user: String,
+    pub(crate) workdir: String,
+    pub(crate) env: Vec<(String, String)>,
+    pub(crate) privileged: bool,
+}
+
+impl Default for ExecConfig {
+    fn default() -> Self {
+        Self {
+            command: "/bin/sh".to_owned(),
+            user: String::new(),
+            workdir: String::new(),
+            env: Vec::new(),
+            privileged: false,
+        }
+    }
+}
+
+impl ExecConfig {
+    pub(crate) fn argv(&self) -> anyhow::Result<Vec<String>> {
+        let argv = glib::shell_parse_argv(&self.command)
+            .map_err(|e| anyhow::anyhow!(gettext!("Invalid command: {}", e.message())))?;
+
+        Ok(argv
+            .into_iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect())
+    }
+
+    pub(crate) fn create_opts(&self) -> anyhow::Result<podman::opts::ExecCreateOpts> {
+        let opts = podman::opts::ExecCreateOpts::builder()
+            .attach_stderr(true)
+            .attach_stdout(true)
+            .attach_stdin(true)
+            .tty(true)
+            .command(self.argv()?)
+            .env(self.env.iter().map(|(key, value)| format!("{key}={value}")))
+            .privileged(self.privileged);
+
+        let opts = if self.user.is_empty() {
+            opts
+        } else {
+            opts.user(&self.user)
+        };
+        let opts = if self.workdir.is_empty() {
+            opts
+        } else {
+            opts.working_dir(&self.workdir)
+        };
+
+        Ok(opts.build())
+    }
+
+    /// Returns the last config that has been used for a container of the image.
+    pub(crate) fn load_for_image(image: &str) -> Option<Self> {
+        utils::load_config_map::<Self>(FILE_NAME)
+            .map_err(|e| log::warn!("Error on loading exec defaults: {e}"))
+            .ok()
+            .and_then(|mut all_configs| all_configs.shift_remove(image))
+    }
+
+    pub(crate) fn store_for_image(&self, image: &str) {
+        let config = self.clone();
+        let image = image.to_owned();
+
+        utils::do_async(
+            // The defaults of all images are kept in the same file.
+            utils::update_config_map(FILE_NAME, move |all_configs| {
+                all_configs.insert(image, config);
+            }),
+            |result| {
+                if let Err(e) = result {
+                    log::error!("Error on saving exec defaults: {e}");
+                }
+            },
+        );
+    }
+}
+
+/// Returns the shells of [`SHELLS`] that can be run in the container.
+pub(crate) async fn detect_shells(container: podman::api::Container) -> Vec<&'static str> {
+    let mut shells = Vec::new();
+
+    for shell in SHELLS {
+        match runs(&container, shell).await {
+            Ok(true) => shells.push(*shell),
+            Ok(false) => {}
+            Err(e) => log::debug!("Error on detecting shell '{shell}': {e}"),
+        }
+    }
+
+    shells
+}
+
+async fn runs(container: &podman::api::Container, shell: &str) -> podman::Result<bool> {
+    let opts = podman::opts::ExecCreateOpts::builder()
+        .attach_stderr(true)
+        .attach_stdout(true)
+        .attach_stdin(false)
+        .tty(false)
+        .command([shell, "-c", "echo ok"])
+        .build();
+    let exec = container.create_exec(&opts).await?;
+
+    let opts = podman::opts::ExecStartOpts::builder().tty(false).build();
+    Ok(match exec.start(&opts).await? {
+        Some(multiplexer) => {
+            let (reader, _) = multiplexer.split();
+            let output = reader
+                .filter_map(|chunk| async move { chunk.ok().map(Vec::from) })
+                .concat()
+                .await;
+
+            String::from_utf8_lossy(&output).trim() == "ok"
+        }
+        None => false,
+    })
+}
@@ -214,7 +214,8 @@ impl ImageList {
         }
     }
 
-    pub(crate) fn refresh<F>(&self, err_op: F)
+    /// Lists the images. If an `id` is given, only that image is added or updated.
+    pub(crate) fn refresh<F>(&self, id: Option<String>, err_op: F)
     where
         F: FnOnce(super::RefreshError) + Clone + 'static,
     {
@@ -222,31 +223,42 @@ impl ImageList {
         utils::do_async(
             {
                 let podman = self.client().unwrap().podman();
+                let id = id.clone();
                 async move {
                     podman
                         .images()
-                        .list(&podman::opts::ImageListOpts::builder().all(true).build())
+                        .list(
+                            &podman::opts::ImageListOpts::builder()
+                                .all(true)
+                                .filter(
+                                    id.map(podman::Id::from)
+                                        .map(podman::opts::ImageListFilter::Id),
+                                )
+                                .build(),
+                        )
                         .await
                 }
             },
             clone!(@weak self as obj => move |result| {
                 match result {
                     Ok(summaries) => {
-                        let to_remove = obj
-                            .imp()
-                            .list
-                            .borrow()
-                            .keys()
-                            .filter(|id| {
-                                !summaries
-                                    .iter()
-                                    .any(|summary| summary.id.as_ref() == Some(id))
-                            })
-                            .cloned()
-                            .collect::<Vec<_>>();
-                        to_remove.iter().for_each(|id| {
-                            obj.remove_image(id);
-                        });
+                        if id.is_none() {
+                            let to_remove = obj
+                                .imp()
+                                .list
+                                .borrow()
+                                .keys()
+                                .filter(|id| {
+                                    !summaries
+                                        .iter()
+                                        .any(|summary| summary.id.as_ref() == Some(id))
+                                })
+                                .cloned()
+                                .collect::<Vec<_>>();
+                            to_remove.iter().for_each(|id| {
+                                obj.remove_image(id);
+                            });
+                        }
 
                         summaries.iter().for_each(|summary| {
                             let index = obj.len();
@@ -311,13 +323,20 @@ impl ImageList {
         F: FnOnce(super::RefreshError) + Clone + 'static,
     {
         match event.action.as_str() {
+            // Images that were created by Buildah only become known when they are tagged.
+            "tag" if self.get_image(&event.actor.id).is_none() => {
+                self.refresh(Some(event.actor.id), err_op)
+            }
             "tag" => self.tag(
                 &event.actor.id,
                 &format!("localhost/{}", event.actor.attributes.get("name").unwrap()),
             ),
             "untag" => self.untag(&event.actor.id, event.actor.attributes.get("name").unwrap()),
             "remove" => self.remove_image(&event.actor.id),
-            "build" | "pull" => self.refresh(err_op),
+            // The id of a "load" event is the name of the archive rather than of an image, which
+            // might also contain multiple images.
+            "load" => self.refresh(None, err_op),
+            "build" | "pull" | "import" => self.refresh(Some(event.actor.id), err_op),
             other => log::warn!("Unknown action: {other}"),
         }
     }
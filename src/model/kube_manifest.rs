@@ -0,0 +1,167 @@
+use std::fmt;
+
+use gettextrs::gettext;
+use serde::Deserialize;
+
+/// A summary of what `podman kube play` will create from a Kubernetes YAML file.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct KubeManifest {
+    pub(crate) pods: Vec<KubePod>,
+    pub(crate) volumes: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct KubePod {
+    pub(crate) name: String,
+    pub(crate) kind: KubeKind,
+    pub(crate) containers: Vec<KubeContainer>,
+}
+
+/// The resources that result in a pod.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum KubeKind {
+    #[default]
+    Pod,
+    Deployment,
+    DaemonSet,
+    Job,
+}
+
+impl KubeKind {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "Pod" => Self::Pod,
+            "Deployment" => Self::Deployment,
+            "DaemonSet" => Self::DaemonSet,
+            "Job" => Self::Job,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for KubeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Pod => gettext("Pod"),
+                Self::Deployment => gettext("Deployment"),
+                Self::DaemonSet => gettext("Daemon Set"),
+                Self::Job => gettext("Job"),
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct KubeContainer {
+    pub(crate) name: String,
+    pub(crate) image: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Document {
+    kind: String,
+    metadata: Metadata,
+    spec: Spec,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Metadata {
+    name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Spec {
+    containers: Vec<Container>,
+    init_containers: Vec<Container>,
+    volumes: Vec<Volume>,
+    template: Option<Template>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Template {
+    spec: Spec,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Container {
+    name: String,
+    image: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Volume {
+    persistent_volume_claim: Option<PersistentVolumeClaim>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PersistentVolumeClaim {
+    claim_name: String,
+}
+
+impl KubeManifest {
+    pub(crate) fn parse(yaml: &str) -> anyhow::Result<Self> {
+        let mut manifest = Self::default();
+
+        for document in serde_yaml::Deserializer::from_str(yaml) {
+            let document = Document::deserialize(document)?;
+
+            match KubeKind::from_name(&document.kind) {
+                Some(KubeKind::Pod) => {
+                    manifest.add_pod(document.metadata.name, KubeKind::Pod, document.spec)
+                }
+                // Podman appends '-pod' to the names of pods created from workload resources.
+                Some(kind) => {
+                    let spec = document.spec.template.map(|t| t.spec).unwrap_or_default();
+                    manifest.add_pod(format!("{}-pod", document.metadata.name), kind, spec);
+                }
+                None if document.kind == "PersistentVolumeClaim" => {
+                    manifest.add_volume(document.metadata.name)
+                }
+                None => log::debug!("Ignoring Kubernetes kind in preview: {}", document.kind),
+            }
+        }
+
+        if manifest.pods.is_empty() && manifest.volumes.is_empty() {
+            anyhow::bail!("no pods or volumes found");
+        }
+
+        Ok(manifest)
+    }
+
+    fn add_pod(&mut self, name: String, kind: KubeKind, spec: Spec) {
+        spec.volumes
+            .into_iter()
+            .filter_map(|volume| volume.persistent_volume_claim)
+            .for_each(|claim| self.add_volume(claim.claim_name));
+
+        self.pods.push(KubePod {
+            name,
+            kind,
+            containers: spec
+                .init_containers
+                .into_iter()
+                .chain(spec.containers)
+                .map(|container| KubeContainer {
+                    name: container.name,
+                    image: container.image,
+                })
+                .collect(),
+        });
+    }
+
+    fn add_volume(&mut self, name: String) {
+        if !name.is_empty() && !self.volumes.contains(&name) {
+            self.volumes.push(name);
+        }
+    }
+}
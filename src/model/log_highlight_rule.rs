@@ -0,0 +1,84 @@
+use gettextrs::gettext;
+use gtk::gio;
+use gtk::glib;
+use gtk::prelude::*;
+
+const SETTINGS_KEY: &str = "log-highlight-rules";
+
+/// A user defined pattern that is highlighted with a color in every container log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct LogHighlightRule {
+    pub(crate) pattern: String,
+    /// A color in a form that `gdk::RGBA::parse` understands.
+    pub(crate) color: String,
+}
+
+impl LogHighlightRule {
+    pub(crate) fn new(pattern: String, color: String) -> anyhow::Result<Self> {
+        let rule = Self { pattern, color };
+        rule.regex()?;
+        Ok(rule)
+    }
+
+    pub(crate) fn load(settings: &gio::Settings) -> Vec<Self> {
+        settings
+            .value(SETTINGS_KEY)
+            .get::<Vec<(String, String)>>()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pattern, color)| Self { pattern, color })
+            .collect()
+    }
+
+    pub(crate) fn store(settings: &gio::Settings, rules: &[Self]) -> anyhow::Result<()> {
+        settings.set_value(
+            SETTINGS_KEY,
+            &rules
+                .iter()
+                .map(|rule| (rule.pattern.clone(), rule.color.clone()))
+                .collect::<Vec<_>>()
+                .to_variant(),
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn connect_changed<F: Fn(&gio::Settings) + 'static>(
+        settings: &gio::Settings,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        settings.connect_changed(Some(SETTINGS_KEY), move |settings, _| f(settings))
+    }
+
+    pub(crate) fn regex(&self) -> anyhow::Result<glib::Regex> {
+        glib::Regex::new(
+            &self.pattern,
+            glib::RegexCompileFlags::OPTIMIZE,
+            glib::RegexMatchFlags::empty(),
+        )?
+        .ok_or_else(|| anyhow::anyhow!(gettext!("Invalid pattern '{}'", self.pattern)))
+    }
+
+    /// Returns the character ranges of all matches in the text.
+    pub(crate) fn matches(regex: &glib::Regex, text: &str) -> Vec<(i32, i32)> {
+        let text = glib::GString::from(text);
+        let mut ranges = Vec::new();
+
+        if let Some(match_info) = regex.match_(text.as_gstr(), glib::RegexMatchFlags::empty()) {
+            while match_info.matches() {
+                if let Some((start, end)) = match_info.fetch_pos(0) {
+                    if start < end {
+                        ranges.push((
+                            text[..start as usize].chars().count() as i32,
+                            text[..end as usize].chars().count() as i32,
+                        ));
+                    }
+                }
+                if !matches!(match_info.next(), Ok(true)) {
+                    break;
+                }
+            }
+        }
+
+        ranges
+    }
+}
@@ -0,0 +1,87 @@
+use gettextrs::gettext;
+use gtk::glib;
+
+/// A window of a log that is given by an optional start and an optional end.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct LogTimeRange {
+    pub(crate) since: Option<glib::DateTime>,
+    pub(crate) until: Option<glib::DateTime>,
+}
+
+impl LogTimeRange {
+    /// Parses both points of the range relative to the current time.
+    pub(crate) fn parse(since: &str, until: &str) -> anyhow::Result<Self> {
+        let now = glib::DateTime::now_local()?;
+
+        let range = Self {
+            since: parse_point(since, &now)?,
+            until: parse_point(until, &now)?,
+        };
+
+        if let (Some(since), Some(until)) = (&range.since, &range.until) {
+            if since >= until {
+                anyhow::bail!(gettext("The start of the range must be before its end"));
+            }
+        }
+
+        Ok(range)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.since.is_none() && self.until.is_none()
+    }
+
+    /// The start of the range in the form that the log endpoint expects.
+    pub(crate) fn since_unix(&self) -> Option<String> {
+        self.since.as_ref().map(|since| since.to_unix().to_string())
+    }
+
+    /// The end of the range in the form that the log endpoint expects.
+    pub(crate) fn until_unix(&self) -> Option<String> {
+        self.until.as_ref().map(|until| until.to_unix().to_string())
+    }
+}
+
+/// Parses a point in time like `now`, `90s`, `15m`, `2h`, `3d`, `1w`, `2024-05-01`,
+/// `2024-05-01 12:00` or an ISO 8601 date time. An empty string means that the range is open.
+fn parse_point(s: &str, now: &glib::DateTime) -> anyhow::Result<Option<glib::DateTime>> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(None);
+    }
+    if s == "now" {
+        return Ok(Some(now.clone()));
+    }
+
+    let invalid = || anyhow::anyhow!(gettext!("Invalid point in time '{}'", s));
+
+    if let Some(unit) = s.chars().last().filter(char::is_ascii_alphabetic) {
+        if let Ok(amount) = s[..s.len() - 1].trim_start_matches('-').parse::<i64>() {
+            let seconds = match unit {
+                's' => 1,
+                'm' => 60,
+                'h' => 60 * 60,
+                'd' => 24 * 60 * 60,
+                'w' => 7 * 24 * 60 * 60,
+                _ => return Err(invalid()),
+            };
+
+            let seconds = amount.checked_mul(seconds).ok_or_else(invalid)?;
+
+            return now
+                .add_seconds(-seconds as f64)
+                .map(Some)
+                .map_err(|_| invalid());
+        }
+    }
+
+    let iso8601 = match s.split_once(&[' ', 'T'][..]) {
+        Some((date, time)) if time.matches(':').count() == 1 => format!("{date}T{time}:00"),
+        Some((date, time)) => format!("{date}T{time}"),
+        None => format!("{s}T00:00:00"),
+    };
+
+    glib::DateTime::from_iso8601(&iso8601, Some(&glib::TimeZone::local()))
+        .map(Some)
+        .map_err(|_| invalid())
+}
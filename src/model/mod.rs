@@ -1,7 +1,11 @@
 mod abstract_container_list;
 mod action;
 mod action_list;
+mod alert_monitor;
+mod alert_rule;
+mod archive_upload;
 mod client;
+mod compose_project;
 mod connection;
 mod connection_manager;
 mod container;
@@ -9,7 +13,9 @@ mod container_data;
 mod container_list;
 mod container_volume;
 mod container_volume_list;
+mod deep_link;
 mod device;
+mod exec_config;
 mod health_check_log;
 mod health_check_log_list;
 mod image;
@@ -18,19 +24,34 @@ mod image_data;
 mod image_list;
 mod image_search_response;
 mod key_val;
+mod kube_manifest;
+mod log_highlight_rule;
+mod log_time_range;
 mod mount;
+mod network;
+mod network_attachment;
+mod network_list;
 mod pod;
 mod pod_data;
 mod pod_list;
+mod podman_connection;
 mod port_mapping;
 mod port_mapping_list;
 mod process;
 mod process_list;
 mod repo_tag;
 mod repo_tag_list;
+mod secret;
+mod secret_list;
+mod secret_mount;
 mod selectable;
 mod selectable_list;
 mod simple_container_list;
+mod ssh_tunnel;
+mod stats_history;
+mod structured_log;
+mod systemd_unit;
+mod tls;
 mod value;
 mod volume;
 mod volume_list;
@@ -48,11 +69,23 @@ pub(crate) use self::action::Action;
 pub(crate) use self::action::State as ActionState;
 pub(crate) use self::action::Type as ActionType;
 pub(crate) use self::action_list::ActionList;
+pub(crate) use self::alert_monitor::AlertMonitor;
+pub(crate) use self::alert_rule::AlertCondition;
+pub(crate) use self::alert_rule::AlertRule;
+pub(crate) use self::alert_rule::AlertSubject;
+pub(crate) use self::archive_upload::upload_archive;
+pub(crate) use self::archive_upload::ArchiveUploadEndpoint;
+pub(crate) use self::archive_upload::ArchiveUploadEvent;
 pub(crate) use self::client::Client;
 pub(crate) use self::client::ClientError;
+pub(crate) use self::compose_project::ComposeMount;
+pub(crate) use self::compose_project::ComposeProject;
+pub(crate) use self::compose_project::PROJECT_LABEL as COMPOSE_PROJECT_LABEL;
 pub(crate) use self::connection::Connection;
 pub(crate) use self::connection::ConnectionInfo;
+pub(crate) use self::connection_manager::connection_infos_from_slice;
 pub(crate) use self::connection_manager::ConnectionManager;
+pub(crate) use self::connection_manager::ImportConflictResolution;
 pub(crate) use self::container::BoxedContainerStats;
 pub(crate) use self::container::Container;
 pub(crate) use self::container::HealthStatus as ContainerHealthStatus;
@@ -62,7 +95,14 @@ pub(crate) use self::container_data::ContainerData;
 pub(crate) use self::container_list::ContainerList;
 pub(crate) use self::container_volume::ContainerVolume;
 pub(crate) use self::container_volume_list::ContainerVolumeList;
+pub(crate) use self::deep_link::DeepLink;
+pub(crate) use self::deep_link::DeepLinkKind;
+pub(crate) use self::deep_link::DeepLinkPage;
+pub(crate) use self::deep_link::DEEP_LINK_SCHEME;
 pub(crate) use self::device::Device;
+pub(crate) use self::exec_config::detect_shells;
+pub(crate) use self::exec_config::ExecConfig;
+pub(crate) use self::exec_config::SHELLS;
 pub(crate) use self::health_check_log::HealthCheckLog;
 pub(crate) use self::health_check_log_list::HealthCheckLogList;
 pub(crate) use self::image::Image;
@@ -71,13 +111,23 @@ pub(crate) use self::image_data::ImageData;
 pub(crate) use self::image_list::ImageList;
 pub(crate) use self::image_search_response::ImageSearchResponse;
 pub(crate) use self::key_val::KeyVal;
+pub(crate) use self::kube_manifest::KubeManifest;
+pub(crate) use self::log_highlight_rule::LogHighlightRule;
+pub(crate) use self::log_time_range::LogTimeRange;
 pub(crate) use self::mount::Mount;
 pub(crate) use self::mount::MountType;
 pub(crate) use self::mount::SELinux as MountSELinux;
+pub(crate) use self::network::BoxedNetwork;
+pub(crate) use self::network::Network;
+pub(crate) use self::network_attachment::NetworkAttachment;
+pub(crate) use self::network_list::NetworkList;
 pub(crate) use self::pod::Pod;
 pub(crate) use self::pod::Status as PodStatus;
 pub(crate) use self::pod_data::PodData;
 pub(crate) use self::pod_list::PodList;
+pub(crate) use self::podman_connection::files as podman_connection_files;
+pub(crate) use self::podman_connection::load as load_podman_connections;
+pub(crate) use self::podman_connection::PodmanConnection;
 pub(crate) use self::port_mapping::PortMapping;
 pub(crate) use self::port_mapping::Protocol as PortMappingProtocol;
 pub(crate) use self::port_mapping_list::PortMappingList;
@@ -85,11 +135,33 @@ pub(crate) use self::process::Process;
 pub(crate) use self::process_list::ProcessList;
 pub(crate) use self::repo_tag::RepoTag;
 pub(crate) use self::repo_tag_list::RepoTagList;
+pub(crate) use self::secret::BoxedSecret;
+pub(crate) use self::secret::Secret;
+pub(crate) use self::secret_list::SecretList;
+pub(crate) use self::secret_mount::SecretMount;
+pub(crate) use self::secret_mount::SecretMountType;
 pub(crate) use self::selectable::Selectable;
 pub(crate) use self::selectable::SelectableExt;
 pub(crate) use self::selectable_list::SelectableList;
 pub(crate) use self::selectable_list::SelectableListExt;
 pub(crate) use self::simple_container_list::SimpleContainerList;
+pub(crate) use self::ssh_tunnel::askpass as ssh_askpass;
+pub(crate) use self::ssh_tunnel::trust_host_keys as trust_ssh_host_keys;
+pub(crate) use self::ssh_tunnel::HostKeyCheck as SshHostKeyCheck;
+pub(crate) use self::ssh_tunnel::SshInfo;
+pub(crate) use self::ssh_tunnel::SshTunnel;
+pub(crate) use self::ssh_tunnel::ASKPASS_ENV as SSH_ASKPASS_ENV;
+pub(crate) use self::stats_history::StatsHistory;
+pub(crate) use self::stats_history::StatsPoint;
+pub(crate) use self::stats_history::StatsSample;
+pub(crate) use self::stats_history::DEFAULT_DURATION as DEFAULT_STATS_HISTORY_DURATION;
+pub(crate) use self::structured_log::LogFilter;
+pub(crate) use self::structured_log::LogLevel;
+pub(crate) use self::structured_log::StructuredLogLine;
+pub(crate) use self::systemd_unit::SystemdUnit;
+pub(crate) use self::tls::connect_error;
+pub(crate) use self::tls::TlsHandshakeError;
+pub(crate) use self::tls::TlsInfo;
 pub(crate) use self::value::Value;
 pub(crate) use self::volume::BoxedVolume;
 pub(crate) use self::volume::Volume;
@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use glib::prelude::*;
+use glib::subclass::prelude::*;
+use glib::subclass::Signal;
+use glib::Properties;
+use gtk::glib;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::NetworkAttachment)]
+    pub(crate) struct NetworkAttachment {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, nullable)]
+        pub(super) network: glib::WeakRef<model::Network>,
+        #[property(get, set)]
+        pub(super) aliases: RefCell<String>,
+        #[property(get, set)]
+        pub(super) ipv4_address: RefCell<String>,
+        #[property(get, set)]
+        pub(super) ipv6_address: RefCell<String>,
+        #[property(get, set)]
+        pub(super) mac_address: RefCell<String>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkAttachment {
+        const NAME: &'static str = "NetworkAttachment";
+        type Type = super::NetworkAttachment;
+    }
+
+    impl ObjectImpl for NetworkAttachment {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("remove-request").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkAttachment(ObjectSubclass<imp::NetworkAttachment>);
+}
+
+impl From<&model::Client> for NetworkAttachment {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl NetworkAttachment {
+    /// The options for the selected network as expected by the `networks` field of the container
+    /// and pod specs, or `None` if no network has been selected yet.
+    pub(crate) fn per_network_options(
+        &self,
+    ) -> Option<(String, podman::models::PerNetworkOptions)> {
+        let network = self.network()?;
+
+        let aliases = utils::split_list(&self.aliases());
+        let static_ips = [self.ipv4_address(), self.ipv6_address()]
+            .into_iter()
+            .map(|address| address.trim().to_owned())
+            .filter(|address| !address.is_empty())
+            .collect::<Vec<_>>();
+        let static_mac = self.mac_address().trim().to_owned();
+
+        Some((
+            network.name(),
+            podman::models::PerNetworkOptions {
+                aliases: Some(aliases).filter(|aliases| !aliases.is_empty()),
+                interface_name: None,
+                static_ips: Some(static_ips).filter(|static_ips| !static_ips.is_empty()),
+                static_mac: Some(static_mac).filter(|static_mac| !static_mac.is_empty()),
+            },
+        ))
+    }
+
+    pub(crate) fn remove_request(&self) {
+        self.emit_by_name::<()>("remove-request", &[]);
+    }
+
+    pub(crate) fn connect_remove_request<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("remove-request", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            f(&obj);
+
+            None
+        })
+    }
+}
@@ -95,6 +95,8 @@ mod imp {
         pub(super) infra_container: glib::WeakRef<model::Container>,
         #[property(get, set)]
         pub(super) action_ongoing: Cell<bool>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) compose_project: RefCell<Option<String>>,
         #[property(get, set, construct_only)]
         pub(super) created: OnceCell<i64>,
         #[property(get = Self::data, nullable)]
@@ -192,6 +194,13 @@ impl Pod {
     pub(crate) fn new(pod_list: &model::PodList, report: podman::models::ListPodsReport) -> Self {
         glib::Object::builder()
             .property("pod-list", pod_list)
+            .property(
+                "compose-project",
+                report
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.get(model::COMPOSE_PROJECT_LABEL).cloned()),
+            )
             .property(
                 "created",
                 report.created.map(|dt| dt.timestamp()).unwrap_or(0),
@@ -206,6 +215,12 @@ impl Pod {
             .build()
     }
 
+    /// Whether the pod is in the state that is reported by the listing.
+    pub(crate) fn is_in_sync(&self, report: &podman::models::ListPodsReport) -> bool {
+        self.status() == status(report.status.as_deref())
+            && self.num_containers() == report.containers.as_ref().map_or(0, |c| c.len() as u64)
+    }
+
     pub(crate) fn update(&self, report: podman::models::ListPodsReport) {
         self.set_action_ongoing(false);
         self.set_num_containers(report.containers.map(|c| c.len() as u64).unwrap_or(0));
@@ -162,6 +162,20 @@ impl PodList {
         self.n_items()
     }
 
+    /// Whether the list contains exactly the listed pods in their listed states.
+    pub(crate) fn is_in_sync(&self, reports: &[podman::models::ListPodsReport]) -> bool {
+        let list = self.imp().list.borrow();
+
+        list.len() == reports.len()
+            && reports.iter().all(|report| {
+                report
+                    .id
+                    .as_ref()
+                    .and_then(|id| list.get(id))
+                    .is_some_and(|pod| pod.is_in_sync(report))
+            })
+    }
+
     pub(crate) fn degraded(&self) -> u32 {
         self.num_pods_of_status(model::PodStatus::Degraded)
     }
@@ -291,7 +305,7 @@ impl PodList {
 
         match event.action.as_str() {
             "remove" => self.remove_pod(&pod_id),
-            _ => self.refresh(self.get_pod(&pod_id).map(|_| pod_id), err_op),
+            _ => self.refresh(Some(pod_id), err_op),
         }
     }
 
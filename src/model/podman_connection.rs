@@ -0,0 +1,176 @@
+use std::path::PathBuf;
+
+use gettextrs::gettext;
+use gtk::glib;
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::model;
+use crate::utils;
+
+/// The socket that Podman uses on remote hosts if the URI doesn't contain a path.
+const DEFAULT_REMOTE_SOCKET_PATH: &str = "/run/podman/podman.sock";
+
+/// A connection that is defined by Podman itself, either in `containers.conf` or in
+/// `podman-connections.json`, as listed by `podman system connection list`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PodmanConnection {
+    pub(crate) name: String,
+    pub(crate) uri: String,
+    pub(crate) identity: Option<String>,
+    /// Whether this is the connection that Podman uses by default.
+    pub(crate) default: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ContainersConf {
+    #[serde(default)]
+    engine: EngineConf,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct EngineConf {
+    active_service: Option<String>,
+    #[serde(default)]
+    service_destinations: IndexMap<String, ServiceDestination>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ServiceDestination {
+    uri: String,
+    identity: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ConnectionsFile {
+    #[serde(default)]
+    connection: ConnectionsConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ConnectionsConfig {
+    default: Option<String>,
+    #[serde(default)]
+    connections: IndexMap<String, Destination>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Destination {
+    #[serde(rename = "URI")]
+    uri: String,
+    identity: Option<String>,
+}
+
+impl PodmanConnection {
+    /// Returns the settings of the SSH tunnel for `ssh://` URIs.
+    pub(crate) fn ssh_info(&self) -> anyhow::Result<Option<model::SshInfo>> {
+        if !self.uri.starts_with("ssh://") {
+            return Ok(None);
+        }
+
+        let uri = glib::Uri::parse(&self.uri, glib::UriFlags::NONE)?;
+
+        let host = uri
+            .host()
+            .filter(|host| !host.is_empty())
+            .ok_or_else(|| anyhow::anyhow!(gettext!("Missing host in '{}'", self.uri)))?;
+        let user = uri
+            .user()
+            .map(|user| user.to_string())
+            .unwrap_or_else(|| glib::user_name().to_string_lossy().into_owned());
+        let path = uri.path();
+        let socket_path = if path.is_empty() || path.as_str() == "/" {
+            DEFAULT_REMOTE_SOCKET_PATH.to_owned()
+        } else {
+            path.to_string()
+        };
+
+        let ssh = model::SshInfo {
+            user,
+            host: host.to_string(),
+            port: u16::try_from(uri.port()).unwrap_or(22),
+            identity_file: self
+                .identity
+                .clone()
+                .filter(|identity| !identity.is_empty()),
+            // Podman itself falls back to the agent as well.
+            use_agent: true,
+            socket_path,
+        };
+        ssh.validate()?;
+
+        Ok(Some(ssh))
+    }
+
+    /// Returns the URL under which Pods connects to this connection.
+    pub(crate) fn url(&self) -> anyhow::Result<String> {
+        self.ssh_info()
+            .map(|ssh| ssh.map(|ssh| ssh.url()).unwrap_or_else(|| self.uri.clone()))
+    }
+}
+
+/// The files that Podman reads its connections from, in the order in which they override each
+/// other.
+pub(crate) fn files() -> Vec<PathBuf> {
+    let containers_dir = utils::host_config_dir().join("containers");
+
+    vec![
+        PathBuf::from("/usr/share/containers/containers.conf"),
+        PathBuf::from("/etc/containers/containers.conf"),
+        containers_dir.join("containers.conf"),
+        containers_dir.join("podman-connections.json"),
+    ]
+}
+
+/// Reads all connections that Podman knows about. Entries of `podman-connections.json` take
+/// precedence over the ones of `containers.conf`, just like in Podman.
+pub(crate) fn load() -> anyhow::Result<Vec<PodmanConnection>> {
+    let mut default = None;
+    let mut connections = IndexMap::<String, (String, Option<String>)>::new();
+
+    for path in files() {
+        if !path.exists() {
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&path)?;
+
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            let file = serde_json::from_str::<ConnectionsFile>(&content)?;
+
+            default = file.connection.default.or(default);
+            connections.extend(
+                file.connection
+                    .connections
+                    .into_iter()
+                    .map(|(name, destination)| (name, (destination.uri, destination.identity))),
+            );
+        } else {
+            let conf = toml::from_str::<ContainersConf>(&content)?;
+
+            default = conf.engine.active_service.or(default);
+            connections.extend(
+                conf.engine
+                    .service_destinations
+                    .into_iter()
+                    .map(|(name, destination)| (name, (destination.uri, destination.identity))),
+            );
+        }
+    }
+
+    Ok(connections
+        .into_iter()
+        .map(|(name, (uri, identity))| PodmanConnection {
+            default: default.as_ref() == Some(&name),
+            name,
+            uri,
+            identity,
+        })
+        .collect())
+}
@@ -0,0 +1,149 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::ops::Deref;
+use std::sync::OnceLock;
+
+use gio::prelude::*;
+use glib::clone;
+use glib::subclass::prelude::*;
+use glib::subclass::Signal;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+
+use crate::model;
+use crate::monad_boxed_type;
+use crate::podman;
+use crate::utils;
+
+monad_boxed_type!(pub(crate) BoxedSecret(podman::models::SecretInfoReport) impls Debug);
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::Secret)]
+    pub(crate) struct Secret {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) secret_list: glib::WeakRef<model::SecretList>,
+        #[property(get, set, construct_only)]
+        pub(super) inner: OnceCell<BoxedSecret>,
+        #[property(get)]
+        pub(super) to_be_deleted: Cell<bool>,
+        #[property(get, set)]
+        pub(super) selected: Cell<bool>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Secret {
+        const NAME: &'static str = "Secret";
+        type Type = super::Secret;
+        type Interfaces = (model::Selectable,);
+    }
+
+    impl ObjectImpl for Secret {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("deleted").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+
+    impl Secret {
+        pub(super) fn set_to_be_deleted(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.to_be_deleted() == value {
+                return;
+            }
+            self.to_be_deleted.set(value);
+            obj.notify("to-be-deleted");
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct Secret(ObjectSubclass<imp::Secret>) @implements model::Selectable;
+}
+
+impl Secret {
+    pub(crate) fn new(
+        secret_list: &model::SecretList,
+        inner: podman::models::SecretInfoReport,
+    ) -> Self {
+        glib::Object::builder()
+            .property("secret-list", secret_list)
+            .property("inner", BoxedSecret::from(inner))
+            .build()
+    }
+
+    pub(crate) fn id(&self) -> String {
+        self.inner().id.clone().unwrap_or_default()
+    }
+
+    pub(crate) fn name(&self) -> String {
+        self.inner()
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.name.clone())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn driver(&self) -> String {
+        self.inner()
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.driver.as_ref())
+            .and_then(|driver| driver.name.clone())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn delete<F>(&self, op: F)
+    where
+        F: FnOnce(&Self, podman::Result<()>) + 'static,
+    {
+        if let Some(secret) = self.api() {
+            self.imp().set_to_be_deleted(true);
+
+            utils::do_async(
+                async move { secret.delete().await },
+                clone!(@weak self as obj => move |result| {
+                    if let Err(ref e) = result {
+                        obj.imp().set_to_be_deleted(false);
+                        log::error!("Error on removing secret: {}", e);
+                    }
+                    op(&obj, result);
+                }),
+            );
+        }
+    }
+
+    pub(crate) fn api(&self) -> Option<podman::api::Secret> {
+        self.secret_list()
+            .unwrap()
+            .client()
+            .map(|client| podman::api::Secret::new(client.podman().deref().clone(), self.id()))
+    }
+
+    pub(super) fn emit_deleted(&self) {
+        self.emit_by_name::<()>("deleted", &[]);
+    }
+
+    pub(crate) fn connect_deleted<F: Fn(&Self) + 'static>(&self, f: F) -> glib::SignalHandlerId {
+        self.connect_local("deleted", true, move |values| {
+            f(&values[0].get::<Self>().unwrap());
+
+            None
+        })
+    }
+}
@@ -0,0 +1,279 @@
+use std::borrow::Borrow;
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use gio::prelude::*;
+use gio::subclass::prelude::*;
+use glib::clone;
+use glib::subclass::Signal;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use indexmap::map::Entry;
+use indexmap::IndexMap;
+
+use crate::model;
+use crate::model::prelude::*;
+use crate::podman;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::SecretList)]
+    pub(crate) struct SecretList {
+        pub(super) list: RefCell<IndexMap<String, model::Secret>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get)]
+        pub(super) listing: Cell<bool>,
+        #[property(get = Self::is_initialized, type = bool)]
+        pub(super) initialized: OnceCell<()>,
+        #[property(get, set)]
+        pub(super) selection_mode: Cell<bool>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretList {
+        const NAME: &'static str = "SecretList";
+        type Type = super::SecretList;
+        type Interfaces = (gio::ListModel, model::SelectableList);
+    }
+
+    impl ObjectImpl for SecretList {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                vec![
+                    Signal::builder("secret-added")
+                        .param_types([model::Secret::static_type()])
+                        .build(),
+                    Signal::builder("secret-removed")
+                        .param_types([model::Secret::static_type()])
+                        .build(),
+                ]
+            })
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: OnceLock<Vec<glib::ParamSpec>> = OnceLock::new();
+            PROPERTIES.get_or_init(|| {
+                Self::derived_properties()
+                    .iter()
+                    .cloned()
+                    .chain(vec![
+                        glib::ParamSpecUInt::builder("len").read_only().build(),
+                        glib::ParamSpecUInt::builder("num-selected")
+                            .read_only()
+                            .build(),
+                    ])
+                    .collect::<Vec<_>>()
+            })
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "len" => self.obj().len().to_value(),
+                "num-selected" => self.obj().num_selected().to_value(),
+                _ => self.derived_property(id, pspec),
+            }
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            let obj = &*self.obj();
+
+            model::SelectableList::bootstrap(obj.upcast_ref());
+
+            obj.connect_items_changed(|self_, _, _, _| self_.notify("len"));
+        }
+    }
+
+    impl ListModelImpl for SecretList {
+        fn item_type(&self) -> glib::Type {
+            model::Secret::static_type()
+        }
+
+        fn n_items(&self) -> u32 {
+            self.list.borrow().len() as u32
+        }
+
+        fn item(&self, position: u32) -> Option<glib::Object> {
+            self.list
+                .borrow()
+                .get_index(position as usize)
+                .map(|(_, obj)| obj.upcast_ref())
+                .cloned()
+        }
+    }
+
+    impl SecretList {
+        pub(super) fn is_initialized(&self) -> bool {
+            self.initialized.get().is_some()
+        }
+
+        pub(super) fn set_as_initialized(&self) {
+            if self.is_initialized() {
+                return;
+            }
+            self.initialized.set(()).unwrap();
+            self.obj().notify("initialized");
+        }
+
+        pub(super) fn set_listing(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.listing() == value {
+                return;
+            }
+            self.listing.set(value);
+            obj.notify("listing");
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretList(ObjectSubclass<imp::SecretList>)
+        @implements gio::ListModel, model::SelectableList;
+}
+
+impl From<&model::Client> for SecretList {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl SecretList {
+    pub(crate) fn len(&self) -> u32 {
+        self.n_items()
+    }
+
+    pub(crate) fn get_secret<Q: Borrow<str> + ?Sized>(&self, id: &Q) -> Option<model::Secret> {
+        self.imp().list.borrow().get(id.borrow()).cloned()
+    }
+
+    pub(crate) fn remove_secret(&self, id: &str) {
+        let mut list = self.imp().list.borrow_mut();
+        if let Some((idx, _, secret)) = list.shift_remove_full(id) {
+            drop(list);
+
+            self.items_changed(idx as u32, 1, 0);
+            self.secret_removed(&secret);
+            secret.emit_deleted();
+        }
+    }
+
+    pub(crate) fn refresh<F>(&self, err_op: F)
+    where
+        F: FnOnce(super::RefreshError) + Clone + 'static,
+    {
+        self.imp().set_listing(true);
+        utils::do_async(
+            {
+                let podman = self.client().unwrap().podman();
+                async move {
+                    podman
+                        .secrets()
+                        .list(&podman::opts::SecretListOpts::builder().build())
+                        .await
+                }
+            },
+            clone!(@weak self as obj => move |result| {
+                match result {
+                    Ok(secrets) => {
+                        let imp = obj.imp();
+
+                        let to_remove = imp
+                            .list
+                            .borrow()
+                            .keys()
+                            .filter(|id| {
+                                !secrets
+                                    .iter()
+                                    .any(|secret| secret.id.as_ref() == Some(*id))
+                            })
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        to_remove.iter().for_each(|id| {
+                            obj.remove_secret(id);
+                        });
+
+                        secrets.into_iter().for_each(|secret| {
+                            let index = obj.len();
+
+                            let mut list = imp.list.borrow_mut();
+                            if let Entry::Vacant(e) =
+                                list.entry(secret.id.clone().unwrap_or_default())
+                            {
+                                let secret = model::Secret::new(&obj, secret);
+                                e.insert(secret.clone());
+
+                                drop(list);
+
+                                obj.items_changed(index, 0, 1);
+                                obj.secret_added(&secret);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        log::error!("Error on retrieving secrets: {}", e);
+                        err_op(super::RefreshError);
+                    }
+                }
+                let imp = obj.imp();
+                imp.set_listing(false);
+                imp.set_as_initialized();
+            }),
+        );
+    }
+
+    pub(crate) fn handle_event<F>(&self, event: podman::models::Event, err_op: F)
+    where
+        F: FnOnce(super::RefreshError) + Clone + 'static,
+    {
+        match event.action.as_str() {
+            "remove" => self.remove_secret(&event.actor.id),
+            _ => self.refresh(err_op),
+        }
+    }
+
+    fn secret_added(&self, secret: &model::Secret) {
+        self.emit_by_name::<()>("secret-added", &[secret]);
+    }
+
+    pub(crate) fn connect_secret_added<F: Fn(&Self, &model::Secret) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("secret-added", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            let secret = values[1].get::<model::Secret>().unwrap();
+            f(&obj, &secret);
+
+            None
+        })
+    }
+
+    fn secret_removed(&self, secret: &model::Secret) {
+        self.emit_by_name::<()>("secret-removed", &[secret]);
+    }
+
+    pub(crate) fn connect_secret_removed<F: Fn(&Self, &model::Secret) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("secret-removed", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            let secret = values[1].get::<model::Secret>().unwrap();
+            f(&obj, &secret);
+
+            None
+        })
+    }
+}
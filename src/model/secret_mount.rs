@@ -0,0 +1,101 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use glib::prelude::*;
+use glib::subclass::prelude::*;
+use glib::subclass::Signal;
+use glib::Properties;
+use gtk::glib;
+
+use crate::model;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "SecretMountType")]
+pub(crate) enum SecretMountType {
+    #[default]
+    File,
+    Env,
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties)]
+    #[properties(wrapper_type = super::SecretMount)]
+    pub(crate) struct SecretMount {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, nullable)]
+        pub(super) secret: glib::WeakRef<model::Secret>,
+        #[property(get, set, builder(SecretMountType::default()))]
+        pub(super) mount_type: Cell<SecretMountType>,
+        // The file name below /run/secrets if SecretMountType::File is set, otherwise the name of
+        // the environment variable.
+        #[property(get, set)]
+        pub(super) target: RefCell<String>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretMount {
+        const NAME: &'static str = "SecretMount";
+        type Type = super::SecretMount;
+    }
+
+    impl ObjectImpl for SecretMount {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("remove-request").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretMount(ObjectSubclass<imp::SecretMount>);
+}
+
+impl From<&model::Client> for SecretMount {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl SecretMount {
+    /// The target of the secret. Falls back to the name of the secret if no target is given.
+    pub(crate) fn effective_target(&self) -> Option<String> {
+        let target = self.target().trim().to_owned();
+        if target.is_empty() {
+            self.secret().map(|secret| secret.name())
+        } else {
+            Some(target)
+        }
+    }
+
+    pub(crate) fn remove_request(&self) {
+        self.emit_by_name::<()>("remove-request", &[]);
+    }
+
+    pub(crate) fn connect_remove_request<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("remove-request", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            f(&obj);
+
+            None
+        })
+    }
+}
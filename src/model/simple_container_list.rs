@@ -46,6 +46,9 @@ mod imp {
                     glib::ParamSpecUInt::builder("running").read_only().build(),
                     glib::ParamSpecUInt::builder("stopped").read_only().build(),
                     glib::ParamSpecUInt::builder("stopping").read_only().build(),
+                    glib::ParamSpecUInt::builder("unhealthy")
+                        .read_only()
+                        .build(),
                 ]
             })
         }
@@ -64,6 +67,7 @@ mod imp {
                 "running" => obj.running().to_value(),
                 "stopped" => obj.stopped().to_value(),
                 "stopping" => obj.stopping().to_value(),
+                "unhealthy" => obj.unhealthy().to_value(),
                 _ => unimplemented!(),
             }
         }
@@ -128,6 +132,17 @@ impl SimpleContainerList {
             .and_then(glib::WeakRef::upgrade)
     }
 
+    pub(crate) fn get_container<Q: Borrow<str> + ?Sized>(
+        &self,
+        id: &Q,
+    ) -> Option<model::Container> {
+        self.imp()
+            .0
+            .borrow()
+            .get(id.borrow())
+            .and_then(glib::WeakRef::upgrade)
+    }
+
     pub(crate) fn add_container(&self, container: &model::Container) {
         let (index, _) = self.imp().0.borrow_mut().insert_full(container.id(), {
             let weak_ref = glib::WeakRef::new();
@@ -191,6 +206,19 @@ impl SimpleContainerList {
         self.num_containers_of_status(model::ContainerStatus::Stopping)
     }
 
+    /// The number of containers whose health check is failing.
+    pub(crate) fn unhealthy(&self) -> u32 {
+        self.imp()
+            .0
+            .borrow()
+            .values()
+            .filter_map(glib::WeakRef::upgrade)
+            .filter(|container| {
+                container.health_status() == model::ContainerHealthStatus::Unhealthy
+            })
+            .count() as u32
+    }
+
     pub(crate) fn num_containers_of_status(&self, status: model::ContainerStatus) -> u32 {
         self.imp()
             .0
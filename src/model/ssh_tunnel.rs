@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::os::unix::fs::DirBuilderExt;
+use std::os::unix::net::UnixListener;
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Child;
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::Instant;
+
+use gettextrs::gettext;
+use gtk::glib;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The environment variable that makes Pods act as `SSH_ASKPASS` program. Its value is the path
+/// of the socket from which the passphrase of the identity file is read.
+pub(crate) const ASKPASS_ENV: &str = "PODS_SSH_ASKPASS_SOCKET";
+
+const DEFAULT_PORT: u16 = 22;
+
+/// The time to wait for the forwarded socket to become available.
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+/// The settings of a connection to a remote Podman socket over SSH.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SshInfo {
+    pub(crate) user: String,
+    pub(crate) host: String,
+    pub(crate) port: u16,
+    pub(crate) identity_file: Option<String>,
+    pub(crate) use_agent: bool,
+    pub(crate) socket_path: String,
+}
+
+/// The result of looking up a host in `~/.ssh/known_hosts`.
+#[derive(Clone, Debug)]
+pub(crate) enum HostKeyCheck {
+    Known,
+    /// The host is not known yet. `keys` are the scanned keys in `known_hosts` format.
+    Unknown {
+        keys: String,
+        fingerprints: Vec<String>,
+    },
+}
+
+impl SshInfo {
+    /// Returns the URL in the format that is also used by `podman system connection`.
+    pub(crate) fn url(&self) -> String {
+        format!(
+            "ssh://{}@{}:{}{}",
+            self.user, self.host, self.port, self.socket_path
+        )
+    }
+
+    /// Makes sure that neither the user nor the host can be mistaken for an option by ssh.
+    pub(crate) fn validate(&self) -> anyhow::Result<()> {
+        if self.host.is_empty() {
+            anyhow::bail!(gettext("The SSH host must not be empty"));
+        }
+
+        [&self.user, &self.host].into_iter().try_for_each(|value| {
+            if value.starts_with('-') || value.chars().any(char::is_whitespace) {
+                anyhow::bail!(gettext!("Invalid SSH user or host '{}'", value));
+            }
+            Ok(())
+        })
+    }
+
+    fn destination(&self) -> String {
+        format!("{}@{}", self.user, self.host)
+    }
+
+    /// The name under which ssh lists the host in `known_hosts`.
+    fn known_hosts_name(&self) -> String {
+        if self.port == DEFAULT_PORT {
+            self.host.clone()
+        } else {
+            format!("[{}]:{}", self.host, self.port)
+        }
+    }
+
+    /// Looks up the host in `~/.ssh/known_hosts`. The keys of unknown hosts are scanned so that
+    /// the user can decide whether to trust them.
+    pub(crate) fn check_host_key(&self) -> anyhow::Result<HostKeyCheck> {
+        self.validate()?;
+
+        let known_hosts = known_hosts_path();
+
+        if known_hosts.exists()
+            && Command::new("ssh-keygen")
+                .arg("-F")
+                .arg(self.known_hosts_name())
+                .arg("-f")
+                .arg(&known_hosts)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()?
+                .success()
+        {
+            return Ok(HostKeyCheck::Known);
+        }
+
+        let output = Command::new("ssh-keyscan")
+            .arg("-p")
+            .arg(self.port.to_string())
+            .arg("--")
+            .arg(&self.host)
+            .stderr(Stdio::null())
+            .output()?;
+
+        let keys = String::from_utf8_lossy(&output.stdout).into_owned();
+        if keys.trim().is_empty() {
+            anyhow::bail!(gettext!(
+                "Could not retrieve the host keys of {}",
+                self.host
+            ));
+        }
+
+        let mut child = Command::new("ssh-keygen")
+            .args(["-l", "-f", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        child.stdin.take().unwrap().write_all(keys.as_bytes())?;
+
+        let fingerprints = String::from_utf8_lossy(&child.wait_with_output()?.stdout)
+            .lines()
+            .map(str::to_owned)
+            .collect();
+
+        Ok(HostKeyCheck::Unknown { keys, fingerprints })
+    }
+
+    /// Returns the passphrase of the identity file if it is stored in the keyring.
+    pub(crate) async fn passphrase(&self) -> anyhow::Result<Option<String>> {
+        let (Some(keyring), Some(identity_file)) =
+            (crate::KEYRING.get(), self.identity_file.as_deref())
+        else {
+            return Ok(None);
+        };
+
+        match keyring
+            .search_items(&attributes(identity_file))
+            .await?
+            .first()
+        {
+            Some(item) => Ok(Some(String::from_utf8(item.secret().await?.to_vec())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores the passphrase of the identity file in the keyring.
+    pub(crate) async fn store_passphrase(&self, passphrase: &str) -> anyhow::Result<()> {
+        let Some(identity_file) = self.identity_file.as_deref() else {
+            return Ok(());
+        };
+        let keyring = crate::KEYRING
+            .get()
+            .ok_or_else(|| anyhow::anyhow!(gettext("Secret Service is not available")))?;
+
+        keyring
+            .create_item(
+                &gettext!("SSH passphrase for {}", identity_file),
+                &attributes(identity_file),
+                passphrase.as_bytes(),
+                true,
+            )
+            .await
+            .map_err(anyhow::Error::from)
+    }
+}
+
+/// Adds the given keys to `~/.ssh/known_hosts`.
+pub(crate) fn trust_host_keys(keys: &str) -> std::io::Result<()> {
+    let path = known_hosts_path();
+
+    if let Some(dir) = path.parent() {
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(dir)?;
+    }
+
+    std::fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path)?
+        .write_all(keys.as_bytes())
+}
+
+fn known_hosts_path() -> PathBuf {
+    glib::home_dir().join(".ssh").join("known_hosts")
+}
+
+fn attributes(identity_file: &str) -> HashMap<&str, &str> {
+    HashMap::from([("type", "ssh-passphrase"), ("identity-file", identity_file)])
+}
+
+/// An ssh process that forwards a local Unix socket to the remote Podman socket. The process is
+/// terminated when the tunnel is dropped.
+#[derive(Debug)]
+pub(crate) struct SshTunnel {
+    child: Child,
+    socket_path: PathBuf,
+}
+
+impl SshTunnel {
+    /// Starts ssh and blocks until the forwarded socket is available. Host keys are never
+    /// accepted implicitly, so unknown hosts must have been trusted before.
+    pub(crate) fn open(id: &str, info: &SshInfo, passphrase: Option<&str>) -> anyhow::Result<Self> {
+        info.validate()?;
+
+        let dir = glib::user_runtime_dir().join("pods");
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(&dir)?;
+
+        let socket_path = dir.join(format!("ssh-{id}.sock"));
+        if socket_path.exists() {
+            std::fs::remove_file(&socket_path)?;
+        }
+
+        let mut command = Command::new("ssh");
+        command
+            .args(["-N", "-T"])
+            .args(["-o", "ExitOnForwardFailure=yes"])
+            .args(["-o", "StrictHostKeyChecking=yes"])
+            .args(["-o", "PreferredAuthentications=publickey"])
+            .args(["-o", "ServerAliveInterval=15"])
+            .arg("-p")
+            .arg(info.port.to_string())
+            .arg("-L")
+            .arg(format!("{}:{}", socket_path.display(), info.socket_path));
+
+        if let Some(ref identity_file) = info.identity_file {
+            command
+                .arg("-i")
+                .arg(identity_file)
+                .args(["-o", "IdentitiesOnly=yes"]);
+        }
+        if !info.use_agent {
+            command.args(["-o", "IdentityAgent=none"]);
+        }
+
+        // ssh only reads passphrases from a terminal or an askpass program. The passphrase is
+        // handed over through a socket so that it doesn't show up in the environment of ssh.
+        let askpass = match passphrase {
+            Some(passphrase) => {
+                let askpass = AskpassSocket::bind(&dir.join(format!("askpass-{id}.sock")))?;
+                command
+                    .env("SSH_ASKPASS", std::env::current_exe()?)
+                    .env("SSH_ASKPASS_REQUIRE", "force")
+                    .env(ASKPASS_ENV, &askpass.path);
+                Some((askpass, passphrase))
+            }
+            None => {
+                command.args(["-o", "BatchMode=yes"]);
+                None
+            }
+        };
+
+        let mut child = command
+            .arg("--")
+            .arg(info.destination())
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let start = Instant::now();
+        loop {
+            if socket_path.exists() {
+                // ssh keeps writing warnings while the tunnel is open. They are logged so that
+                // it never blocks on a full pipe.
+                if let Some(pipe) = child.stderr.take() {
+                    std::thread::spawn(move || {
+                        BufReader::new(pipe)
+                            .lines()
+                            .map_while(Result::ok)
+                            .for_each(|line| log::warn!("ssh: {line}"));
+                    });
+                }
+
+                return Ok(Self { child, socket_path });
+            }
+
+            if let Some((askpass, passphrase)) = &askpass {
+                askpass.serve(passphrase)?;
+            }
+
+            if let Some(status) = child.try_wait()? {
+                let mut stderr = String::new();
+                if let Some(mut pipe) = child.stderr.take() {
+                    pipe.read_to_string(&mut stderr)?;
+                }
+
+                anyhow::bail!(if stderr.trim().is_empty() {
+                    status.to_string()
+                } else {
+                    stderr.trim().to_owned()
+                });
+            }
+
+            if start.elapsed() > TIMEOUT {
+                let _ = child.kill();
+                anyhow::bail!(gettext("Timed out while connecting over SSH"));
+            }
+
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    /// Opens the tunnel in a blocking task with the passphrase that is stored in the keyring.
+    pub(crate) async fn open_with_stored_passphrase(
+        id: String,
+        info: SshInfo,
+    ) -> anyhow::Result<Self> {
+        let passphrase = info.passphrase().await.unwrap_or_else(|e| {
+            log::warn!("Error on reading SSH passphrase from keyring: {e}");
+            None
+        });
+
+        tokio::task::spawn_blocking(move || Self::open(&id, &info, passphrase.as_deref())).await?
+    }
+
+    pub(crate) fn url(&self) -> String {
+        format!("unix://{}", self.socket_path.display())
+    }
+}
+
+/// A socket from which the askpass program reads the passphrase. The socket lives in a directory
+/// that only the user can access and is removed when it is dropped.
+struct AskpassSocket {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+impl AskpassSocket {
+    fn bind(path: &Path) -> std::io::Result<Self> {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        let listener = UnixListener::bind(path)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            listener,
+            path: path.to_owned(),
+        })
+    }
+
+    /// Writes the passphrase to the askpass program if it is waiting for it.
+    fn serve(&self, passphrase: &str) -> std::io::Result<()> {
+        match self.listener.accept() {
+            Ok((mut stream, _)) => stream.write_all(passphrase.as_bytes()),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Drop for AskpassSocket {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Prints the passphrase that is served at the given socket. This is what Pods does when it is
+/// run by ssh as askpass program.
+pub(crate) fn askpass(socket_path: &Path) -> std::io::Result<()> {
+    let mut passphrase = String::new();
+    UnixStream::connect(socket_path)?.read_to_string(&mut passphrase)?;
+    println!("{passphrase}");
+    Ok(())
+}
+
+impl Drop for SshTunnel {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+
+use crate::podman;
+
+/// The maximum number of samples kept per history. Longer durations are covered by merging
+/// samples into coarser buckets.
+const MAX_SAMPLES: i64 = 720;
+
+pub(crate) const DEFAULT_DURATION: u32 = 15 * 60;
+
+/// A single recorded stats sample. Network and block I/O values are the cumulative counters as
+/// reported by Podman.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct StatsSample {
+    pub(crate) time: i64,
+    pub(crate) cpu: f64,
+    pub(crate) mem_usage: u64,
+    pub(crate) net_input: u64,
+    pub(crate) net_output: u64,
+    pub(crate) block_input: u64,
+    pub(crate) block_output: u64,
+}
+
+impl StatsSample {
+    pub(crate) fn new(time: i64, stats: &podman::models::ContainerStats) -> Self {
+        Self {
+            time,
+            cpu: stats.cpu.unwrap_or_default(),
+            mem_usage: stats.mem_usage.unwrap_or_default(),
+            net_input: stats.net_input.unwrap_or_default(),
+            net_output: stats.net_output.unwrap_or_default(),
+            block_input: stats.block_input.unwrap_or_default(),
+            block_output: stats.block_output.unwrap_or_default(),
+        }
+    }
+}
+
+/// A point of a chart. Network and block I/O values are rates in bytes per second.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct StatsPoint {
+    pub(crate) time: i64,
+    pub(crate) cpu: f64,
+    pub(crate) mem_usage: f64,
+    pub(crate) net_input: f64,
+    pub(crate) net_output: f64,
+    pub(crate) block_input: f64,
+    pub(crate) block_output: f64,
+}
+
+impl std::ops::AddAssign for StatsPoint {
+    fn add_assign(&mut self, other: Self) {
+        self.cpu += other.cpu;
+        self.mem_usage += other.mem_usage;
+        self.net_input += other.net_input;
+        self.net_output += other.net_output;
+        self.block_input += other.block_input;
+        self.block_output += other.block_output;
+    }
+}
+
+/// A ring buffer of stats samples covering the given duration in seconds.
+#[derive(Debug)]
+pub(crate) struct StatsHistory {
+    duration: u32,
+    samples: VecDeque<StatsSample>,
+}
+
+impl Default for StatsHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_DURATION)
+    }
+}
+
+impl StatsHistory {
+    pub(crate) fn new(duration: u32) -> Self {
+        Self {
+            duration,
+            samples: VecDeque::with_capacity(MAX_SAMPLES as usize + 1),
+        }
+    }
+
+    /// Changes the covered duration and merges the existing samples into the new buckets.
+    pub(crate) fn set_duration(&mut self, duration: u32) {
+        if self.duration == duration {
+            return;
+        }
+
+        let samples = std::mem::take(&mut self.samples);
+        self.duration = duration;
+        samples.into_iter().for_each(|sample| self.push(sample));
+    }
+
+    /// Records a sample. Samples falling into the same bucket are merged by keeping the peak CPU
+    /// and memory usage so that short spikes stay visible in coarse histories.
+    pub(crate) fn push(&mut self, sample: StatsSample) {
+        let bucket_size = Self::bucket_size(self.duration);
+
+        match self.samples.back_mut() {
+            Some(last) if last.time / bucket_size == sample.time / bucket_size => {
+                *last = StatsSample {
+                    cpu: last.cpu.max(sample.cpu),
+                    mem_usage: last.mem_usage.max(sample.mem_usage),
+                    ..sample
+                };
+            }
+            _ => self.samples.push_back(sample),
+        }
+
+        let oldest = sample.time - self.duration as i64;
+        while self
+            .samples
+            .front()
+            .filter(|sample| sample.time < oldest)
+            .is_some()
+            || self.samples.len() as i64 > MAX_SAMPLES
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Returns the chart points of this history. The rates are derived from the difference of two
+    /// consecutive samples. Counter resets, e.g. after a restart, are treated as zero.
+    pub(crate) fn points(&self) -> Vec<StatsPoint> {
+        self.samples
+            .iter()
+            .zip(self.samples.iter().skip(1))
+            .map(|(prev, next)| {
+                let elapsed = (next.time - prev.time).max(1) as f64;
+                let rate = |prev: u64, next: u64| next.saturating_sub(prev) as f64 / elapsed;
+
+                StatsPoint {
+                    time: next.time,
+                    cpu: next.cpu,
+                    mem_usage: next.mem_usage as f64,
+                    net_input: rate(prev.net_input, next.net_input),
+                    net_output: rate(prev.net_output, next.net_output),
+                    block_input: rate(prev.block_input, next.block_input),
+                    block_output: rate(prev.block_output, next.block_output),
+                }
+            })
+            .collect()
+    }
+
+    /// Sums up the points of several histories bucket by bucket, e.g. for all containers of a pod.
+    pub(crate) fn aggregate<I>(duration: u32, points: I) -> Vec<StatsPoint>
+    where
+        I: IntoIterator<Item = Vec<StatsPoint>>,
+    {
+        let bucket_size = Self::bucket_size(duration);
+
+        points
+            .into_iter()
+            .flatten()
+            .fold(BTreeMap::<i64, StatsPoint>::new(), |mut buckets, point| {
+                let bucket = point.time / bucket_size;
+                *buckets.entry(bucket).or_insert_with(|| StatsPoint {
+                    time: bucket * bucket_size,
+                    ..Default::default()
+                }) += point;
+                buckets
+            })
+            .into_values()
+            .collect()
+    }
+
+    /// Returns the number of seconds covered by a single sample for the given duration.
+    pub(crate) fn bucket_size(duration: u32) -> i64 {
+        (duration as i64 / MAX_SAMPLES).max(1)
+    }
+}
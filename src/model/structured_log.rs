@@ -0,0 +1,301 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use gettextrs::gettext;
+use serde_json::Map;
+use serde_json::Value;
+
+/// The fields that structured loggers commonly use for the level, the message and the time.
+const LEVEL_KEYS: &[&str] = &["level", "lvl", "severity", "log.level"];
+const MESSAGE_KEYS: &[&str] = &["msg", "message"];
+const TIMESTAMP_KEYS: &[&str] = &["time", "ts", "timestamp", "@timestamp"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl FromStr for LogLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "trace" => Self::Trace,
+            "debug" | "dbg" => Self::Debug,
+            "info" | "information" | "notice" => Self::Info,
+            "warn" | "warning" => Self::Warn,
+            "error" | "err" => Self::Error,
+            "fatal" | "critical" | "crit" | "panic" | "alert" | "emerg" => Self::Fatal,
+            _ => anyhow::bail!(gettext!("Unknown log level '{}'", s)),
+        })
+    }
+}
+
+impl LogLevel {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) => s.parse().ok(),
+            // The numeric levels of bunyan and pino.
+            Value::Number(n) => n.as_u64().map(|n| match n {
+                ..=10 => Self::Trace,
+                11..=20 => Self::Debug,
+                21..=30 => Self::Info,
+                31..=40 => Self::Warn,
+                41..=50 => Self::Error,
+                _ => Self::Fatal,
+            }),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+            Self::Fatal => "FATAL",
+        }
+    }
+
+    pub(crate) fn color(self) -> &'static str {
+        match self {
+            Self::Trace => "#77767b",
+            Self::Debug => "#3584e4",
+            Self::Info => "#2ec27e",
+            Self::Warn => "#e5a50a",
+            Self::Error => "#e01b24",
+            Self::Fatal => "#c061cb",
+        }
+    }
+}
+
+/// A log line that consists of a single JSON object.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct StructuredLogLine(Map<String, Value>);
+
+impl StructuredLogLine {
+    /// Returns `None` if the line isn't a JSON object.
+    pub(crate) fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            return None;
+        }
+
+        serde_json::from_str(line).ok().map(Self)
+    }
+
+    /// Looks up a field. Nested fields can be given as `a.b`.
+    pub(crate) fn field(&self, key: &str) -> Option<&Value> {
+        self.0.get(key).or_else(|| {
+            let mut keys = key.split('.');
+            keys.next()
+                .and_then(|key| self.0.get(key))
+                .and_then(|value| keys.try_fold(value, |value, key| value.get(key)))
+        })
+    }
+
+    fn first_field(&self, keys: &[&str]) -> Option<&Value> {
+        keys.iter().find_map(|key| self.field(key))
+    }
+
+    pub(crate) fn level(&self) -> Option<LogLevel> {
+        self.first_field(LEVEL_KEYS).and_then(LogLevel::from_value)
+    }
+
+    pub(crate) fn message(&self) -> Option<String> {
+        self.first_field(MESSAGE_KEYS).map(value_to_string)
+    }
+
+    pub(crate) fn timestamp(&self) -> Option<String> {
+        self.first_field(TIMESTAMP_KEYS).map(value_to_string)
+    }
+
+    /// Returns all top level fields except the level, the message and the time.
+    pub(crate) fn other_fields(&self) -> impl Iterator<Item = (&str, String)> {
+        self.0
+            .iter()
+            .filter(|(key, _)| {
+                !LEVEL_KEYS
+                    .iter()
+                    .chain(MESSAGE_KEYS)
+                    .chain(TIMESTAMP_KEYS)
+                    .any(|k| *k == key.as_str())
+            })
+            .map(|(key, value)| (key.as_str(), value_to_string(value)))
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_owned(),
+        other => other.to_string(),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+}
+
+impl Operator {
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Lt => ordering.is_lt(),
+            Self::Le => ordering.is_le(),
+            Self::Gt => ordering.is_gt(),
+            Self::Ge => ordering.is_ge(),
+            Self::Contains => unreachable!(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Condition {
+    key: String,
+    operator: Operator,
+    value: String,
+    /// The parsed value if the condition compares the level.
+    level: Option<LogLevel>,
+}
+
+impl FromStr for Condition {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow::anyhow!(gettext!("Invalid condition '{}'", s));
+
+        let position = s.find(&['=', '!', '<', '>', '~'][..]).ok_or_else(invalid)?;
+        let (key, rest) = s.split_at(position);
+
+        let (operator, value) = [
+            (">=", Operator::Ge),
+            ("<=", Operator::Le),
+            ("!=", Operator::Ne),
+            ("=", Operator::Eq),
+            (">", Operator::Gt),
+            ("<", Operator::Lt),
+            ("~", Operator::Contains),
+        ]
+        .into_iter()
+        .find_map(|(token, operator)| {
+            rest.strip_prefix(token)
+                .map(|value| (operator, value.trim_matches('"')))
+        })
+        .ok_or_else(invalid)?;
+
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(invalid());
+        }
+
+        let level = if LEVEL_KEYS.contains(&key) && operator != Operator::Contains {
+            Some(value.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            key: key.to_owned(),
+            operator,
+            value: value.to_owned(),
+            level,
+        })
+    }
+}
+
+impl Condition {
+    fn matches(&self, line: &StructuredLogLine) -> bool {
+        if let Some(level) = self.level {
+            return line
+                .level()
+                .is_some_and(|line_level| self.operator.holds(line_level.cmp(&level)));
+        }
+
+        match line.field(&self.key).map(value_to_string) {
+            Some(value) => match self.operator {
+                Operator::Contains => value.contains(&self.value),
+                operator => {
+                    operator.holds(match (value.parse::<f64>(), self.value.parse::<f64>()) {
+                        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                        _ => value.as_str().cmp(&self.value),
+                    })
+                }
+            },
+            None => self.operator == Operator::Ne,
+        }
+    }
+}
+
+/// A filter for structured log lines like `level>=warn request_id=abc`. All conditions must
+/// hold. Supported operators are `=`, `!=`, `<`, `<=`, `>`, `>=` and `~` (contains).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct LogFilter(Vec<Condition>);
+
+impl FromStr for LogFilter {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        tokenize(s)
+            .iter()
+            .map(|token| token.parse())
+            .collect::<anyhow::Result<_>>()
+            .map(Self)
+    }
+}
+
+impl LogFilter {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Lines that aren't structured always pass so that plain output like stack traces stays
+    /// visible between the filtered lines.
+    pub(crate) fn matches(&self, line: Option<&StructuredLogLine>) -> bool {
+        match line {
+            Some(line) => self.0.iter().all(|condition| condition.matches(line)),
+            None => true,
+        }
+    }
+}
+
+/// Splits the filter at whitespace outside of double quotes.
+fn tokenize(s: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quoted = false;
+
+    for c in s.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                token.push(c);
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+            }
+            c => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+
+    tokens
+}
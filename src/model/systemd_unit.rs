@@ -0,0 +1,357 @@
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use gtk::gio;
+use gtk::glib;
+use serde_json::Value;
+
+use crate::utils;
+
+/// Where a [`SystemdUnit`] needs to be installed to be picked up by systemd.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SystemdUnitKind {
+    /// A `.container` or `.pod` file that is translated by the Quadlet generator.
+    Quadlet,
+    /// A `.service` file as created by `podman generate systemd`.
+    Service,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct SystemdUnit {
+    pub(crate) kind: SystemdUnitKind,
+    pub(crate) file_name: String,
+    pub(crate) content: String,
+}
+
+impl SystemdUnit {
+    /// Renders a Quadlet `.container` file from the inspection data of a container.
+    ///
+    /// Containers that belong to a pod don't publish ports or join networks on their own, they
+    /// refer to the Quadlet `.pod` file of `pod_name` instead.
+    pub(crate) fn quadlet_container(data: &Value, pod_name: Option<&str>) -> Self {
+        let name = str_of(data, "/Name");
+        let mut container = Section::new("Container");
+
+        container.push("ContainerName", name);
+        container.push("Image", str_of(data, "/ImageName"));
+        container.push_opt("HostName", non_empty(str_of(data, "/Config/Hostname")));
+        container.push_opt("User", non_empty(str_of(data, "/Config/User")));
+        container.push_opt("WorkingDir", non_empty(str_of(data, "/Config/WorkingDir")));
+
+        strings_of(data, "/Config/Env")
+            .into_iter()
+            // These are always set by Podman itself.
+            .filter(|env| !env.starts_with("container=") && !env.starts_with("HOSTNAME="))
+            .for_each(|env| container.push("Environment", &quote(&env)));
+
+        match pod_name {
+            Some(pod_name) => container.push("Pod", &format!("{pod_name}.pod")),
+            None => {
+                publish_ports(data.pointer("/HostConfig/PortBindings"))
+                    .iter()
+                    .for_each(|port| container.push("PublishPort", port));
+
+                match str_of(data, "/HostConfig/NetworkMode") {
+                    "host" | "none" => {
+                        container.push("Network", str_of(data, "/HostConfig/NetworkMode"))
+                    }
+                    _ => networks(data.pointer("/NetworkSettings/Networks"))
+                        .iter()
+                        .for_each(|network| container.push("Network", network)),
+                }
+            }
+        }
+
+        data.pointer("/Mounts")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|mount| {
+                let source = match mount.get("Type").and_then(Value::as_str) {
+                    Some("volume") => mount.get("Name"),
+                    Some("bind") => mount.get("Source"),
+                    _ => None,
+                }?
+                .as_str()?;
+                let destination = mount.get("Destination")?.as_str()?;
+
+                Some(
+                    if mount.get("RW").and_then(Value::as_bool).unwrap_or(true) {
+                        format!("{source}:{destination}")
+                    } else {
+                        format!("{source}:{destination}:ro")
+                    },
+                )
+            })
+            .for_each(|volume| container.push("Volume", &volume));
+
+        let cmd = strings_of(data, "/Config/Cmd");
+        if !cmd.is_empty() {
+            container.push(
+                "Exec",
+                &cmd.iter()
+                    // systemd expands variables in command lines.
+                    .map(|arg| quote(&arg.replace('$', "$$")))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+        }
+
+        let mut service = Section::new("Service");
+        service.push_opt(
+            "Restart",
+            match str_of(data, "/HostConfig/RestartPolicy/Name") {
+                "always" | "unless-stopped" => Some("always"),
+                "on-failure" => Some("on-failure"),
+                _ => None,
+            },
+        );
+
+        Self::quadlet(
+            format!("{name}.container"),
+            &[unit_section(name), container, service, install_section()],
+        )
+    }
+
+    /// Renders a Quadlet `.pod` file from the inspection data of a pod.
+    pub(crate) fn quadlet_pod(data: &Value) -> Self {
+        let name = str_of(data, "/Name");
+        let mut pod = Section::new("Pod");
+
+        pod.push("PodName", name);
+
+        publish_ports(data.pointer("/InfraConfig/PortBindings"))
+            .iter()
+            .for_each(|port| pod.push("PublishPort", port));
+
+        if data
+            .pointer("/InfraConfig/HostNetwork")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+        {
+            pod.push("Network", "host");
+        } else {
+            strings_of(data, "/InfraConfig/Networks")
+                .into_iter()
+                .filter(|network| network != "podman")
+                .for_each(|network| pod.push("Network", &network));
+        }
+
+        Self::quadlet(
+            format!("{name}.pod"),
+            &[unit_section(name), pod, install_section()],
+        )
+    }
+
+    /// Splits the output of libpod's `generate systemd` endpoint into its units.
+    pub(crate) fn from_generated(units: &Value) -> Vec<Self> {
+        units
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, content)| {
+                content.as_str().map(|content| Self {
+                    kind: SystemdUnitKind::Service,
+                    file_name: format!("{name}.service"),
+                    content: content.to_owned(),
+                })
+            })
+            .collect()
+    }
+
+    fn quadlet(file_name: String, sections: &[Section]) -> Self {
+        let mut content = format!("# {file_name}\n# autogenerated by Pods\n");
+        sections
+            .iter()
+            .filter(|section| !section.entries.is_empty())
+            .for_each(|section| {
+                let _ = write!(content, "\n[{}]\n", section.name);
+                section.entries.iter().for_each(|(key, value)| {
+                    let _ = writeln!(content, "{key}={value}");
+                });
+            });
+
+        Self {
+            kind: SystemdUnitKind::Quadlet,
+            file_name,
+            content,
+        }
+    }
+
+    /// The directory of the current user in which systemd looks for units of this kind.
+    pub(crate) fn install_dir(&self) -> PathBuf {
+        let config_dir = utils::host_config_dir();
+
+        match self.kind {
+            SystemdUnitKind::Quadlet => config_dir.join("containers").join("systemd"),
+            SystemdUnitKind::Service => config_dir.join("systemd").join("user"),
+        }
+    }
+
+    /// The path the unit is installed to.
+    pub(crate) fn install_path(&self) -> PathBuf {
+        self.install_dir().join(&self.file_name)
+    }
+
+    /// Whether a unit with the same file name has already been installed.
+    pub(crate) fn is_installed(&self) -> bool {
+        self.install_path().exists()
+    }
+
+    pub(crate) fn install(&self) -> std::io::Result<PathBuf> {
+        let dir = self.install_dir();
+        std::fs::create_dir_all(&dir)?;
+
+        self.write_to(&dir)
+    }
+
+    /// Writes the unit into the directory. The content is written to a temporary file first, so
+    /// that systemd never picks up a partially written unit.
+    pub(crate) fn write_to(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        let path = dir.join(&self.file_name);
+        let tmp_path = dir.join(format!(".{}.tmp", self.file_name));
+
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(self.content.as_bytes())?;
+        file.sync_all()?;
+
+        std::fs::rename(tmp_path, &path)?;
+
+        Ok(path)
+    }
+
+    /// Asks the systemd user instance to reload its units, which also runs the Quadlet generator.
+    pub(crate) async fn reload_user_units() -> Result<(), glib::Error> {
+        gio::bus_get_future(gio::BusType::Session)
+            .await?
+            .call_future(
+                Some("org.freedesktop.systemd1"),
+                "/org/freedesktop/systemd1",
+                "org.freedesktop.systemd1.Manager",
+                "Reload",
+                None,
+                None,
+                gio::DBusCallFlags::NONE,
+                -1,
+            )
+            .await
+            .map(|_| ())
+    }
+}
+
+struct Section {
+    name: &'static str,
+    entries: Vec<(&'static str, String)>,
+}
+
+impl Section {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            entries: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, key: &'static str, value: &str) {
+        self.entries.push((key, value.to_owned()));
+    }
+
+    fn push_opt(&mut self, key: &'static str, value: Option<&str>) {
+        if let Some(value) = value {
+            self.push(key, value);
+        }
+    }
+}
+
+fn unit_section(name: &str) -> Section {
+    let mut section = Section::new("Unit");
+    section.push("Description", name);
+    section
+}
+
+fn install_section() -> Section {
+    let mut section = Section::new("Install");
+    section.push("WantedBy", "default.target");
+    section
+}
+
+fn str_of<'a>(data: &'a Value, pointer: &str) -> &'a str {
+    data.pointer(pointer).and_then(Value::as_str).unwrap_or("")
+}
+
+fn strings_of(data: &Value, pointer: &str) -> Vec<String> {
+    data.pointer(pointer)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(str::to_owned)
+        .collect()
+}
+
+fn non_empty(s: &str) -> Option<&str> {
+    Some(s).filter(|s| !s.is_empty())
+}
+
+fn publish_ports(port_bindings: Option<&Value>) -> Vec<String> {
+    port_bindings
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .flat_map(|(container_port, host_ports)| {
+            let container_port = container_port.trim_end_matches("/tcp").to_owned();
+
+            host_ports
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(move |host_port| {
+                    let host_ip = str_of(host_port, "/HostIp");
+                    let host_port = str_of(host_port, "/HostPort");
+
+                    if host_ip.is_empty() {
+                        format!("{host_port}:{container_port}")
+                    } else {
+                        format!("{host_ip}:{host_port}:{container_port}")
+                    }
+                })
+        })
+        .collect()
+}
+
+fn networks(networks: Option<&Value>) -> Vec<String> {
+    networks
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(|networks| networks.keys())
+        // The default network doesn't need to be stated explicitly.
+        .filter(|network| *network != "podman")
+        .cloned()
+        .collect()
+}
+
+/// Quotes a value the way systemd splits command lines and environment assignments.
+fn quote(s: &str) -> String {
+    if !s.is_empty()
+        && !s
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | '%'))
+    {
+        return s.to_owned();
+    }
+
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    s.chars().for_each(|c| match c {
+        '"' | '\\' => {
+            quoted.push('\\');
+            quoted.push(c);
+        }
+        '%' => quoted.push_str("%%"),
+        _ => quoted.push(c),
+    });
+    quoted.push('"');
+    quoted
+}
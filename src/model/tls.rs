@@ -0,0 +1,106 @@
+use std::error::Error;
+use std::fmt;
+use std::os::unix::fs::DirBuilderExt;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+
+use gettextrs::gettext;
+use gtk::glib;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The certificate files of a TCP connection that is secured by mutual TLS.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct TlsInfo {
+    pub(crate) ca_cert: String,
+    pub(crate) client_cert: String,
+    pub(crate) client_key: String,
+}
+
+impl TlsInfo {
+    /// Podman expects `ca.pem`, `cert.pem` and `key.pem` within a single directory. Therefore, the
+    /// files are copied to a private runtime directory of the connection, which is removed again
+    /// by [`Self::remove_cert_dir`].
+    pub(crate) async fn prepare_cert_dir(self, id: String) -> anyhow::Result<PathBuf> {
+        tokio::task::spawn_blocking(move || self.copy_to_cert_dir(&id))
+            .await?
+            .map_err(anyhow::Error::from)
+    }
+
+    /// The directory the certificate files of the connection are copied to.
+    pub(crate) fn cert_dir(id: &str) -> PathBuf {
+        glib::user_runtime_dir()
+            .join("pods")
+            .join(format!("tls-{id}"))
+    }
+
+    /// Removes the copied certificate files of the connection, most notably the private key.
+    pub(crate) fn remove_cert_dir(id: &str) {
+        if let Err(e) = std::fs::remove_dir_all(Self::cert_dir(id)) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("Error on removing certificate files: {e}");
+            }
+        }
+    }
+
+    fn copy_to_cert_dir(&self, id: &str) -> std::io::Result<PathBuf> {
+        let dir = Self::cert_dir(id);
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(&dir)?;
+
+        [
+            (&self.ca_cert, "ca.pem"),
+            (&self.client_cert, "cert.pem"),
+            (&self.client_key, "key.pem"),
+        ]
+        .into_iter()
+        .try_for_each(|(source, name)| {
+            let target = dir.join(name);
+            std::fs::copy(source, &target)?;
+            std::fs::set_permissions(&target, std::fs::Permissions::from_mode(0o600))
+        })?;
+
+        Ok(dir)
+    }
+}
+
+/// A failed TLS handshake, e.g. because the server rejected the client certificate or the server
+/// certificate isn't signed by the given CA.
+#[derive(Debug)]
+pub(crate) struct TlsHandshakeError(String);
+
+impl fmt::Display for TlsHandshakeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", gettext!("TLS handshake failed: {}", self.0))
+    }
+}
+
+impl Error for TlsHandshakeError {}
+
+/// Converts an error on connecting to Podman. Errors that are caused by the TLS layer become a
+/// [`TlsHandshakeError`] so that they can be told apart from an unreachable service.
+///
+/// The TLS transport of podman-api is built on OpenSSL, whose errors are wrapped by hyper's
+/// connect error.
+pub(crate) fn connect_error<E>(e: E) -> anyhow::Error
+where
+    E: Error + Send + Sync + 'static,
+{
+    let mut source: Option<&(dyn Error + 'static)> = Some(&e);
+    while let Some(error) = source {
+        // Plain I/O errors during the handshake are reported as `ssl::Error` as well.
+        let is_tls_error = error
+            .downcast_ref::<openssl::ssl::Error>()
+            .is_some_and(|e| e.ssl_error().is_some())
+            || error.is::<openssl::error::ErrorStack>();
+
+        if is_tls_error {
+            return anyhow::Error::new(TlsHandshakeError(error.to_string()));
+        }
+        source = error.source();
+    }
+
+    anyhow::Error::new(e)
+}
@@ -1,8 +1,11 @@
 use std::cell::RefCell;
 use std::fmt::Debug;
+use std::io::Write;
 use std::ops::Deref;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::Mutex;
 
 use adw::prelude::*;
 use ashpd::desktop::file_chooser::OpenFileRequest;
@@ -16,6 +19,9 @@ use gettextrs::ngettext;
 use glib::clone;
 use gtk::gio;
 use gtk::glib;
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 use crate::config;
 use crate::APPLICATION_OPTS;
@@ -55,6 +61,64 @@ pub(crate) fn config_dir() -> &'static PathBuf {
     &APPLICATION_OPTS.get().unwrap().config_dir
 }
 
+/// Reads a map that is stored as JSON file in the configuration directory. A missing file is
+/// treated as an empty map.
+pub(crate) fn load_config_map<V: DeserializeOwned>(
+    file_name: &str,
+) -> anyhow::Result<IndexMap<String, V>> {
+    match std::fs::read(config_dir().join(file_name)) {
+        Ok(buf) => serde_json::from_slice(&buf).map_err(anyhow::Error::from),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(IndexMap::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Changes a map that is stored as JSON file in the configuration directory, e.g. to update the
+/// entry of a single connection. The map is written to a temporary file that replaces the old
+/// file afterwards, so a failed write never leaves a truncated file behind.
+pub(crate) async fn update_config_map<V, F>(file_name: &'static str, f: F) -> anyhow::Result<()>
+where
+    V: Serialize + DeserializeOwned + Send + 'static,
+    F: FnOnce(&mut IndexMap<String, V>) + Send + 'static,
+{
+    // Concurrent updates of the same file would overwrite each other.
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    tokio::task::spawn_blocking(move || {
+        let _guard = LOCK.lock().unwrap();
+
+        let mut map = load_config_map::<V>(file_name)?;
+        f(&mut map);
+
+        std::fs::create_dir_all(config_dir())?;
+
+        let path = config_dir().join(file_name);
+        let tmp_path = config_dir().join(format!("{file_name}.tmp"));
+
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(&serde_json::to_vec_pretty(&map)?)?;
+        file.sync_all()?;
+
+        std::fs::rename(tmp_path, path).map_err(anyhow::Error::from)
+    })
+    .await?
+}
+
+/// The configuration directory of the host, which is where Podman and systemd look for user
+/// configuration.
+pub(crate) fn host_config_dir() -> PathBuf {
+    // Inside the Flatpak sandbox `XDG_CONFIG_HOME` points to the app's private directory.
+    std::env::var_os("HOST_XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            if Path::new("/.flatpak-info").exists() {
+                glib::home_dir().join(".config")
+            } else {
+                glib::user_config_dir()
+            }
+        })
+}
+
 pub(crate) fn unix_socket_url() -> String {
     format!(
         "unix://{}",
@@ -225,6 +289,37 @@ pub(crate) fn show_toast(widget: &gtk::Widget, title: impl Into<glib::GString>)
         );
 }
 
+/// Copies the text to the clipboard and confirms it with a toast.
+pub(crate) fn copy_to_clipboard(widget: &gtk::Widget, text: &str, toast: &str) {
+    widget.clipboard().set_text(text);
+    show_toast(widget, toast);
+}
+
+/// Calls `op` as soon as the given list has been initialized.
+pub(crate) fn on_initialized<F>(list: &glib::Object, op: F)
+where
+    F: FnOnce() + 'static,
+{
+    if list.property::<bool>("initialized") {
+        op();
+        return;
+    }
+
+    let op = RefCell::new(Some(op));
+    let handler = Rc::new(RefCell::new(None));
+    handler.replace(Some(list.connect_notify_local(
+        Some("initialized"),
+        clone!(@strong handler => move |list, _| {
+            if let Some(op) = op.take() {
+                op();
+            }
+            if let Some(handler) = handler.take() {
+                list.disconnect(handler);
+            }
+        }),
+    )));
+}
+
 pub(crate) fn show_error_toast(widget: &gtk::Widget, title: &str, msg: &str) {
     show_toast(widget, format!("{title}: {msg}"));
 }
@@ -280,6 +375,14 @@ where
     })
 }
 
+/// Splits a user provided list whose items are separated by commas and/or whitespace.
+pub(crate) fn split_list(s: &str) -> Vec<String> {
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
 // Function from https://gitlab.gnome.org/GNOME/fractal/-/blob/fractal-next/src/utils.rs
 pub(crate) fn do_async<R, Fut, F>(tokio_fut: Fut, glib_closure: F)
 where
@@ -82,7 +82,9 @@ mod imp {
 
             self.status_page
                 .set_icon_name(Some(match action.action_type() {
-                    PruneContainers | PruneImages | PrunePods | PruneVolumes => "eraser5-symbolic",
+                    PruneContainers | PruneImages | PrunePods | PruneVolumes | PruneNetworks => {
+                        "eraser5-symbolic"
+                    }
                     DownloadImage | BuildImage => "image-x-generic-symbolic",
                     PushImage => "put-symbolic",
                     Commit => "merge-symbolic",
@@ -90,7 +92,14 @@ mod imp {
                     CreateAndRunContainer => "media-playback-start-symbolic",
                     CopyFiles => "edit-copy-symbolic",
                     Pod => "pods-symbolic",
+                    PlayKube | Compose => "document-open-symbolic",
                     Volume => "drive-harddisk-symbolic",
+                    Network => "network-workgroup-symbolic",
+                    Secret => "dialog-password-symbolic",
+                    SaveImages | ExportContainer => "document-save-symbolic",
+                    LoadImages | ImportImage => "document-open-symbolic",
+                    Checkpoint => "document-save-symbolic",
+                    Restore => "view-refresh-symbolic",
                     _ => unimplemented!(),
                 }));
 
@@ -151,8 +160,19 @@ impl ActionPage {
                     CopyFiles => gettext("Copying Files"),
                     PrunePods => gettext("Pruning Pods"),
                     Pod => gettext("Creating Pod"),
+                    PlayKube => gettext("Playing Kubernetes YAML"),
+                    Compose => gettext("Bringing Up Compose Stack"),
+                    SaveImages => gettext("Saving Images"),
+                    LoadImages => gettext("Loading Images"),
+                    ExportContainer => gettext("Exporting Container"),
+                    ImportImage => gettext("Importing Image"),
+                    Checkpoint => gettext("Checkpointing Container"),
+                    Restore => gettext("Restoring Container"),
                     Volume => gettext("Creating Volume"),
                     PruneVolumes => gettext("Pruning Volumes"),
+                    Network => gettext("Creating Network"),
+                    PruneNetworks => gettext("Pruning Networks"),
+                    Secret => gettext("Creating Secret"),
                     _ => unreachable!(),
                 });
             }
@@ -169,8 +189,19 @@ impl ActionPage {
                     CopyFiles => gettext("Files Copied"),
                     PrunePods => gettext("Pods Pruned"),
                     Pod => gettext("Pod Created"),
+                    PlayKube => gettext("Kubernetes YAML Played"),
+                    Compose => gettext("Compose Stack Up"),
+                    SaveImages => gettext("Images Saved"),
+                    LoadImages => gettext("Images Loaded"),
+                    ExportContainer => gettext("Container Exported"),
+                    ImportImage => gettext("Image Imported"),
+                    Checkpoint => gettext("Container Checkpointed"),
+                    Restore => gettext("Container Restored"),
                     Volume => gettext("Volume Created"),
                     PruneVolumes => gettext("Volumes Pruned"),
+                    Network => gettext("Network Created"),
+                    PruneNetworks => gettext("Networks Pruned"),
+                    Secret => gettext("Secret Created"),
                     _ => unreachable!(),
                 });
             }
@@ -187,8 +218,19 @@ impl ActionPage {
                     CopyFiles => gettext("File Copying Aborted"),
                     PrunePods => gettext("Pod Pruning Aborted"),
                     Pod => gettext("Pod Creation Aborted"),
+                    PlayKube => gettext("Playing Kubernetes YAML Aborted"),
+                    Compose => gettext("Bringing Up Compose Stack Aborted"),
+                    SaveImages => gettext("Saving Images Aborted"),
+                    LoadImages => gettext("Loading Images Aborted"),
+                    ExportContainer => gettext("Exporting Container Aborted"),
+                    ImportImage => gettext("Importing Image Aborted"),
+                    Checkpoint => gettext("Checkpointing Container Aborted"),
+                    Restore => gettext("Restoring Container Aborted"),
                     Volume => gettext("Volume Creation Aborted"),
                     PruneVolumes => gettext("Volume Pruning Aborted"),
+                    Network => gettext("Network Creation Aborted"),
+                    PruneNetworks => gettext("Network Pruning Aborted"),
+                    Secret => gettext("Secret Creation Aborted"),
                     _ => unreachable!(),
                 });
             }
@@ -205,8 +247,19 @@ impl ActionPage {
                     CopyFiles => gettext("Copying Files Failed"),
                     PrunePods => gettext("Pruning Pods Failed"),
                     Pod => gettext("Creating Pod Failed"),
+                    PlayKube => gettext("Playing Kubernetes YAML Failed"),
+                    Compose => gettext("Bringing Up Compose Stack Failed"),
+                    SaveImages => gettext("Saving Images Failed"),
+                    LoadImages => gettext("Loading Images Failed"),
+                    ExportContainer => gettext("Exporting Container Failed"),
+                    ImportImage => gettext("Importing Image Failed"),
+                    Checkpoint => gettext("Checkpointing Container Failed"),
+                    Restore => gettext("Restoring Container Failed"),
                     Volume => gettext("Creating Volume Failed"),
                     PruneVolumes => gettext("Pruning Volumes Failed"),
+                    Network => gettext("Creating Network Failed"),
+                    PruneNetworks => gettext("Pruning Networks Failed"),
+                    Secret => gettext("Creating Secret Failed"),
                     _ => unreachable!(),
                 });
             }
@@ -225,9 +278,14 @@ impl ActionPage {
                         | PruneImages
                         | PrunePods
                         | PruneVolumes
+                        | PruneNetworks
                         | Commit
                         | CopyFiles
                         | PushImage
+                        | SaveImages
+                        | LoadImages
+                        | ExportContainer
+                        | Checkpoint
                 ),
         );
         self.action_set_enabled(
@@ -278,6 +336,10 @@ impl ActionPage {
                     view::PodDetailsPage::from(pod).upcast()
                 } else if let Some(volume) = artifact.downcast_ref::<model::Volume>() {
                     view::VolumeDetailsPage::from(volume).upcast()
+                } else if let Some(network) = artifact.downcast_ref::<model::Network>() {
+                    view::NetworkDetailsPage::from(network).upcast()
+                } else if let Some(secret) = artifact.downcast_ref::<model::Secret>() {
+                    view::SecretDetailsPage::from(secret).upcast()
                 } else {
                     unreachable!();
                 };
@@ -84,16 +84,20 @@ mod imp {
                     use model::ActionType::*;
 
                     match type_ {
-                        PruneContainers | PruneImages | PrunePods | PruneVolumes => {
-                            "eraser5-symbolic"
-                        }
+                        PruneContainers | PruneImages | PrunePods | PruneVolumes
+                        | PruneNetworks => "eraser5-symbolic",
                         DownloadImage => "folder-download-symbolic",
                         BuildImage => "build-configure-symbolic",
                         PushImage => "put-symbolic",
                         Commit => "merge-symbolic",
                         CreateAndRunContainer => "media-playback-start-symbolic",
-                        CreateContainer | Pod => "list-add-symbolic",
+                        CreateContainer | Pod | Network | Secret => "list-add-symbolic",
+                        PlayKube | Compose => "document-open-symbolic",
                         CopyFiles => "edit-copy-symbolic",
+                        SaveImages | ExportContainer => "document-save-symbolic",
+                        LoadImages | ImportImage => "document-open-symbolic",
+                        Checkpoint => "document-save-symbolic",
+                        Restore => "view-refresh-symbolic",
                         _ => unreachable!(),
                     }
                 }))
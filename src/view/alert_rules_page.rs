@@ -0,0 +1,227 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+
+const ACTION_ADD_RULE: &str = "alert-rules-page.add-rule";
+const ACTION_REMOVE_RULE: &str = "alert-rules-page.remove-rule";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::AlertRulesPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/alert_rules_page.ui")]
+    pub(crate) struct AlertRulesPage {
+        pub(super) subject: OnceCell<model::AlertSubject>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) alert_monitor: glib::WeakRef<model::AlertMonitor>,
+        #[property(get, set, construct_only)]
+        pub(super) name: OnceCell<String>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) rules_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) kind_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) threshold_spin_row: TemplateChild<adw::SpinRow>,
+        #[template_child]
+        pub(super) seconds_spin_row: TemplateChild<adw::SpinRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for AlertRulesPage {
+        const NAME: &'static str = "PdsAlertRulesPage";
+        type Type = super::AlertRulesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_ADD_RULE, None, |widget, _, _| {
+                widget.add_rule();
+            });
+            klass.install_action(
+                ACTION_REMOVE_RULE,
+                Some(glib::VariantTy::STRING),
+                |widget, _, data| {
+                    let id: String = data.unwrap().get().unwrap();
+                    widget.remove_rule(&id);
+                },
+            );
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for AlertRulesPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.window_title.set_subtitle(&obj.name());
+            self.on_kind_combo_row_notify_selected();
+
+            if let Some(alert_monitor) = obj.alert_monitor() {
+                alert_monitor.connect_rules_changed(clone!(@weak obj => move |_| {
+                    obj.update_rules();
+                }));
+            }
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for AlertRulesPage {}
+
+    #[gtk::template_callbacks]
+    impl AlertRulesPage {
+        #[template_callback]
+        fn on_kind_combo_row_notify_selected(&self) {
+            let (threshold, seconds) = match self.kind_combo_row.selected() {
+                0 => (Some((gettext("Threshold (%)"), 80.0)), Some(30.0)),
+                1 => (Some((gettext("Threshold (MiB)"), 512.0)), None),
+                4 => (Some((gettext("Restarts"), 3.0)), Some(60.0)),
+                _ => (None, None),
+            };
+
+            self.threshold_spin_row.set_visible(threshold.is_some());
+            if let Some((title, value)) = threshold {
+                self.threshold_spin_row.set_title(&title);
+                self.threshold_spin_row.set_value(value);
+            }
+
+            self.seconds_spin_row.set_visible(seconds.is_some());
+            if let Some(value) = seconds {
+                self.seconds_spin_row
+                    .set_title(&if self.kind_combo_row.selected() == 0 {
+                        gettext("Duration (Seconds)")
+                    } else {
+                        gettext("Time Window (Seconds)")
+                    });
+                self.seconds_spin_row.set_value(value);
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct AlertRulesPage(ObjectSubclass<imp::AlertRulesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl AlertRulesPage {
+    pub(crate) fn new(
+        alert_monitor: &model::AlertMonitor,
+        subject: model::AlertSubject,
+        name: &str,
+    ) -> Self {
+        let obj: Self = glib::Object::builder()
+            .property("alert-monitor", alert_monitor)
+            .property("name", name)
+            .build();
+
+        obj.imp().subject.set(subject).unwrap();
+        obj.update_rules();
+
+        obj
+    }
+
+    fn subject(&self) -> model::AlertSubject {
+        *self.imp().subject.get().unwrap()
+    }
+
+    fn update_rules(&self) {
+        let imp = self.imp();
+
+        imp.rules_list_box.remove_all();
+
+        if let Some(alert_monitor) = self.alert_monitor() {
+            alert_monitor
+                .rules_of(self.subject(), &self.name())
+                .into_iter()
+                .for_each(|rule| {
+                    let row = adw::ActionRow::builder()
+                        .title(rule.condition.to_string())
+                        .build();
+
+                    let button = gtk::Button::builder()
+                        .action_name(ACTION_REMOVE_RULE)
+                        .action_target(&rule.id.to_variant())
+                        .css_classes(["flat"])
+                        .icon_name("user-trash-symbolic")
+                        .tooltip_text(gettext("Remove Rule"))
+                        .valign(gtk::Align::Center)
+                        .build();
+                    row.add_suffix(&button);
+
+                    imp.rules_list_box.append(&row);
+                });
+        }
+    }
+
+    fn add_rule(&self) {
+        let imp = self.imp();
+
+        let threshold = imp.threshold_spin_row.value();
+        let seconds = imp.seconds_spin_row.value() as u32;
+
+        let condition = match imp.kind_combo_row.selected() {
+            0 => model::AlertCondition::Cpu {
+                percent: threshold,
+                seconds,
+            },
+            1 => model::AlertCondition::Memory {
+                bytes: threshold as u64 * 1024 * 1024,
+            },
+            2 => model::AlertCondition::Unhealthy,
+            3 => model::AlertCondition::NonZeroExit,
+            _ => model::AlertCondition::RestartLoop {
+                restarts: threshold as u32,
+                seconds,
+            },
+        };
+
+        if let Some(alert_monitor) = self.alert_monitor() {
+            alert_monitor.add_rule(model::AlertRule::new(
+                self.subject(),
+                self.name(),
+                condition,
+            ));
+        }
+    }
+
+    fn remove_rule(&self, id: &str) {
+        if let Some(alert_monitor) = self.alert_monitor() {
+            alert_monitor.remove_rule(id);
+        }
+    }
+}
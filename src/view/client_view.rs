@@ -1,6 +1,8 @@
 use adw::prelude::*;
 use adw::subclass::prelude::*;
 use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::closure;
 use glib::Properties;
 use gtk::gdk;
 use gtk::glib;
@@ -16,6 +18,7 @@ const ACTION_SHOW_CONNECTIONS: &str = "client-view.show-connections";
 const ACTION_SHOW_ACTIONS: &str = "client-view.show-actions";
 const ACTION_CANCEL_OR_DELETE_ACTION: &str = "client-view.cancel-or-delete-action";
 const ACTION_CREATE_ENTITY: &str = "client-view.create-entity";
+const ACTION_RECONNECT: &str = "client-view.reconnect";
 
 mod imp {
     use super::*;
@@ -29,6 +32,8 @@ mod imp {
         #[property(get, set)]
         pub(super) client: glib::WeakRef<model::Client>,
         #[template_child]
+        pub(super) reconnect_banner: TemplateChild<adw::Banner>,
+        #[template_child]
         pub(super) navigation_split_view: TemplateChild<adw::NavigationSplitView>,
         #[template_child]
         pub(super) sidebar_navigation_view: TemplateChild<adw::NavigationView>,
@@ -49,6 +54,10 @@ mod imp {
         #[template_child]
         pub(super) volumes_panel: TemplateChild<view::VolumesPanel>,
         #[template_child]
+        pub(super) networks_panel: TemplateChild<view::NetworksPanel>,
+        #[template_child]
+        pub(super) secrets_panel: TemplateChild<view::SecretsPanel>,
+        #[template_child]
         pub(super) color_bin: TemplateChild<adw::Bin>,
     }
 
@@ -104,6 +113,12 @@ mod imp {
             klass.install_action(ACTION_CREATE_ENTITY, None, |widget, _, _| {
                 widget.create_entity();
             });
+
+            klass.install_action(ACTION_RECONNECT, None, |widget, _, _| {
+                if let Some(client) = widget.client() {
+                    client.reconnect_now();
+                }
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -127,6 +142,24 @@ mod imp {
         fn constructed(&self) {
             self.parent_constructed();
 
+            let obj = &*self.obj();
+
+            Self::Type::this_expression("client")
+                .chain_property::<model::Client>("reconnect-countdown")
+                .chain_closure::<String>(closure!(|_: Self::Type, countdown: u32| {
+                    if countdown == 0 {
+                        gettext("Connection lost. Reconnecting…")
+                    } else {
+                        ngettext!(
+                            "Connection lost. Reconnecting in {} second",
+                            "Connection lost. Reconnecting in {} seconds",
+                            countdown,
+                            countdown
+                        )
+                    }
+                }))
+                .bind(&*self.reconnect_banner, "title", Some(obj));
+
             self.settings
                 .bind(
                     "last-used-view",
@@ -199,8 +232,10 @@ mod imp {
                                 "pods" => 1,
                                 "images" => 2,
                                 "volumes" => 3,
-                                "info" => 4,
-                                "search" => 5,
+                                "networks" => 4,
+                                "secrets" => 5,
+                                "info" => 6,
+                                "search" => 7,
                                 _ => unreachable!(),
                             },
                         )
@@ -223,6 +258,10 @@ mod imp {
                         "images"
                     } else if child.is::<view::VolumesRow>() {
                         "volumes"
+                    } else if child.is::<view::NetworksRow>() {
+                        "networks"
+                    } else if child.is::<view::SecretsRow>() {
+                        "secrets"
                     } else if child.is::<view::InfoRow>() {
                         "info"
                     } else if child.is::<view::SearchRow>() {
@@ -253,6 +292,8 @@ mod imp {
                 "pods" => gettext("Pods"),
                 "images" => gettext("Images"),
                 "volumes" => gettext("Volumes"),
+                "networks" => gettext("Networks"),
+                "secrets" => gettext("Secrets"),
                 "info" => gettext("Info"),
                 "search" => gettext("Search"),
                 _ => unreachable!(),
@@ -266,6 +307,8 @@ mod imp {
             self.pods_panel.set_search_mode(false);
             self.images_panel.set_search_mode(false);
             self.volumes_panel.set_search_mode(false);
+            self.networks_panel.set_search_mode(false);
+            self.secrets_panel.set_search_mode(false);
         }
 
         fn exit_selection_mode(&self) {
@@ -273,6 +316,8 @@ mod imp {
             self.pods_panel.exit_selection_mode();
             self.images_panel.exit_selection_mode();
             self.volumes_panel.exit_selection_mode();
+            self.networks_panel.exit_selection_mode();
+            self.secrets_panel.exit_selection_mode();
         }
 
         fn set_background(&self, bg_color: Option<gdk::RGBA>) {
@@ -318,6 +363,8 @@ impl ClientView {
             "pods" => imp.pods_panel.toggle_search_mode(),
             "images" => imp.images_panel.toggle_search_mode(),
             "volumes" => imp.volumes_panel.toggle_search_mode(),
+            "networks" => imp.networks_panel.toggle_search_mode(),
+            "secrets" => imp.secrets_panel.toggle_search_mode(),
             _ => {}
         }
     }
@@ -344,6 +391,10 @@ impl ClientView {
                 imp.images_panel.show_download_page();
             } else if imp.volumes_panel.is_mapped() {
                 imp.volumes_panel.create_volume();
+            } else if imp.networks_panel.is_mapped() {
+                imp.networks_panel.create_network();
+            } else if imp.secrets_panel.is_mapped() {
+                imp.secrets_panel.create_secret();
             }
         }
     }
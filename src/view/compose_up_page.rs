@@ -0,0 +1,324 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::WindowIdentifier;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_FILE: &str = "compose-up-page.select-file";
+const ACTION_UP: &str = "compose-up-page.up";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ComposeUpPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/compose_up_page.ui")]
+    pub(crate) struct ComposeUpPage {
+        pub(super) project: RefCell<Option<model::ComposeProject>>,
+        pub(super) preview_rows: RefCell<Vec<(adw::PreferencesGroup, gtk::Widget)>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) show_view_artifact: Cell<bool>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) up_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) file_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) name_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) start_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) services_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) volumes_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) networks_group: TemplateChild<adw::PreferencesGroup>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ComposeUpPage {
+        const NAME: &'static str = "PdsComposeUpPage";
+        type Type = super::ComposeUpPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action_async(ACTION_SELECT_FILE, None, |widget, _, _| async move {
+                widget.select_file().await;
+            });
+            klass.install_action(ACTION_UP, None, |widget, _, _| {
+                widget.up();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ComposeUpPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().action_set_enabled(ACTION_UP, false);
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for ComposeUpPage {
+        fn root(&self) {
+            self.parent_root();
+            utils::root(self.obj().upcast_ref()).set_default_widget(Some(&*self.up_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+
+    #[gtk::template_callbacks]
+    impl ComposeUpPage {
+        #[template_callback]
+        fn on_name_entry_row_changed(&self) {
+            self.obj().update_up_action();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ComposeUpPage(ObjectSubclass<imp::ComposeUpPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Client> for ComposeUpPage {
+    fn from(client: &model::Client) -> Self {
+        Self::new(client, true)
+    }
+}
+
+impl ComposeUpPage {
+    pub(crate) fn new(client: &model::Client, show_view_artifact: bool) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("show-view-artifact", show_view_artifact)
+            .build()
+    }
+
+    async fn select_file(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Compose File").as_str())
+            .filter(
+                FileFilter::new(&gettext("YAML Files"))
+                    .mimetype("application/yaml")
+                    .mimetype("application/x-yaml")
+                    .glob("*.yaml")
+                    .glob("*.yml"),
+            )
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    utils::do_async(
+                        {
+                            let path = path.clone();
+                            async move { tokio::fs::read_to_string(path).await }
+                        },
+                        clone!(@weak obj => move |result| match result {
+                            Ok(yaml) => obj.set_yaml(&path, &yaml),
+                            Err(e) => utils::show_error_toast(
+                                obj.upcast_ref(),
+                                &gettext("Error on reading file"),
+                                &e.to_string(),
+                            ),
+                        }),
+                    );
+                }
+            }),
+        )
+        .await;
+    }
+
+    fn set_yaml(&self, path: &std::path::Path, yaml: &str) {
+        let imp = self.imp();
+
+        self.clear_preview();
+
+        match model::ComposeProject::parse(yaml, path) {
+            Ok(project) => {
+                imp.file_row.set_subtitle(path.to_str().unwrap());
+                imp.name_entry_row.set_text(&project.name);
+
+                project.services.iter().for_each(|service| {
+                    let row = adw::ExpanderRow::builder()
+                        .title(&service.name)
+                        .subtitle(&service.image)
+                        .build();
+
+                    service
+                        .ports
+                        .iter()
+                        .map(|port| {
+                            adw::ActionRow::builder()
+                                .title(gettext!(
+                                    "Port {} → {}/{}",
+                                    port.host_port,
+                                    port.container_port,
+                                    port.protocol
+                                ))
+                                .build()
+                        })
+                        .chain(service.volumes.iter().map(|mount| {
+                            let (source, target) = match mount {
+                                model::ComposeMount::Volume { name, target, .. } => {
+                                    (project.scoped_name(name), target)
+                                }
+                                model::ComposeMount::Bind { source, target, .. } => {
+                                    (source.to_string_lossy().into_owned(), target)
+                                }
+                            };
+                            adw::ActionRow::builder()
+                                .title(gettext!("Mount {} → {}", source, target))
+                                .build()
+                        }))
+                        .for_each(|child| row.add_row(&child));
+
+                    row.set_enable_expansion(
+                        !service.ports.is_empty() || !service.volumes.is_empty(),
+                    );
+
+                    self.add_preview_row(&imp.services_group, row.upcast_ref());
+                });
+
+                project.volumes.iter().for_each(|volume| {
+                    self.add_preview_row(
+                        &imp.volumes_group,
+                        adw::ActionRow::builder()
+                            .title(project.scoped_name(volume))
+                            .build()
+                            .upcast_ref(),
+                    );
+                });
+
+                project.networks.iter().for_each(|network| {
+                    self.add_preview_row(
+                        &imp.networks_group,
+                        adw::ActionRow::builder()
+                            .title(project.scoped_name(&network.name))
+                            .subtitle(network.driver.as_deref().unwrap_or("bridge"))
+                            .build()
+                            .upcast_ref(),
+                    );
+                });
+
+                imp.services_group.set_visible(true);
+                imp.volumes_group.set_visible(!project.volumes.is_empty());
+                imp.networks_group.set_visible(!project.networks.is_empty());
+
+                imp.project.replace(Some(project));
+            }
+            Err(e) => {
+                imp.project.replace(None);
+
+                utils::show_error_toast(
+                    self.upcast_ref(),
+                    &gettext("Invalid Compose File"),
+                    &e.to_string(),
+                );
+            }
+        }
+
+        self.update_up_action();
+    }
+
+    fn update_up_action(&self) {
+        let imp = self.imp();
+
+        self.action_set_enabled(
+            ACTION_UP,
+            imp.project.borrow().is_some() && !imp.name_entry_row.text().is_empty(),
+        );
+    }
+
+    fn add_preview_row(&self, group: &adw::PreferencesGroup, row: &gtk::Widget) {
+        group.add(row);
+        self.imp()
+            .preview_rows
+            .borrow_mut()
+            .push((group.to_owned(), row.to_owned()));
+    }
+
+    fn clear_preview(&self) {
+        let imp = self.imp();
+
+        imp.preview_rows
+            .take()
+            .into_iter()
+            .for_each(|(group, row)| group.remove(&row));
+
+        imp.services_group.set_visible(false);
+        imp.volumes_group.set_visible(false);
+        imp.networks_group.set_visible(false);
+    }
+
+    fn up(&self) {
+        let imp = self.imp();
+
+        if let Some((mut project, client)) = imp.project.borrow().clone().zip(self.client()) {
+            project.name = imp.name_entry_row.text().to_string();
+
+            let page = view::ActionPage::new(
+                &client
+                    .action_list()
+                    .compose_up(project, imp.start_switch_row.is_active()),
+                self.show_view_artifact(),
+            );
+
+            imp.navigation_view.push(
+                &adw::NavigationPage::builder()
+                    .can_pop(false)
+                    .child(&page)
+                    .build(),
+            );
+        }
+    }
+}
@@ -11,9 +11,9 @@ pub(crate) fn show_ongoing_actions_warning_dialog(
     heading: &str,
 ) -> bool {
     if connection_manager
-        .client()
-        .map(|client| client.action_list().ongoing() > 0)
-        .unwrap_or(false)
+        .clients()
+        .iter()
+        .any(|client| client.action_list().ongoing() > 0)
     {
         let dialog = adw::MessageDialog::builder()
             .heading(heading)
@@ -21,6 +21,10 @@ mod imp {
         pub(crate) connection_manager: glib::WeakRef<model::ConnectionManager>,
         #[template_child]
         pub(super) connection_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) podman_connections_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) podman_connection_list_box: TemplateChild<gtk::ListBox>,
     }
 
     #[glib::object_subclass]
@@ -64,7 +68,7 @@ mod imp {
     impl ConnectionChooserPage {
         #[template_callback]
         fn on_notify_connection_manager(&self) {
-            let obj = self.obj();
+            let obj = &*self.obj();
             self.connection_list_box
                 .bind_model(obj.connection_manager().as_ref(), |item| {
                     gtk::ListBoxRow::builder()
@@ -73,6 +77,16 @@ mod imp {
                         .build()
                         .upcast()
                 });
+
+            if let Some(connection_manager) = obj.connection_manager() {
+                connection_manager.connect_podman_connections_changed(
+                    clone!(@weak obj => move |_| obj.update_podman_connections()),
+                );
+                connection_manager.connect_items_changed(
+                    clone!(@weak obj => move |_, _, _, _| obj.update_podman_connections()),
+                );
+            }
+            obj.update_podman_connections();
         }
 
         #[template_callback]
@@ -118,3 +132,73 @@ glib::wrapper! {
         @extends gtk::Widget,
         @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
 }
+
+impl ConnectionChooserPage {
+    /// Lists the connections of Podman that can be imported with a single click.
+    fn update_podman_connections(&self) {
+        let imp = self.imp();
+
+        imp.podman_connection_list_box.remove_all();
+
+        let podman_connections = self
+            .connection_manager()
+            .map(|connection_manager| connection_manager.importable_podman_connections())
+            .unwrap_or_default();
+
+        imp.podman_connections_group
+            .set_visible(!podman_connections.is_empty());
+
+        podman_connections
+            .into_iter()
+            .for_each(|podman_connection| {
+                let row = adw::ActionRow::builder()
+                    .title(utils::escape(&podman_connection.name))
+                    .subtitle(utils::escape(&podman_connection.uri))
+                    .build();
+
+                if podman_connection.default {
+                    let label = gtk::Label::builder()
+                        .css_classes(["dim-label", "caption"])
+                        .label(gettext("Default"))
+                        .build();
+                    row.add_suffix(&label);
+                }
+
+                let button = gtk::Button::builder()
+                    .css_classes(["flat"])
+                    .label(gettext("Import"))
+                    .valign(gtk::Align::Center)
+                    .build();
+                button.connect_clicked(clone!(@weak self as obj => move |_| {
+                    obj.import_podman_connection(&podman_connection.name);
+                }));
+                row.add_suffix(&button);
+                row.set_activatable_widget(Some(&button));
+
+                imp.podman_connection_list_box.append(&row);
+            });
+    }
+
+    fn import_podman_connection(&self, name: &str) {
+        let Some(connection_manager) = self.connection_manager() else {
+            return;
+        };
+
+        if let Err(e) = connection_manager.import_podman_connection(
+            name,
+            clone!(@weak self as obj => move |result| if let Err(e) = result {
+                utils::show_error_toast(
+                    obj.upcast_ref(),
+                    &gettext("Error on importing connection"),
+                    &e.to_string(),
+                );
+            }),
+        ) {
+            utils::show_error_toast(
+                self.upcast_ref(),
+                &gettext("Error on importing connection"),
+                &e.to_string(),
+            );
+        }
+    }
+}
@@ -2,21 +2,25 @@ use std::cell::OnceCell;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::WindowIdentifier;
 use gettextrs::gettext;
 use glib::clone;
 use glib::closure;
 use glib::Properties;
 use gtk::gdk;
+use gtk::gio;
 use gtk::glib;
 use gtk::CompositeTemplate;
 
 use crate::model;
 use crate::utils;
-use crate::view;
 
 const ACTION_COPY_SOCKET_ACTIVATION_COMMAND: &str =
     "connection-creator-page.copy-socket-activation-command";
 const ACTION_SHOW_CUSTOM_INFO_DIALOG: &str = "connection-creation-page.show-custom-info-dialog";
+const ACTION_SELECT_IDENTITY_FILE: &str = "connection-creation-page.select-identity-file";
+const ACTION_SELECT_TLS_FILE: &str = "connection-creation-page.select-tls-file";
 const ACTION_TRY_CONNECT: &str = "connection-creation-page.try-connect";
 
 const ACTION_ABORT: &str = "connection-creation-page.abort";
@@ -51,6 +55,30 @@ mod imp {
         #[template_child]
         pub(super) custom_url_entry_row: TemplateChild<adw::EntryRow>,
         #[template_child]
+        pub(super) tls_expander_row: TemplateChild<adw::ExpanderRow>,
+        #[template_child]
+        pub(super) tls_ca_cert_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) tls_client_cert_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) tls_client_key_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ssh_radio_button: TemplateChild<gtk::CheckButton>,
+        #[template_child]
+        pub(super) ssh_user_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ssh_host_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ssh_port_spin_row: TemplateChild<adw::SpinRow>,
+        #[template_child]
+        pub(super) ssh_socket_path_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ssh_identity_file_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ssh_passphrase_entry_row: TemplateChild<adw::PasswordEntryRow>,
+        #[template_child]
+        pub(super) ssh_agent_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
         pub(super) color_dialog_button: TemplateChild<gtk::ColorDialogButton>,
         #[template_child]
         pub(super) color_switch: TemplateChild<gtk::Switch>,
@@ -76,6 +104,21 @@ mod imp {
             klass.install_action(ACTION_SHOW_CUSTOM_INFO_DIALOG, None, |widget, _, _| {
                 widget.show_custom_info_dialog();
             });
+            klass.install_action_async(
+                ACTION_SELECT_IDENTITY_FILE,
+                None,
+                |widget, _, _| async move {
+                    widget.select_identity_file().await;
+                },
+            );
+            klass.install_action_async(
+                ACTION_SELECT_TLS_FILE,
+                Some(glib::VariantTy::STRING),
+                |widget, _, data| async move {
+                    let kind: String = data.unwrap().get().unwrap();
+                    widget.select_tls_file(&kind).await;
+                },
+            );
             klass.install_action(ACTION_TRY_CONNECT, None, |widget, _, _| {
                 widget.try_connect();
             });
@@ -144,6 +187,9 @@ mod imp {
             self.custom_url_radio_button
                 .set_active(obj.connection_manager().contains_local_connection());
 
+            self.ssh_user_entry_row
+                .set_text(&glib::user_name().to_string_lossy());
+
             self.color_dialog_button
                 .set_rgba(&gdk::RGBA::new(0.207, 0.517, 0.894, 1.0));
         }
@@ -192,6 +238,22 @@ mod imp {
         #[template_callback]
         fn on_custom_url_entry_row_changed(&self) {
             self.custom_url_radio_button.set_active(true);
+            self.obj().update_actions();
+        }
+
+        #[template_callback]
+        fn on_tls_entry_row_changed(&self) {
+            self.obj().update_actions();
+        }
+
+        #[template_callback]
+        fn on_ssh_radio_button_toggled(&self) {
+            self.obj().update_actions();
+        }
+
+        #[template_callback]
+        fn on_ssh_entry_row_changed(&self) {
+            self.obj().update_actions();
         }
 
         #[template_callback]
@@ -239,34 +301,204 @@ impl ConnectionCreationPage {
             .push_by_tag("custom-connection-info");
     }
 
-    pub(crate) fn try_connect(&self) {
-        if view::show_ongoing_actions_warning_dialog(
+    async fn select_identity_file(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Identity File").as_str())
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
             self.upcast_ref(),
-            &self.connection_manager(),
-            &gettext("Confirm Connecting to New Instance"),
-        ) {
-            let imp = self.imp();
-
-            if let Err(e) = self.connection_manager().try_connect(
-                imp.name_entry_row.text().as_str(),
-                if imp.custom_url_radio_button.is_active() {
-                    imp.custom_url_entry_row.text().into()
-                } else {
-                    utils::unix_socket_url()
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    obj.imp()
+                        .ssh_identity_file_entry_row
+                        .set_text(&path.to_string_lossy());
                 }
-                .as_ref(),
-                if imp.color_switch.is_active() {
-                    Some(imp.color_dialog_button.rgba())
-                } else {
-                    None
-                },
-                clone!(@weak self as obj => move |result| match result {
-                    Ok(_) => obj.activate_action("win.close", None).unwrap(),
+            }),
+        )
+        .await;
+    }
+
+    async fn select_tls_file(&self, kind: &str) {
+        let imp = self.imp();
+
+        let (title, entry_row) = match kind {
+            "ca-cert" => (
+                gettext("Select CA Certificate"),
+                imp.tls_ca_cert_entry_row.get(),
+            ),
+            "client-cert" => (
+                gettext("Select Client Certificate"),
+                imp.tls_client_cert_entry_row.get(),
+            ),
+            _ => (
+                gettext("Select Client Key"),
+                imp.tls_client_key_entry_row.get(),
+            ),
+        };
+
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(title.as_str())
+            .modal(true);
+
+        utils::show_open_file_dialog(request, self.upcast_ref(), move |files| {
+            let file = gio::File::for_uri(files.uris()[0].as_str());
+
+            if let Some(path) = file.path() {
+                entry_row.set_text(&path.to_string_lossy());
+            }
+        })
+        .await;
+    }
+
+    fn tls_info(&self) -> Option<model::TlsInfo> {
+        let imp = self.imp();
+
+        if imp.custom_url_radio_button.is_active() && imp.tls_expander_row.enables_expansion() {
+            Some(model::TlsInfo {
+                ca_cert: imp.tls_ca_cert_entry_row.text().trim().to_owned(),
+                client_cert: imp.tls_client_cert_entry_row.text().trim().to_owned(),
+                client_key: imp.tls_client_key_entry_row.text().trim().to_owned(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn ssh_info(&self) -> model::SshInfo {
+        let imp = self.imp();
+
+        let identity_file = imp.ssh_identity_file_entry_row.text();
+
+        model::SshInfo {
+            user: imp.ssh_user_entry_row.text().trim().to_owned(),
+            host: imp.ssh_host_entry_row.text().trim().to_owned(),
+            port: imp.ssh_port_spin_row.value() as u16,
+            identity_file: Some(identity_file.trim().to_owned()).filter(|path| !path.is_empty()),
+            use_agent: imp.ssh_agent_switch_row.is_active(),
+            socket_path: imp.ssh_socket_path_entry_row.text().trim().to_owned(),
+        }
+    }
+
+    pub(crate) fn try_connect(&self) {
+        if !self.imp().ssh_radio_button.is_active() {
+            self.connect(None);
+            return;
+        }
+
+        let ssh = self.ssh_info();
+
+        utils::do_async(
+            {
+                let ssh = ssh.clone();
+                async move {
+                    tokio::task::spawn_blocking(move || ssh.check_host_key())
+                        .await
+                        .map_err(anyhow::Error::from)
+                        .and_then(|result| result)
+                }
+            },
+            clone!(@weak self as obj => move |result| match result {
+                Ok(model::SshHostKeyCheck::Known) => obj.store_passphrase_and_connect(ssh),
+                Ok(model::SshHostKeyCheck::Unknown { keys, fingerprints }) => {
+                    obj.ask_trust_host(ssh, keys, fingerprints);
+                }
+                Err(e) => obj.on_error(&e.to_string()),
+            }),
+        );
+    }
+
+    /// Asks the user whether to trust a host that is not in `~/.ssh/known_hosts` yet.
+    fn ask_trust_host(&self, ssh: model::SshInfo, keys: String, fingerprints: Vec<String>) {
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Unknown Host"))
+            .body_use_markup(true)
+            .body(gettext!(
+                "The authenticity of host <b>{}</b> can't be established. Only continue if the fingerprints match the host's keys:\n\n<tt>{}</tt>",
+                utils::escape(&ssh.host),
+                utils::escape(&fingerprints.join("\n")),
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("trust", &gettext("_Trust and Connect")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("trust", adw::ResponseAppearance::Suggested);
+
+        dialog.connect_response(
+            None,
+            clone!(@weak self as obj => move |_, response| if response == "trust" {
+                match model::trust_ssh_host_keys(&keys) {
+                    Ok(_) => obj.store_passphrase_and_connect(ssh.clone()),
                     Err(e) => obj.on_error(&e.to_string()),
-                }),
-            ) {
-                self.on_error(&e.to_string());
+                }
+            }),
+        );
+
+        dialog.present(Some(self));
+    }
+
+    fn store_passphrase_and_connect(&self, ssh: model::SshInfo) {
+        let passphrase = self.imp().ssh_passphrase_entry_row.text();
+
+        if passphrase.is_empty() || ssh.identity_file.is_none() {
+            self.connect(Some(ssh));
+            return;
+        }
+
+        utils::do_async(
+            {
+                let ssh = ssh.clone();
+                async move { ssh.store_passphrase(&passphrase).await }
+            },
+            clone!(@weak self as obj => move |result| match result {
+                Ok(_) => obj.connect(Some(ssh)),
+                Err(e) => obj.on_error(&e.to_string()),
+            }),
+        );
+    }
+
+    fn connect(&self, ssh: Option<model::SshInfo>) {
+        let imp = self.imp();
+
+        let url = match ssh {
+            Some(ref ssh) => ssh.url(),
+            None if imp.custom_url_radio_button.is_active() => {
+                imp.custom_url_entry_row.text().into()
             }
+            None => utils::unix_socket_url(),
+        };
+
+        if let Err(e) = self.connection_manager().try_connect(
+            imp.name_entry_row.text().as_str(),
+            &url,
+            if imp.color_switch.is_active() {
+                Some(imp.color_dialog_button.rgba())
+            } else {
+                None
+            },
+            ssh,
+            self.tls_info(),
+            clone!(@weak self as obj => move |result| match result {
+                Ok(_) => obj.activate_action("win.close", None).unwrap(),
+                Err(e) => match e.downcast_ref::<model::TlsHandshakeError>() {
+                    Some(e) => utils::show_error_toast(
+                        obj.upcast_ref(),
+                        &gettext("Error on establishing secure connection"),
+                        &e.to_string(),
+                    ),
+                    None => obj.on_error(&e.to_string()),
+                },
+            }),
+        ) {
+            self.on_error(&e.to_string());
         }
     }
 
@@ -277,9 +509,21 @@ impl ConnectionCreationPage {
     fn update_actions(&self) {
         let is_connecting = self.connection_manager().is_connecting();
 
+        let imp = self.imp();
+
         self.action_set_enabled(
             ACTION_TRY_CONNECT,
-            !is_connecting && !self.imp().name_entry_row.text().is_empty(),
+            !is_connecting
+                && !imp.name_entry_row.text().is_empty()
+                && (!imp.ssh_radio_button.is_active()
+                    || !(imp.ssh_user_entry_row.text().trim().is_empty()
+                        || imp.ssh_host_entry_row.text().trim().is_empty()
+                        || imp.ssh_socket_path_entry_row.text().trim().is_empty()))
+                && self.tls_info().map_or(true, |tls| {
+                    !(tls.ca_cert.is_empty()
+                        || tls.client_cert.is_empty()
+                        || tls.client_key.is_empty())
+                }),
         );
         self.action_set_enabled(ACTION_ABORT, is_connecting);
     }
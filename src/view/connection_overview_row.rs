@@ -0,0 +1,399 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::time::Duration;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+
+const ACTION_CONNECT: &str = "connection-overview-row.connect";
+const ACTION_SHOW: &str = "connection-overview-row.show";
+
+/// The time to wait for further changes of the lists before the disk usage is requested again.
+const DATA_USAGE_DELAY: Duration = Duration::from_secs(2);
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ConnectionOverviewRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/connection_overview_row.ui")]
+    pub(crate) struct ConnectionOverviewRow {
+        pub(super) css_provider: gtk::CssProvider,
+        pub(super) connection_handlers: RefCell<Vec<glib::SignalHandlerId>>,
+        pub(super) client: glib::WeakRef<model::Client>,
+        pub(super) client_handlers: RefCell<Vec<(glib::Object, glib::SignalHandlerId)>>,
+        pub(super) data_usage_source: RefCell<Option<glib::SourceId>>,
+        pub(super) data_usage: Cell<Option<u64>>,
+        #[property(get, set = Self::set_connection, construct, nullable)]
+        pub(super) connection: glib::WeakRef<model::Connection>,
+        #[template_child]
+        pub(super) color_bin: TemplateChild<adw::Bin>,
+        #[template_child]
+        pub(super) status_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) running_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) exited_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) unhealthy_label: TemplateChild<gtk::Label>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ConnectionOverviewRow {
+        const NAME: &'static str = "PdsConnectionOverviewRow";
+        type Type = super::ConnectionOverviewRow;
+        type ParentType = adw::ActionRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_CONNECT, None, |widget, _, _| {
+                widget.connect();
+            });
+            klass.install_action(ACTION_SHOW, None, |widget, _, _| {
+                widget.show();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ConnectionOverviewRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            self.color_bin
+                .style_context()
+                .add_provider(&self.css_provider, gtk::STYLE_PROVIDER_PRIORITY_APPLICATION);
+        }
+
+        fn dispose(&self) {
+            let obj = &*self.obj();
+
+            if let Some(connection) = obj.connection() {
+                self.connection_handlers
+                    .take()
+                    .into_iter()
+                    .for_each(|handler_id| connection.disconnect(handler_id));
+            }
+            obj.bind_client(None);
+        }
+    }
+
+    impl WidgetImpl for ConnectionOverviewRow {}
+
+    impl ListBoxRowImpl for ConnectionOverviewRow {}
+    impl PreferencesRowImpl for ConnectionOverviewRow {}
+    impl ActionRowImpl for ConnectionOverviewRow {}
+
+    impl ConnectionOverviewRow {
+        pub(super) fn set_connection(&self, value: Option<&model::Connection>) {
+            let obj = &*self.obj();
+            if obj.connection().as_ref() == value {
+                return;
+            }
+
+            self.color_bin
+                .set_visible(match value.and_then(model::Connection::rgb) {
+                    Some(rgb) => {
+                        self.css_provider.load_from_data(&format!(
+                            "widget {{ background: shade({rgb}, 1.2); }}"
+                        ));
+                        true
+                    }
+                    None => false,
+                });
+
+            if let Some(connection) = obj.connection() {
+                self.connection_handlers
+                    .take()
+                    .into_iter()
+                    .for_each(|handler_id| connection.disconnect(handler_id));
+            }
+
+            if let Some(connection) = value {
+                obj.set_title(&utils::escape(&connection.name()));
+
+                self.connection_handlers.replace(vec![
+                    connection.connect_connecting_notify(clone!(@weak obj => move |_| {
+                        obj.update();
+                    })),
+                    connection.connect_connected_notify(clone!(@weak obj => move |_| {
+                        obj.update();
+                    })),
+                ]);
+            }
+
+            self.connection.set(value);
+            obj.update();
+            obj.notify_connection();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ConnectionOverviewRow(ObjectSubclass<imp::ConnectionOverviewRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ActionRow,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Connection> for ConnectionOverviewRow {
+    fn from(connection: &model::Connection) -> Self {
+        glib::Object::builder()
+            .property("connection", connection)
+            .build()
+    }
+}
+
+impl ConnectionOverviewRow {
+    fn client(&self) -> Option<model::Client> {
+        self.connection().and_then(|connection| {
+            connection
+                .manager()
+                .and_then(|manager| manager.client_by_uuid(&connection.uuid()))
+        })
+    }
+
+    fn update(&self) {
+        let imp = self.imp();
+
+        let Some(connection) = self.connection() else {
+            return;
+        };
+
+        let client = self.client();
+        self.bind_client(client.as_ref());
+
+        let client = client.filter(model::Client::started);
+
+        imp.status_stack.set_visible_child_name(match client {
+            Some(_) => "connected",
+            None if connection.connecting() => "connecting",
+            None => "disconnected",
+        });
+
+        if let Some(client) = client {
+            let container_list = client.container_list();
+
+            let running = container_list.running();
+            imp.running_label.set_label(&running.to_string());
+            imp.running_label.set_tooltip_text(Some(&ngettext!(
+                "{} running container",
+                "{} running containers",
+                running,
+                running
+            )));
+
+            let exited = container_list.exited();
+            imp.exited_label.set_label(&exited.to_string());
+            imp.exited_label.set_tooltip_text(Some(&ngettext!(
+                "{} exited container",
+                "{} exited containers",
+                exited,
+                exited
+            )));
+
+            let unhealthy = container_list.unhealthy();
+            imp.unhealthy_label.set_visible(unhealthy > 0);
+            imp.unhealthy_label.set_label(&unhealthy.to_string());
+            imp.unhealthy_label.set_tooltip_text(Some(&ngettext!(
+                "{} failing health check",
+                "{} failing health checks",
+                unhealthy,
+                unhealthy
+            )));
+        }
+
+        self.update_subtitle();
+    }
+
+    /// Follows the changes of the client's lists instead of polling them.
+    fn bind_client(&self, client: Option<&model::Client>) {
+        let imp = self.imp();
+
+        if imp.client.upgrade().as_ref() == client {
+            return;
+        }
+
+        imp.client_handlers
+            .take()
+            .into_iter()
+            .for_each(|(object, handler_id)| object.disconnect(handler_id));
+        if let Some(source_id) = imp.data_usage_source.take() {
+            source_id.remove();
+        }
+        imp.data_usage.set(None);
+        imp.client.set(client);
+
+        let Some(client) = client else {
+            return;
+        };
+
+        let container_list = client.container_list();
+        let image_list = client.image_list();
+
+        let mut handlers = vec![(
+            client.clone().upcast::<glib::Object>(),
+            client.connect_started_notify(clone!(@weak self as obj => move |_| {
+                obj.update();
+                obj.update_data_usage();
+            })),
+        )];
+
+        handlers.extend(["running", "exited", "unhealthy"].into_iter().map(|name| {
+            (
+                container_list.clone().upcast(),
+                container_list.connect_notify_local(
+                    Some(name),
+                    clone!(@weak self as obj => move |_, _| obj.update()),
+                ),
+            )
+        }));
+
+        // The disk usage changes with the number of containers and images.
+        handlers.extend(
+            [
+                container_list.upcast::<gio::ListModel>(),
+                image_list.upcast::<gio::ListModel>(),
+            ]
+            .into_iter()
+            .map(|list| {
+                let handler_id = list.connect_items_changed(
+                    clone!(@weak self as obj => move |_, _, _, _| obj.schedule_data_usage()),
+                );
+                (list.upcast(), handler_id)
+            }),
+        );
+
+        imp.client_handlers.replace(handlers);
+
+        if client.started() {
+            self.update_data_usage();
+        }
+    }
+
+    fn schedule_data_usage(&self) {
+        let imp = self.imp();
+
+        if let Some(source_id) = imp.data_usage_source.take() {
+            source_id.remove();
+        }
+
+        imp.data_usage_source
+            .replace(Some(glib::timeout_add_local_once(
+                DATA_USAGE_DELAY,
+                clone!(@weak self as obj => move || {
+                    obj.imp().data_usage_source.take();
+                    obj.update_data_usage();
+                }),
+            )));
+    }
+
+    fn update_data_usage(&self) {
+        let Some(client) = self.client().filter(model::Client::started) else {
+            return;
+        };
+
+        client.data_usage(clone!(@weak self as obj => move |result| {
+            match result {
+                Ok(data_usage) => obj.imp().data_usage.set(Some(data_usage)),
+                Err(e) => log::warn!("Error on retrieving disk usage: {e}"),
+            }
+            obj.update_subtitle();
+        }));
+    }
+
+    fn update_subtitle(&self) {
+        let Some(connection) = self.connection() else {
+            return;
+        };
+
+        self.set_subtitle(&match self.imp().data_usage.get() {
+            Some(data_usage) if connection.connected() => {
+                gettext!("{} of disk space used", glib::format_size(data_usage))
+            }
+            _ if connection.is_remote() => utils::escape(&connection.url()),
+            _ => gettext("Local connection"),
+        });
+    }
+
+    fn connect(&self) {
+        let Some(connection) = self.connection() else {
+            return;
+        };
+        let Some(manager) = connection.manager() else {
+            return;
+        };
+
+        manager.connect_to(
+            &connection.uuid(),
+            clone!(@weak self as obj => move |result| {
+                if let Err(e) = result {
+                    utils::show_error_toast(
+                        obj.upcast_ref(),
+                        &gettext("Error on establishing connection"),
+                        &e.to_string(),
+                    );
+                }
+                obj.update();
+            }),
+        );
+        self.update();
+    }
+
+    fn show(&self) {
+        let Some(connection) = self.connection() else {
+            return;
+        };
+        let Some(manager) = connection.manager() else {
+            return;
+        };
+
+        if connection.active() {
+            self.close_dialog();
+            return;
+        }
+
+        manager.set_client_from(
+            &connection.uuid(),
+            clone!(@weak self as obj => move |result| match result {
+                Ok(_) => obj.close_dialog(),
+                Err(e) => utils::show_error_toast(
+                    obj.upcast_ref(),
+                    &gettext("Error on switching connection"),
+                    &e.to_string(),
+                ),
+            }),
+        );
+    }
+
+    fn close_dialog(&self) {
+        if let Some(dialog) = self.ancestor(adw::Dialog::static_type()) {
+            dialog.downcast::<adw::Dialog>().unwrap().close();
+        }
+    }
+}
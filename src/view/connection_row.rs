@@ -102,8 +102,12 @@ mod imp {
             .bind(&*self.url_label, "label", Some(obj));
 
             let classes = utils::css_classes(self.image.upcast_ref());
-            is_active_expr
-                .chain_closure::<Vec<String>>(closure!(|_: Self::Type, is_active: bool| {
+            gtk::ClosureExpression::new::<Vec<String>>(
+                [
+                    &is_active_expr,
+                    &connection_expr.chain_property::<model::Connection>("connected"),
+                ],
+                closure!(|_: Self::Type, is_active: bool, is_connected: bool| {
                     classes
                         .iter()
                         .cloned()
@@ -112,22 +116,36 @@ mod imp {
                         } else {
                             "unselected-connection"
                         })))
+                        // Connections without an open client are dimmed.
+                        .chain(if is_connected {
+                            None
+                        } else {
+                            Some(String::from("dim-label"))
+                        })
                         .collect::<Vec<_>>()
-                }))
-                .bind(&*self.image, "css-classes", Some(obj));
+                }),
+            )
+            .bind(&*self.image, "css-classes", Some(obj));
 
             is_active_expr.bind(&*self.checkmark, "visible", Some(obj));
 
-            connection_expr
-                .chain_property::<model::Connection>("connecting")
-                .chain_closure::<String>(closure!(
-                    |_: Self::Type, connecting: bool| if connecting {
+            gtk::ClosureExpression::new::<String>(
+                [
+                    &connection_expr.chain_property::<model::Connection>("connecting"),
+                    &connection_expr.chain_property::<model::Connection>("managed"),
+                ],
+                closure!(|_: Self::Type, connecting: bool, managed: bool| {
+                    if connecting {
                         "connecting"
+                    } else if managed {
+                        // Managed connections can't be deleted.
+                        "managed"
                     } else {
                         "delete"
                     }
-                ))
-                .bind(&*self.end_stack, "visible-child-name", Some(obj));
+                }),
+            )
+            .bind(&*self.end_stack, "visible-child-name", Some(obj));
 
             connection_expr
                 .chain_property::<model::Connection>("uuid")
@@ -0,0 +1,85 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use glib::Properties;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ConnectionsOverviewPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/connections_overview_page.ui")]
+    pub(crate) struct ConnectionsOverviewPage {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) connection_manager: glib::WeakRef<model::ConnectionManager>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ConnectionsOverviewPage {
+        const NAME: &'static str = "PdsConnectionsOverviewPage";
+        type Type = super::ConnectionsOverviewPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ConnectionsOverviewPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            if let Some(connection_manager) = self.obj().connection_manager() {
+                self.list_box.bind_model(Some(&connection_manager), |item| {
+                    view::ConnectionOverviewRow::from(
+                        item.downcast_ref::<model::Connection>().unwrap(),
+                    )
+                    .upcast()
+                });
+            }
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for ConnectionsOverviewPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ConnectionsOverviewPage(ObjectSubclass<imp::ConnectionsOverviewPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::ConnectionManager> for ConnectionsOverviewPage {
+    fn from(connection_manager: &model::ConnectionManager) -> Self {
+        glib::Object::builder()
+            .property("connection-manager", connection_manager)
+            .build()
+    }
+}
@@ -9,7 +9,6 @@ use gtk::CompositeTemplate;
 
 use crate::model;
 use crate::utils;
-use crate::view;
 
 mod imp {
     use super::*;
@@ -91,22 +90,16 @@ mod imp {
                     return;
                 }
 
-                if view::show_ongoing_actions_warning_dialog(
-                    obj.upcast_ref(),
-                    &connection_manager,
-                    &gettext("Confirm Switching Connection"),
-                ) {
-                    connection_manager.set_client_from(
-                        &connection.uuid(),
-                        clone!(@weak obj => move |result| if let Err(e) = result {
-                            utils::show_error_toast(
-                                obj.upcast_ref(),
-                                &gettext("Error on switching connection"),
-                                &e.to_string(),
-                            );
-                        }),
-                    );
-                }
+                connection_manager.set_client_from(
+                    &connection.uuid(),
+                    clone!(@weak obj => move |result| if let Err(e) = result {
+                        utils::show_error_toast(
+                            obj.upcast_ref(),
+                            &gettext("Error on switching connection"),
+                            &e.to_string(),
+                        );
+                    }),
+                );
             }
         }
     }
@@ -52,6 +52,26 @@ pub(crate) fn container_status_combined_css_class(
     }
 }
 
+pub(crate) fn export(widget: &gtk::Widget, container: Option<&model::Container>) {
+    if let Some(container) = container {
+        utils::Dialog::new(
+            widget,
+            view::ContainerExportPage::from(container).upcast_ref(),
+        )
+        .present();
+    }
+}
+
+pub(crate) fn checkpoint(widget: &gtk::Widget, container: Option<&model::Container>) {
+    if let Some(container) = container {
+        utils::Dialog::new(
+            widget,
+            view::ContainerCheckpointPage::from(container).upcast_ref(),
+        )
+        .present();
+    }
+}
+
 pub(crate) fn rename(widget: &gtk::Widget, container: Option<&model::Container>) {
     if let Some(container) = container {
         let container_renamer = view::ContainerRenamer::from(container);
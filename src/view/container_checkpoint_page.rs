@@ -0,0 +1,200 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::SaveFileRequest;
+use ashpd::WindowIdentifier;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_PATH: &str = "container-checkpoint-page.select-path";
+const ACTION_CHECKPOINT: &str = "container-checkpoint-page.checkpoint";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerCheckpointPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_checkpoint_page.ui")]
+    pub(crate) struct ContainerCheckpointPage {
+        pub(super) path: RefCell<Option<PathBuf>>,
+        #[property(get, set = Self::set_container, construct, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) checkpoint_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) path_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) leave_running_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) volumes_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) tcp_switch_row: TemplateChild<adw::SwitchRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerCheckpointPage {
+        const NAME: &'static str = "PdsContainerCheckpointPage";
+        type Type = super::ContainerCheckpointPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action_async(ACTION_SELECT_PATH, None, |widget, _, _| async move {
+                widget.select_path().await;
+            });
+            klass.install_action(ACTION_CHECKPOINT, None, |widget, _, _| {
+                widget.checkpoint();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerCheckpointPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().action_set_enabled(ACTION_CHECKPOINT, false);
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for ContainerCheckpointPage {
+        fn root(&self) {
+            self.parent_root();
+            utils::root(self.obj().upcast_ref()).set_default_widget(Some(&*self.checkpoint_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+
+    impl ContainerCheckpointPage {
+        pub(super) fn set_container(&self, value: Option<&model::Container>) {
+            let obj = &*self.obj();
+            if obj.container().as_ref() == value {
+                return;
+            }
+
+            if let Some(container) = value {
+                container.connect_deleted(clone!(@weak obj => move |_| {
+                    obj.activate_action("win.close", None).unwrap();
+                }));
+            }
+
+            self.container.set(value);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerCheckpointPage(ObjectSubclass<imp::ContainerCheckpointPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ContainerCheckpointPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerCheckpointPage {
+    async fn select_path(&self) {
+        let current_name = self
+            .container()
+            .map(|container| format!("{}-checkpoint.tar.gz", container.name()))
+            .unwrap_or_default();
+
+        let request = SaveFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Checkpoint Destination").as_str())
+            .current_name(current_name.as_str())
+            .filter(
+                FileFilter::new(&gettext("Compressed Tar Archive"))
+                    .mimetype("application/x-compressed-tar"),
+            )
+            .modal(true);
+
+        utils::show_save_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    let imp = obj.imp();
+
+                    imp.path_row.set_subtitle(&path.to_string_lossy());
+                    imp.path.replace(Some(path));
+                    obj.action_set_enabled(ACTION_CHECKPOINT, true);
+                }
+            }),
+        )
+        .await;
+    }
+
+    fn checkpoint(&self) {
+        let imp = self.imp();
+
+        if let Some((path, container)) = imp.path.borrow().clone().zip(self.container()) {
+            let opts = podman::opts::ContainerCheckpointOpts::builder()
+                .leave_running(imp.leave_running_switch_row.is_active())
+                .ignore_volumes(!imp.volumes_switch_row.is_active())
+                .tcp_established(imp.tcp_switch_row.is_active())
+                .build();
+
+            let page = view::ActionPage::from(
+                &container
+                    .container_list()
+                    .unwrap()
+                    .client()
+                    .unwrap()
+                    .action_list()
+                    .checkpoint_container(&container, path, opts),
+            );
+
+            imp.navigation_view.push(
+                &adw::NavigationPage::builder()
+                    .can_pop(false)
+                    .child(&page)
+                    .build(),
+            );
+        }
+    }
+}
@@ -22,6 +22,8 @@ const ACTION_SELECT_POD: &str = "container-creation-page.select-pod";
 const ACTION_CLEAR_POD: &str = "container-creation-page.clear-pod";
 const ACTION_ADD_PORT_MAPPING: &str = "container-creation-page.add-port-mapping";
 const ACTION_ADD_VOLUME: &str = "container-creation-page.add-volume";
+const ACTION_ADD_NETWORK: &str = "container-creation-page.add-network";
+const ACTION_ADD_SECRET: &str = "container-creation-page.add-secret";
 const ACTION_ADD_ENV_VAR: &str = "container-creation-page.add-env-var";
 const ACTION_ADD_LABEL: &str = "container-creation-page.add-label";
 const ACTION_CREATE_AND_RUN: &str = "container-creation-page.create-and-run";
@@ -37,6 +39,8 @@ mod imp {
         pub(super) cmd_args: OnceCell<gio::ListStore>,
         pub(super) port_mappings: OnceCell<gio::ListStore>,
         pub(super) volumes: OnceCell<gio::ListStore>,
+        pub(super) network_attachments: OnceCell<gio::ListStore>,
+        pub(super) secret_mounts: OnceCell<gio::ListStore>,
         pub(super) env_vars: OnceCell<gio::ListStore>,
         pub(super) labels: OnceCell<gio::ListStore>,
         pub(super) command_row_handler:
@@ -82,6 +86,20 @@ mod imp {
         #[template_child]
         pub(super) volume_list_box: TemplateChild<gtk::ListBox>,
         #[template_child]
+        pub(super) network_preferences_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) network_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) dns_preferences_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) dns_servers_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) dns_search_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) dns_options_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) secret_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
         pub(super) env_var_list_box: TemplateChild<gtk::ListBox>,
         #[template_child]
         pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
@@ -122,6 +140,12 @@ mod imp {
             klass.install_action(ACTION_ADD_VOLUME, None, |widget, _, _| {
                 widget.add_mount();
             });
+            klass.install_action(ACTION_ADD_NETWORK, None, |widget, _, _| {
+                widget.add_network_attachment();
+            });
+            klass.install_action(ACTION_ADD_SECRET, None, |widget, _, _| {
+                widget.add_secret_mount();
+            });
             klass.install_action(ACTION_ADD_ENV_VAR, None, |widget, _, _| {
                 widget.add_env_var();
             });
@@ -170,15 +194,18 @@ mod imp {
                     .unwrap_or_default()))
                 .bind(&self.pod_row.get(), "subtitle", Some(obj));
 
-            pod_expr
-                .chain_closure::<bool>(closure!(|_: Self::Type, pod: Option<&model::Pod>| {
-                    pod.is_none()
-                }))
-                .bind(
-                    &self.port_mapping_preferences_group.get(),
-                    "visible",
-                    Some(obj),
-                );
+            let no_pod_expr =
+                pod_expr.chain_closure::<bool>(closure!(
+                    |_: Self::Type, pod: Option<&model::Pod>| { pod.is_none() }
+                ));
+            no_pod_expr.bind(
+                &self.port_mapping_preferences_group.get(),
+                "visible",
+                Some(obj),
+            );
+            // Containers in a pod share the network namespace of the pod's infra container.
+            no_pod_expr.bind(&self.network_preferences_group.get(), "visible", Some(obj));
+            no_pod_expr.bind(&self.dns_preferences_group.get(), "visible", Some(obj));
 
             if let Some(image) = obj.image() {
                 self.image_selection_combo_row.set_image(Some(image));
@@ -219,6 +246,30 @@ mod imp {
                 &gettext("Add Volume"),
             );
 
+            bind_model(
+                &self.network_list_box,
+                self.network_attachments(),
+                |item| {
+                    view::NetworkAttachmentRow::from(
+                        item.downcast_ref::<model::NetworkAttachment>().unwrap(),
+                    )
+                    .upcast()
+                },
+                ACTION_ADD_NETWORK,
+                &gettext("Add Network"),
+            );
+
+            bind_model(
+                &self.secret_list_box,
+                self.secret_mounts(),
+                |item| {
+                    view::SecretMountRow::from(item.downcast_ref::<model::SecretMount>().unwrap())
+                        .upcast()
+                },
+                ACTION_ADD_SECRET,
+                &gettext("Add Secret"),
+            );
+
             bind_model(
                 &self.env_var_list_box,
                 self.env_vars(),
@@ -297,6 +348,16 @@ mod imp {
                 .get_or_init(gio::ListStore::new::<model::Mount>)
         }
 
+        pub(super) fn network_attachments(&self) -> &gio::ListStore {
+            self.network_attachments
+                .get_or_init(gio::ListStore::new::<model::NetworkAttachment>)
+        }
+
+        pub(super) fn secret_mounts(&self) -> &gio::ListStore {
+            self.secret_mounts
+                .get_or_init(gio::ListStore::new::<model::SecretMount>)
+        }
+
         pub(super) fn env_vars(&self) -> &gio::ListStore {
             self.env_vars
                 .get_or_init(gio::ListStore::new::<model::KeyVal>)
@@ -477,6 +538,18 @@ impl ContainerCreationPage {
             .map(|ref client| add_mount(self.imp().volumes(), client))
     }
 
+    fn add_network_attachment(&self) {
+        if let Some(ref client) = self.client() {
+            add_network_attachment(self.imp().network_attachments(), client);
+        }
+    }
+
+    fn add_secret_mount(&self) {
+        if let Some(ref client) = self.client() {
+            add_secret_mount(self.imp().secret_mounts(), client);
+        }
+    }
+
     fn add_env_var(&self) {
         add_key_val(self.imp().env_vars());
     }
@@ -607,6 +680,35 @@ impl ContainerCreationPage {
                         options: mount_options(&mount),
                     }),
             )
+            .secrets(
+                imp.secret_mounts()
+                    .iter::<model::SecretMount>()
+                    .map(Result::unwrap)
+                    .filter(|secret_mount| {
+                        secret_mount.mount_type() == model::SecretMountType::File
+                    })
+                    .filter_map(|secret_mount| {
+                        secret_mount.secret().map(|secret| podman::models::Secret {
+                            gid: None,
+                            key: None,
+                            mode: None,
+                            source: Some(secret.name()),
+                            target: secret_mount.effective_target(),
+                            uid: None,
+                        })
+                    }),
+            )
+            .secret_env(
+                imp.secret_mounts()
+                    .iter::<model::SecretMount>()
+                    .map(Result::unwrap)
+                    .filter(|secret_mount| secret_mount.mount_type() == model::SecretMountType::Env)
+                    .filter_map(|secret_mount| {
+                        secret_mount
+                            .effective_target()
+                            .zip(secret_mount.secret().as_ref().map(model::Secret::name))
+                    }),
+            )
             .env(
                 imp.env_vars()
                     .iter::<model::KeyVal>()
@@ -648,6 +750,21 @@ impl ContainerCreationPage {
             create_opts
         };
 
+        let create_opts = if self.pod().is_none() {
+            create_opts
+                .networks(
+                    imp.network_attachments()
+                        .iter::<model::NetworkAttachment>()
+                        .map(Result::unwrap)
+                        .filter_map(|attachment| attachment.per_network_options()),
+                )
+                .dns_server(utils::split_list(&imp.dns_servers_entry_row.text()))
+                .dns_search(utils::split_list(&imp.dns_search_entry_row.text()))
+                .dns_option(utils::split_list(&imp.dns_options_entry_row.text()))
+        } else {
+            create_opts
+        };
+
         let cmd = imp.command_entry_row.text();
         let create_opts = if cmd.is_empty() {
             create_opts
@@ -737,6 +854,30 @@ fn add_mount(model: &gio::ListStore, client: &model::Client) -> model::Mount {
     mount
 }
 
+fn add_network_attachment(model: &gio::ListStore, client: &model::Client) {
+    let network_attachment = model::NetworkAttachment::from(client);
+
+    network_attachment.connect_remove_request(clone!(@weak model => move |network_attachment| {
+        if let Some(pos) = model.find(network_attachment) {
+            model.remove(pos);
+        }
+    }));
+
+    model.append(&network_attachment);
+}
+
+fn add_secret_mount(model: &gio::ListStore, client: &model::Client) {
+    let secret_mount = model::SecretMount::from(client);
+
+    secret_mount.connect_remove_request(clone!(@weak model => move |secret_mount| {
+        if let Some(pos) = model.find(secret_mount) {
+            model.remove(pos);
+        }
+    }));
+
+    model.append(&secret_mount);
+}
+
 fn add_value(model: &gio::ListStore) {
     let value = model::Value::default();
 
@@ -31,9 +31,13 @@ const ACTION_DELETE: &str = "container-details-page.delete";
 
 const ACTION_INSPECT: &str = "container-details-page.inspect";
 const ACTION_GENERATE_KUBE: &str = "container-details-page.generate-kube";
+const ACTION_GENERATE_QUADLET: &str = "container-details-page.generate-quadlet";
+const ACTION_GENERATE_SYSTEMD: &str = "container-details-page.generate-systemd";
 const ACTION_SHOW_TTY: &str = "container-details-page.show-tty";
 const ACTION_SHOW_LOG: &str = "container-details-page.show-log";
 const ACTION_SHOW_PROCESSES: &str = "container-details-page.show-processes";
+const ACTION_SHOW_ALERTS: &str = "container-details-page.show-alerts";
+const ACTION_COPY_LINK: &str = "container-details-page.copy-link";
 
 mod imp {
     use super::*;
@@ -70,6 +74,10 @@ mod imp {
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
 
+            klass.install_action(ACTION_COPY_LINK, None, |widget, _, _| {
+                widget.copy_link();
+            });
+
             klass.install_action(ACTION_RENAME, None, |widget, _, _| {
                 view::container::rename(widget.upcast_ref(), widget.container().as_ref());
             });
@@ -122,6 +130,12 @@ mod imp {
             klass.install_action(ACTION_GENERATE_KUBE, None, |widget, _, _| {
                 widget.show_kube();
             });
+            klass.install_action(ACTION_GENERATE_QUADLET, None, |widget, _, _| {
+                widget.show_quadlet();
+            });
+            klass.install_action(ACTION_GENERATE_SYSTEMD, None, |widget, _, _| {
+                widget.show_systemd();
+            });
             klass.install_action(ACTION_SHOW_TTY, None, |widget, _, _| {
                 widget.show_tty();
             });
@@ -131,6 +145,9 @@ mod imp {
             klass.install_action(ACTION_SHOW_PROCESSES, None, |widget, _, _| {
                 widget.show_processes();
             });
+            klass.install_action(ACTION_SHOW_ALERTS, None, |widget, _, _| {
+                widget.show_alerts();
+            });
 
             klass.add_binding_action(gdk::Key::F2, gdk::ModifierType::empty(), ACTION_RENAME);
             klass.add_binding_action(gdk::Key::K, gdk::ModifierType::CONTROL_MASK, ACTION_COMMIT);
@@ -282,6 +299,26 @@ impl From<&model::Container> for ContainerDetailsPage {
 }
 
 impl ContainerDetailsPage {
+    fn copy_link(&self) {
+        if let Some((container, client)) = self.container().and_then(|container| {
+            container
+                .container_list()
+                .and_then(|list| list.client())
+                .map(|client| (container, client))
+        }) {
+            utils::copy_to_clipboard(
+                self.upcast_ref(),
+                &model::DeepLink::new(
+                    &client.connection().name(),
+                    model::DeepLinkKind::Container,
+                    &container.id(),
+                )
+                .to_string(),
+                &gettext("Link copied"),
+            );
+        }
+    }
+
     fn update_volumes_visibility(&self) {
         let imp = self.imp();
         imp.volumes_group
@@ -395,6 +432,14 @@ impl ContainerDetailsPage {
         self.show_kube_inspection_or_kube(view::ScalableTextViewMode::Kube);
     }
 
+    pub(crate) fn show_quadlet(&self) {
+        self.show_kube_inspection_or_kube(view::ScalableTextViewMode::Quadlet);
+    }
+
+    pub(crate) fn show_systemd(&self) {
+        self.show_kube_inspection_or_kube(view::ScalableTextViewMode::Systemd);
+    }
+
     pub(crate) fn show_kube_inspection_or_kube(&self, mode: view::ScalableTextViewMode) {
         self.exec_action(|| {
             if let Some(container) = self.container() {
@@ -437,18 +482,49 @@ impl ContainerDetailsPage {
         });
     }
 
+    pub(crate) fn show_alerts(&self) {
+        if let Some((container, client)) = self.container().and_then(|container| {
+            container
+                .container_list()
+                .and_then(|list| list.client())
+                .map(|client| (container, client))
+        }) {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::AlertRulesPage::new(
+                    &client.alert_monitor(),
+                    model::AlertSubject::Container,
+                    &container.name(),
+                )
+                .upcast_ref(),
+            )
+            .present();
+        }
+    }
+
     pub(crate) fn show_tty(&self) {
         self.exec_action(|| {
             if let Some(container) = self.container() {
-                utils::navigation_view(self.upcast_ref()).push(
-                    &adw::NavigationPage::builder()
-                        .child(&view::ContainerTerminalPage::from(&container))
-                        .build(),
-                );
+                let exec_page = view::ContainerExecPage::from(&container);
+                exec_page.connect_launch(clone!(@weak self as obj => move |exec_page| {
+                    obj.open_terminal(exec_page);
+                }));
+
+                utils::Dialog::new(self.upcast_ref(), exec_page.upcast_ref()).present();
             }
         });
     }
 
+    fn open_terminal(&self, exec_page: &view::ContainerExecPage) {
+        if let Some(container) = self.container() {
+            utils::navigation_view(self.upcast_ref()).push(
+                &adw::NavigationPage::builder()
+                    .child(&view::ContainerTerminalPage::new(&container, exec_page))
+                    .build(),
+            );
+        }
+    }
+
     fn exec_action<F: Fn()>(&self, op: F) {
         if utils::navigation_view(self.upcast_ref())
             .visible_page()
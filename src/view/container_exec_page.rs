@@ -0,0 +1,333 @@
+use std::cell::OnceCell;
+use std::sync::OnceLock;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use glib::subclass::Signal;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_ADD_ENV_VAR: &str = "container-exec-page.add-env-var";
+const ACTION_USE_ROOT: &str = "container-exec-page.use-root";
+const ACTION_CONNECT: &str = "container-exec-page.connect";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerExecPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_exec_page.ui")]
+    pub(crate) struct ContainerExecPage {
+        pub(super) env_vars: OnceCell<gio::ListStore>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[template_child]
+        pub(super) connect_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) command_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) custom_command_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) user_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) workdir_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) privileged_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) env_vars_list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerExecPage {
+        const NAME: &'static str = "PdsContainerExecPage";
+        type Type = super::ContainerExecPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action(ACTION_ADD_ENV_VAR, None, |widget, _, _| {
+                widget.add_env_var();
+            });
+            klass.install_action(ACTION_USE_ROOT, None, |widget, _, _| {
+                widget.imp().user_entry_row.set_text("root");
+            });
+            klass.install_action(ACTION_CONNECT, None, |widget, _, _| {
+                widget.launch();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerExecPage {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| vec![Signal::builder("launch").build()])
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.env_vars_list_box
+                .bind_model(Some(self.env_vars()), |item| {
+                    view::KeyValRow::from(item.downcast_ref::<model::KeyVal>().unwrap()).upcast()
+                });
+            self.env_vars_list_box.append(
+                &gtk::ListBoxRow::builder()
+                    .action_name(ACTION_ADD_ENV_VAR)
+                    .selectable(false)
+                    .child(
+                        &gtk::Label::builder()
+                            .label(gettext("Add Environment Variable"))
+                            .margin_top(12)
+                            .margin_bottom(12)
+                            .build(),
+                    )
+                    .build(),
+            );
+
+            let exec_config = obj
+                .image_key()
+                .and_then(|image| model::ExecConfig::load_for_image(&image));
+            let has_defaults = exec_config.is_some();
+            obj.set_exec_config(&exec_config.unwrap_or_default());
+
+            obj.detect_shells(!has_defaults);
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for ContainerExecPage {
+        fn root(&self) {
+            self.parent_root();
+            utils::root(self.obj().upcast_ref()).set_default_widget(Some(&*self.connect_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+
+    #[gtk::template_callbacks]
+    impl ContainerExecPage {
+        #[template_callback]
+        fn on_command_combo_row_notify_selected(&self) {
+            self.custom_command_entry_row
+                .set_visible(self.command_combo_row.selected() as usize >= model::SHELLS.len());
+        }
+    }
+
+    impl ContainerExecPage {
+        pub(super) fn env_vars(&self) -> &gio::ListStore {
+            self.env_vars
+                .get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerExecPage(ObjectSubclass<imp::ContainerExecPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ContainerExecPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerExecPage {
+    /// The key of the remembered defaults. They are shared by all containers of an image.
+    fn image_key(&self) -> Option<String> {
+        self.container().map(|container| {
+            container
+                .image_name()
+                .unwrap_or_else(|| container.image_id())
+        })
+    }
+
+    pub(crate) fn exec_config(&self) -> model::ExecConfig {
+        let imp = self.imp();
+
+        model::ExecConfig {
+            command: model::SHELLS
+                .get(imp.command_combo_row.selected() as usize)
+                .map(|shell| shell.to_string())
+                .unwrap_or_else(|| imp.custom_command_entry_row.text().trim().to_owned()),
+            user: imp.user_entry_row.text().trim().to_owned(),
+            workdir: imp.workdir_entry_row.text().trim().to_owned(),
+            env: imp
+                .env_vars()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .filter(|entry| !entry.key().is_empty())
+                .map(|entry| (entry.key(), entry.value()))
+                .collect(),
+            privileged: imp.privileged_switch_row.is_active(),
+        }
+    }
+
+    fn set_exec_config(&self, exec_config: &model::ExecConfig) {
+        let imp = self.imp();
+
+        match model::SHELLS
+            .iter()
+            .position(|shell| *shell == exec_config.command)
+        {
+            Some(position) => imp.command_combo_row.set_selected(position as u32),
+            None => {
+                imp.command_combo_row
+                    .set_selected(model::SHELLS.len() as u32);
+                imp.custom_command_entry_row.set_text(&exec_config.command);
+            }
+        }
+        imp.user_entry_row.set_text(&exec_config.user);
+        imp.workdir_entry_row.set_text(&exec_config.workdir);
+        imp.privileged_switch_row.set_active(exec_config.privileged);
+
+        imp.env_vars().remove_all();
+        exec_config.env.iter().for_each(|(key, value)| {
+            let entry = self.add_env_var();
+            entry.set_key(key.as_str());
+            entry.set_value(value.as_str());
+        });
+    }
+
+    /// Looks for the available shells and selects the preferred one if requested. The result is
+    /// remembered by the container.
+    fn detect_shells(&self, select: bool) {
+        let Some(container) = self.container() else {
+            return;
+        };
+
+        if let Some(shells) = container.shells() {
+            self.show_shells(shells, select);
+            return;
+        }
+
+        if let Some(api) = container.api() {
+            self.imp()
+                .command_combo_row
+                .set_subtitle(&gettext("Detecting available shells…"));
+
+            utils::do_async(
+                model::detect_shells(api),
+                clone!(@weak self as obj, @weak container => move |shells| {
+                    obj.show_shells(&shells, select);
+                    container.set_shells(shells);
+                }),
+            );
+        }
+    }
+
+    fn show_shells(&self, shells: &[&str], select: bool) {
+        let command_combo_row = &*self.imp().command_combo_row;
+
+        if shells.is_empty() {
+            command_combo_row.set_subtitle(&gettext("No shell found"));
+            if select {
+                command_combo_row.set_selected(model::SHELLS.len() as u32);
+            }
+        } else {
+            command_combo_row.set_subtitle(&gettext!("Available: {}", shells.join(", ")));
+            if select {
+                if let Some(position) = model::SHELLS.iter().position(|shell| *shell == shells[0]) {
+                    command_combo_row.set_selected(position as u32);
+                }
+            }
+        }
+    }
+
+    fn add_env_var(&self) -> model::KeyVal {
+        let env_vars = self.imp().env_vars();
+        let entry = model::KeyVal::default();
+
+        entry.connect_remove_request(clone!(@weak env_vars => move |entry| {
+            if let Some(pos) = env_vars.find(entry) {
+                env_vars.remove(pos);
+            }
+        }));
+
+        env_vars.append(&entry);
+
+        entry
+    }
+
+    fn launch(&self) {
+        let exec_config = self.exec_config();
+
+        if let Err(e) = exec_config.argv() {
+            utils::show_error_toast(
+                self.upcast_ref(),
+                &gettext("Invalid command"),
+                &e.to_string(),
+            );
+            return;
+        }
+
+        if let Some(image) = self.image_key() {
+            exec_config.store_for_image(&image);
+        }
+
+        // The page stays open until the terminal has been started to show possible errors.
+        self.action_set_enabled(ACTION_CONNECT, false);
+        self.emit_by_name::<()>("launch", &[]);
+    }
+
+    /// Closes the page after the terminal has been started.
+    pub(crate) fn finish_launch(&self) {
+        self.activate_action("win.close", None).unwrap();
+    }
+
+    /// Shows why the terminal could not be started and allows to try it again.
+    pub(crate) fn fail_launch(&self, message: &str) {
+        self.action_set_enabled(ACTION_CONNECT, true);
+        utils::show_error_toast(
+            self.upcast_ref(),
+            &gettext("Error on starting terminal"),
+            message,
+        );
+    }
+
+    pub(crate) fn connect_launch<F: Fn(&Self) + 'static>(&self, f: F) -> glib::SignalHandlerId {
+        self.connect_local("launch", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            f(&obj);
+
+            None
+        })
+    }
+}
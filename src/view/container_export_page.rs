@@ -0,0 +1,184 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::SaveFileRequest;
+use ashpd::WindowIdentifier;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_PATH: &str = "container-export-page.select-path";
+const ACTION_EXPORT: &str = "container-export-page.export";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerExportPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_export_page.ui")]
+    pub(crate) struct ContainerExportPage {
+        pub(super) path: RefCell<Option<PathBuf>>,
+        #[property(get, set = Self::set_container, construct, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) export_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) path_row: TemplateChild<adw::ActionRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerExportPage {
+        const NAME: &'static str = "PdsContainerExportPage";
+        type Type = super::ContainerExportPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action_async(ACTION_SELECT_PATH, None, |widget, _, _| async move {
+                widget.select_path().await;
+            });
+            klass.install_action(ACTION_EXPORT, None, |widget, _, _| {
+                widget.export();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerExportPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().action_set_enabled(ACTION_EXPORT, false);
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for ContainerExportPage {
+        fn root(&self) {
+            self.parent_root();
+            utils::root(self.obj().upcast_ref()).set_default_widget(Some(&*self.export_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+
+    impl ContainerExportPage {
+        pub(super) fn set_container(&self, value: Option<&model::Container>) {
+            let obj = &*self.obj();
+            if obj.container().as_ref() == value {
+                return;
+            }
+
+            if let Some(container) = value {
+                container.connect_deleted(clone!(@weak obj => move |_| {
+                    obj.activate_action("win.close", None).unwrap();
+                }));
+            }
+
+            self.container.set(value);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerExportPage(ObjectSubclass<imp::ContainerExportPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ContainerExportPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerExportPage {
+    async fn select_path(&self) {
+        let current_name = self
+            .container()
+            .map(|container| format!("{}.tar", container.name()))
+            .unwrap_or_default();
+
+        let request = SaveFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Destination Archive").as_str())
+            .current_name(current_name.as_str())
+            .filter(FileFilter::new(&gettext("Tar Archive")).mimetype("application/x-tar"))
+            .modal(true);
+
+        utils::show_save_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    let imp = obj.imp();
+
+                    imp.path_row.set_subtitle(&path.to_string_lossy());
+                    imp.path.replace(Some(path));
+                    obj.action_set_enabled(ACTION_EXPORT, true);
+                }
+            }),
+        )
+        .await;
+    }
+
+    fn export(&self) {
+        let imp = self.imp();
+
+        if let Some((path, container)) = imp.path.borrow().clone().zip(self.container()) {
+            let page = view::ActionPage::from(
+                &container
+                    .container_list()
+                    .unwrap()
+                    .client()
+                    .unwrap()
+                    .action_list()
+                    .export_container(&container, path),
+            );
+
+            imp.navigation_view.push(
+                &adw::NavigationPage::builder()
+                    .can_pop(false)
+                    .child(&page)
+                    .build(),
+            );
+        }
+    }
+}
@@ -9,6 +9,7 @@ use std::mem;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
@@ -29,18 +30,39 @@ use sourceview5::prelude::*;
 use crate::model;
 use crate::podman;
 use crate::utils;
+use crate::view;
 use crate::widget;
 
 const ACTION_TOGGLE_SEARCH: &str = "container-log-page.toggle-search";
 const ACTION_EXIT_SEARCH: &str = "container-log-page.exit-search";
 const ACTION_SAVE_TO_FILE: &str = "container-log-page.save-to-file";
 const ACTION_SHOW_TIMESTAMPS: &str = "container-log-page.show-timestamps";
+const ACTION_TOGGLE_STRUCTURED: &str = "container-log-page.toggle-structured";
+const ACTION_SET_STREAM: &str = "container-log-page.set-stream";
+const ACTION_APPLY_TIME_RANGE: &str = "container-log-page.apply-time-range";
+const ACTION_RESET_TIME_RANGE: &str = "container-log-page.reset-time-range";
+const ACTION_TOGGLE_BOOKMARK: &str = "container-log-page.toggle-bookmark";
+const ACTION_PREVIOUS_BOOKMARK: &str = "container-log-page.previous-bookmark";
+const ACTION_NEXT_BOOKMARK: &str = "container-log-page.next-bookmark";
+const ACTION_EDIT_HIGHLIGHT_RULES: &str = "container-log-page.edit-highlight-rules";
 const ACTION_SCROLL_DOWN: &str = "container-log-page.scroll-down";
 const ACTION_START_CONTAINER: &str = "container-log-page.start-container";
 const ACTION_ZOOM_OUT: &str = "container-log-page.zoom-out";
 const ACTION_ZOOM_IN: &str = "container-log-page.zoom-in";
 const ACTION_ZOOM_NORMAL: &str = "container-log-page.zoom-normal";
 
+/// The maximum number of lines that are kept. The oldest lines are dropped beyond that.
+const MAX_LINES: usize = 50_000;
+/// The time in milliseconds to wait for further keystrokes before the filter is applied.
+const FILTER_DELAY: u64 = 300;
+
+/// A line of the log as it is needed to render it in either mode.
+#[derive(Debug)]
+struct LogLine {
+    markup: String,
+    structured: Option<model::StructuredLogLine>,
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 enum FetchLinesState {
     #[default]
@@ -58,7 +80,14 @@ mod imp {
     pub(crate) struct ContainerLogPage {
         pub(super) settings: utils::PodsSettings,
         pub(super) log_timestamps: RefCell<VecDeque<String>>,
-        pub(super) fetch_until: OnceCell<String>,
+        pub(super) log_lines: RefCell<VecDeque<LogLine>>,
+        pub(super) filter: RefCell<model::LogFilter>,
+        pub(super) filter_source: RefCell<Option<glib::SourceId>>,
+        pub(super) hidden_tag: OnceCell<gtk::TextTag>,
+        pub(super) highlights: RefCell<Vec<(glib::Regex, gtk::TextTag)>>,
+        pub(super) time_range: RefCell<model::LogTimeRange>,
+        pub(super) stream_generation: Cell<u32>,
+        pub(super) fetch_until: RefCell<Option<String>>,
         pub(super) fetch_lines_state: Cell<FetchLinesState>,
         pub(super) fetched_lines: RefCell<VecDeque<Vec<u8>>>,
         pub(super) prev_adj: Cell<f64>,
@@ -67,6 +96,11 @@ mod imp {
         pub(super) container: glib::WeakRef<model::Container>,
         #[property(get, set)]
         pub(super) sticky: Cell<bool>,
+        #[property(get, set = Self::set_structured, explicit_notify)]
+        pub(super) structured: Cell<bool>,
+        /// Either `all`, `stdout` or `stderr`.
+        #[property(get, set = Self::set_stream, explicit_notify)]
+        pub(super) stream: RefCell<String>,
         #[template_child]
         pub(super) zoom_control: TemplateChild<widget::ZoomControl>,
         #[template_child]
@@ -74,6 +108,12 @@ mod imp {
         #[template_child]
         pub(super) menu_button: TemplateChild<gtk::MenuButton>,
         #[template_child]
+        pub(super) time_range_button: TemplateChild<gtk::MenuButton>,
+        #[template_child]
+        pub(super) since_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) until_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
         pub(super) search_button: TemplateChild<gtk::ToggleButton>,
         #[template_child]
         pub(super) search_bar: TemplateChild<gtk::SearchBar>,
@@ -91,6 +131,8 @@ mod imp {
         pub(super) source_buffer: TemplateChild<sourceview5::Buffer>,
         #[template_child]
         pub(super) banner: TemplateChild<adw::Banner>,
+        #[template_child]
+        pub(super) filter_entry: TemplateChild<gtk::SearchEntry>,
     }
 
     #[glib::object_subclass]
@@ -125,6 +167,46 @@ mod imp {
                 widget.save_to_file().await;
             });
             klass.install_property_action(ACTION_SHOW_TIMESTAMPS, "show-timestamps");
+            klass.install_property_action(ACTION_TOGGLE_STRUCTURED, "structured");
+            klass.install_property_action(ACTION_SET_STREAM, "stream");
+
+            klass.install_action(ACTION_APPLY_TIME_RANGE, None, |widget, _, _| {
+                widget.apply_time_range();
+            });
+            klass.install_action(ACTION_RESET_TIME_RANGE, None, |widget, _, _| {
+                widget.reset_time_range();
+            });
+
+            klass.add_binding_action(
+                gdk::Key::D,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_TOGGLE_BOOKMARK,
+            );
+            klass.install_action(ACTION_TOGGLE_BOOKMARK, None, |widget, _, _| {
+                widget.imp().search_widget.toggle_bookmark();
+            });
+
+            klass.add_binding_action(
+                gdk::Key::F2,
+                gdk::ModifierType::SHIFT_MASK,
+                ACTION_PREVIOUS_BOOKMARK,
+            );
+            klass.install_action(ACTION_PREVIOUS_BOOKMARK, None, |widget, _, _| {
+                widget.imp().search_widget.bookmark_backward();
+            });
+
+            klass.add_binding_action(
+                gdk::Key::F2,
+                gdk::ModifierType::empty(),
+                ACTION_NEXT_BOOKMARK,
+            );
+            klass.install_action(ACTION_NEXT_BOOKMARK, None, |widget, _, _| {
+                widget.imp().search_widget.bookmark_forward();
+            });
+
+            klass.install_action(ACTION_EDIT_HIGHLIGHT_RULES, None, |widget, _, _| {
+                widget.edit_highlight_rules();
+            });
 
             klass.install_action(ACTION_SCROLL_DOWN, None, |widget, _, _| {
                 widget.scroll_down();
@@ -225,6 +307,22 @@ mod imp {
                 )
                 .build();
 
+            let hidden_tag = gtk::TextTag::builder().invisible(true).build();
+            self.source_buffer.tag_table().add(&hidden_tag);
+            self.hidden_tag.set(hidden_tag).unwrap();
+
+            self.settings
+                .bind("structured-logs", obj, "structured")
+                .build();
+
+            obj.update_highlights();
+            model::LogHighlightRule::connect_changed(
+                &self.settings,
+                clone!(@weak obj => move |_| {
+                    obj.update_highlights();
+                }),
+            );
+
             self.menu_button
                 .popover()
                 .unwrap()
@@ -349,6 +447,73 @@ mod imp {
                 self.search_widget.set_text("");
             }
         }
+
+        #[template_callback]
+        fn on_time_range_entry_row_activated(&self) {
+            self.obj().apply_time_range();
+        }
+
+        #[template_callback]
+        fn on_filter_entry_search_changed(&self) {
+            let filter_entry = &*self.filter_entry;
+
+            match filter_entry.text().parse::<model::LogFilter>() {
+                Ok(filter) => {
+                    filter_entry.remove_css_class("error");
+                    filter_entry.set_tooltip_text(None);
+
+                    if let Some(source_id) = self.filter_source.take() {
+                        source_id.remove();
+                    }
+
+                    if *self.filter.borrow() != filter {
+                        let obj = &*self.obj();
+                        self.filter_source
+                            .replace(Some(glib::timeout_add_local_once(
+                                Duration::from_millis(FILTER_DELAY),
+                                clone!(@weak obj => move || {
+                                    let imp = obj.imp();
+                                    imp.filter_source.take();
+                                    imp.filter.replace(filter);
+                                    obj.rebuild_buffer();
+                                }),
+                            )));
+                    }
+                }
+                Err(e) => {
+                    filter_entry.add_css_class("error");
+                    filter_entry.set_tooltip_text(Some(&e.to_string()));
+                }
+            }
+        }
+    }
+
+    impl ContainerLogPage {
+        fn set_structured(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.structured() == value {
+                return;
+            }
+
+            self.structured.set(value);
+            obj.rebuild_buffer();
+            obj.notify_structured();
+        }
+
+        fn set_stream(&self, value: String) {
+            let obj = &*self.obj();
+            if obj.stream() == value {
+                return;
+            }
+
+            let reload = !self.stream.borrow().is_empty();
+
+            self.stream.replace(value);
+            if reload {
+                obj.reload_log();
+            }
+            obj.notify_stream();
+        }
     }
 }
 
@@ -406,33 +571,52 @@ impl ContainerLogPage {
 
     fn init_log(&self) {
         if let Some(container) = self.container().as_ref().and_then(model::Container::api) {
+            let imp = self.imp();
+
+            let generation = imp.stream_generation.get();
+            let time_range = imp.time_range.borrow();
+
+            // The range could span the whole log, so only its end is loaded at first. Older lines
+            // down to the start of the range are loaded when scrolling up.
+            let opts = self.logs_opts_builder(false, true, &time_range).tail("512");
+
             let mut perform = MarkupPerform::default();
 
             utils::run_stream_with_finish_handler(
                 container,
-                move |container| {
-                    container
-                        .logs(&basic_opts_builder(false, true).tail("512").build())
-                        .boxed()
-                },
+                move |container| container.logs(&opts.build()).boxed(),
                 clone!(@weak self as obj => @default-return glib::ControlFlow::Break, move |result| {
+                    if !obj.is_current_stream(generation) {
+                        return glib::ControlFlow::Break;
+                    }
                     obj.imp().stack.set_visible_child_name("loaded");
                     obj.append_line(result, &mut perform)
                 }),
                 clone!(@weak self as obj => move || {
-                    obj.imp().stack.set_visible_child_name("loaded");
-                    obj.follow_log();
+                    if obj.is_current_stream(generation) {
+                        obj.imp().stack.set_visible_child_name("loaded");
+                        obj.follow_log();
+                    }
                 }),
             );
         }
     }
 
     fn follow_log(&self) {
+        let imp = self.imp();
+
+        let time_range = imp.time_range.borrow();
+        if time_range.until.is_some() {
+            return;
+        }
+
         if let Some(container) = self.container().as_ref().and_then(model::Container::api) {
-            let timestamps = self.imp().log_timestamps.borrow();
+            let generation = imp.stream_generation.get();
+
+            let timestamps = imp.log_timestamps.borrow();
             let mut iter = timestamps.iter().rev();
 
-            let opts = basic_opts_builder(true, true);
+            let opts = self.logs_opts_builder(true, true, &time_range);
             let (opts, skip) = match iter.next() {
                 Some(last) => (
                     opts.since(
@@ -452,7 +636,9 @@ impl ContainerLogPage {
                 container,
                 move |container| container.logs(&opts.build()).boxed(),
                 clone!(@weak self as obj => @default-return glib::ControlFlow::Break, move |result: podman::Result<podman::conn::TtyChunk>| {
-                    if skip.load(Ordering::Relaxed) == 0 {
+                    if !obj.is_current_stream(generation) {
+                        glib::ControlFlow::Break
+                    } else if skip.load(Ordering::Relaxed) == 0 {
                         obj.append_line(result, &mut perform)
                     } else {
                         skip.fetch_sub(1, Ordering::Relaxed);
@@ -488,33 +674,294 @@ impl ContainerLogPage {
     fn insert(&self, line: Vec<u8>, perform: &mut MarkupPerform, at_end: bool) {
         let imp = self.imp();
 
+        if imp.log_lines.borrow().len() >= MAX_LINES {
+            // Older lines aren't loaded anymore once the limit is reached.
+            if !at_end {
+                return;
+            }
+            self.remove_first_line();
+        }
+
         let line_buffer = perform.decode(&line);
         let (timestamp, log_message) = line_buffer.split_once(' ').unwrap();
 
-        imp.fetch_until.get_or_init(|| timestamp.to_owned());
+        if imp.fetch_until.borrow().is_none() {
+            imp.fetch_until.replace(Some(timestamp.to_owned()));
+        }
+
+        let log_line = LogLine {
+            markup: log_message.to_owned(),
+            structured: String::from_utf8_lossy(&line)
+                .split_once(' ')
+                .and_then(|(_, message)| model::StructuredLogLine::parse(message)),
+        };
+
+        self.insert_into_buffer(&log_line, at_end);
+
+        let mut timestamps = imp.log_timestamps.borrow_mut();
+        let mut log_lines = imp.log_lines.borrow_mut();
+        if at_end {
+            timestamps.push_back(timestamp.to_owned());
+            log_lines.push_back(log_line);
+        } else {
+            timestamps.push_front(timestamp.to_owned());
+            log_lines.push_front(log_line);
+        }
+    }
+
+    fn insert_into_buffer(&self, log_line: &LogLine, at_end: bool) {
+        let imp = self.imp();
+
+        let message = if self.structured() {
+            log_line
+                .structured
+                .as_ref()
+                .map(structured_markup)
+                .map(Cow::Owned)
+                .unwrap_or(Cow::Borrowed(log_line.markup.as_str()))
+        } else {
+            Cow::Borrowed(log_line.markup.as_str())
+        };
 
         let source_buffer = &*imp.source_buffer;
+        let mut iter = if at_end {
+            source_buffer.end_iter()
+        } else {
+            source_buffer.start_iter()
+        };
+        let start = iter.offset();
+
         source_buffer.insert_markup(
-            &mut if at_end {
-                imp.source_buffer.end_iter()
-            } else {
-                imp.source_buffer.start_iter()
-            },
+            &mut iter,
             &if source_buffer.start_iter() == source_buffer.end_iter() {
-                Cow::Borrowed(log_message)
+                message
             } else if at_end {
-                Cow::Owned(format!("\n{log_message}"))
+                Cow::Owned(format!("\n{message}"))
             } else {
-                Cow::Owned(format!("{log_message}\n"))
+                Cow::Owned(format!("{message}\n"))
             },
         );
 
-        let mut timestamps = imp.log_timestamps.borrow_mut();
-        if at_end {
-            timestamps.push_back(timestamp.to_owned());
+        let start_iter = source_buffer.iter_at_offset(start);
+
+        // Hidden lines keep their place in the buffer so that the timestamps still match.
+        if !imp.filter.borrow().matches(log_line.structured.as_ref()) {
+            source_buffer.apply_tag(imp.hidden_tag.get().unwrap(), &start_iter, &iter);
+        }
+        self.hide_dangling_line_breaks();
+
+        let highlights = imp.highlights.borrow();
+        if !highlights.is_empty() {
+            let text = source_buffer.text(&start_iter, &iter, true);
+            highlights.iter().for_each(|(regex, tag)| {
+                model::LogHighlightRule::matches(regex, &text)
+                    .into_iter()
+                    .for_each(|(from, to)| {
+                        source_buffer.apply_tag(
+                            tag,
+                            &source_buffer.iter_at_offset(start + from),
+                            &source_buffer.iter_at_offset(start + to),
+                        );
+                    });
+            });
+        }
+    }
+
+    /// Hides the line break between the hidden lines at the start or the end of the buffer and
+    /// the visible lines. Otherwise, an empty line would show up there.
+    fn hide_dangling_line_breaks(&self) {
+        let imp = self.imp();
+
+        let source_buffer = &*imp.source_buffer;
+        let hidden_tag = imp.hidden_tag.get().unwrap();
+
+        let mut iter = source_buffer.start_iter();
+        if iter.has_tag(hidden_tag)
+            && iter.forward_to_tag_toggle(Some(hidden_tag))
+            && iter.char() == '\n'
+        {
+            source_buffer.apply_tag(
+                hidden_tag,
+                &iter,
+                &source_buffer.iter_at_offset(iter.offset() + 1),
+            );
+        }
+
+        let mut iter = source_buffer.end_iter();
+        if iter.backward_char()
+            && iter.has_tag(hidden_tag)
+            && iter.backward_to_tag_toggle(Some(hidden_tag))
+            && iter.backward_char()
+            && iter.char() == '\n'
+        {
+            source_buffer.apply_tag(
+                hidden_tag,
+                &iter,
+                &source_buffer.iter_at_offset(iter.offset() + 1),
+            );
+        }
+    }
+
+    /// Drops the oldest line together with its timestamp.
+    fn remove_first_line(&self) {
+        let imp = self.imp();
+
+        let source_buffer = &*imp.source_buffer;
+        let mut start = source_buffer.start_iter();
+        let mut end = source_buffer
+            .iter_at_line(1)
+            .unwrap_or_else(|| source_buffer.end_iter());
+
+        source_buffer.remove_source_marks(&start, &end, None);
+        source_buffer.delete(&mut start, &mut end);
+        self.hide_dangling_line_breaks();
+
+        imp.log_timestamps.borrow_mut().pop_front();
+        imp.log_lines.borrow_mut().pop_front();
+    }
+
+    /// Renders all lines again, e.g. after the mode or the filter has changed.
+    fn rebuild_buffer(&self) {
+        let imp = self.imp();
+
+        let bookmarks = imp.search_widget.bookmarked_lines();
+
+        imp.source_buffer.set_text("");
+        imp.log_lines
+            .borrow()
+            .iter()
+            .for_each(|log_line| self.insert_into_buffer(log_line, true));
+
+        imp.search_widget.set_bookmarked_lines(&bookmarks);
+    }
+
+    /// Creates a tag for every highlight rule and applies them to all lines.
+    fn update_highlights(&self) {
+        let imp = self.imp();
+
+        let tag_table = imp.source_buffer.tag_table();
+        imp.highlights
+            .take()
+            .into_iter()
+            .for_each(|(_, tag)| tag_table.remove(&tag));
+
+        let highlights = model::LogHighlightRule::load(&imp.settings)
+            .into_iter()
+            .filter_map(|rule| {
+                let regex = rule
+                    .regex()
+                    .map_err(|e| log::warn!("Ignoring highlight rule: {e}"))
+                    .ok()?;
+                let mut rgba = gdk::RGBA::parse(&rule.color)
+                    .map_err(|e| log::warn!("Ignoring highlight rule: {e}"))
+                    .ok()?;
+                rgba.set_alpha(0.4);
+
+                let tag = gtk::TextTag::builder().background_rgba(&rgba).build();
+                tag_table.add(&tag);
+
+                Some((regex, tag))
+            })
+            .collect();
+
+        imp.highlights.replace(highlights);
+        self.rebuild_buffer();
+    }
+
+    fn edit_highlight_rules(&self) {
+        utils::navigation_view(self.upcast_ref()).push(
+            &adw::NavigationPage::builder()
+                .child(&view::LogHighlightRulesPage::default())
+                .build(),
+        );
+    }
+
+    fn logs_opts_builder(
+        &self,
+        follow: bool,
+        timestamps: bool,
+        time_range: &model::LogTimeRange,
+    ) -> podman::opts::ContainerLogsOptsBuilder {
+        let stream = self.stream();
+
+        let opts = basic_opts_builder(follow, timestamps)
+            .stdout(stream != "stderr")
+            .stderr(stream != "stdout");
+        let opts = match time_range.since_unix() {
+            Some(since) => opts.since(since),
+            None => opts,
+        };
+        match time_range.until_unix() {
+            Some(until) => opts.until(until),
+            None => opts,
+        }
+    }
+
+    /// Returns whether a stream that was started for the given generation is still wanted.
+    fn is_current_stream(&self, generation: u32) -> bool {
+        self.imp().stream_generation.get() == generation
+    }
+
+    /// Discards all lines and loads the log again, e.g. after the time range has changed.
+    fn reload_log(&self) {
+        let imp = self.imp();
+
+        imp.stream_generation
+            .set(imp.stream_generation.get().wrapping_add(1));
+
+        imp.fetch_until.replace(None);
+        imp.fetch_lines_state.set(FetchLinesState::Waiting);
+        imp.fetched_lines.borrow_mut().clear();
+        imp.log_timestamps.borrow_mut().clear();
+        imp.log_lines.borrow_mut().clear();
+        imp.lines_loading_revealer.set_reveal_child(false);
+        imp.search_widget.set_bookmarked_lines(&[]);
+        imp.source_buffer.set_text("");
+
+        self.init_log();
+    }
+
+    pub(crate) fn apply_time_range(&self) {
+        let imp = self.imp();
+
+        match model::LogTimeRange::parse(&imp.since_entry_row.text(), &imp.until_entry_row.text()) {
+            Ok(time_range) => {
+                imp.time_range_button.popdown();
+                self.set_time_range(time_range);
+            }
+            Err(e) => utils::show_error_toast(
+                self.upcast_ref(),
+                &gettext("Invalid time range"),
+                &e.to_string(),
+            ),
+        }
+    }
+
+    pub(crate) fn reset_time_range(&self) {
+        let imp = self.imp();
+
+        imp.since_entry_row.set_text("");
+        imp.until_entry_row.set_text("");
+        imp.time_range_button.popdown();
+
+        self.set_time_range(model::LogTimeRange::default());
+    }
+
+    fn set_time_range(&self, time_range: model::LogTimeRange) {
+        let imp = self.imp();
+
+        if *imp.time_range.borrow() == time_range {
+            return;
+        }
+
+        if time_range.is_empty() {
+            imp.time_range_button.remove_css_class("accent");
         } else {
-            timestamps.push_front(timestamp.to_owned());
+            imp.time_range_button.add_css_class("accent");
         }
+
+        imp.time_range.replace(time_range);
+        self.reload_log();
     }
 
     fn load_previous_messages(&self, adj: &gtk::Adjustment) {
@@ -526,20 +973,25 @@ impl ContainerLogPage {
 
         match imp.fetch_lines_state.get() {
             FetchLinesState::Waiting => {
-                if let Some(until) = imp.fetch_until.get().map(ToOwned::to_owned) {
+                if let Some(until) = imp.fetch_until.borrow().clone() {
                     if let Some(container) =
                         self.container().as_ref().and_then(model::Container::api)
                     {
                         imp.lines_loading_revealer.set_reveal_child(true);
 
+                        let generation = imp.stream_generation.get();
+                        let opts = self
+                            .logs_opts_builder(false, true, &imp.time_range.borrow())
+                            .until(until);
+
                         utils::run_stream_with_finish_handler(
                             container,
-                            move |container| {
-                                container
-                                    .logs(&basic_opts_builder(false, true).until(until).build())
-                                    .boxed()
-                            },
+                            move |container| container.logs(&opts.build()).boxed(),
                             clone!(@weak self as obj => @default-return glib::ControlFlow::Break, move |result| {
+                                if !obj.is_current_stream(generation) {
+                                    return glib::ControlFlow::Break;
+                                }
+
                                 let imp = obj.imp();
                                 imp.fetch_lines_state.set(FetchLinesState::Fetching);
 
@@ -555,6 +1007,10 @@ impl ContainerLogPage {
                                 }
                             }),
                             clone!(@weak self as obj => move || {
+                                if !obj.is_current_stream(generation) {
+                                    return;
+                                }
+
                                 let imp = obj.imp();
                                 imp.lines_loading_revealer.set_reveal_child(false);
                                 imp.fetch_lines_state.set(FetchLinesState::Finished);
@@ -578,6 +1034,19 @@ impl ContainerLogPage {
 
         let had_lines = !lines.is_empty();
 
+        if had_lines && imp.log_lines.borrow().len() >= MAX_LINES {
+            lines.clear();
+            utils::show_toast(
+                self.upcast_ref(),
+                // Translators: The placeholder is the maximum number of lines.
+                gettext!(
+                    "Older lines are not shown, the log is limited to {} lines",
+                    MAX_LINES
+                ),
+            );
+            return;
+        }
+
         for _ in 0..128 {
             match lines.pop_back() {
                 Some(line) => self.insert(line, &mut perform, false),
@@ -610,13 +1079,24 @@ impl ContainerLogPage {
             let request = SaveFileRequest::default()
                 .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
                 .current_name(format!("{}.log", container.name()).as_str())
-                .choice(Choice::boolean(
-                    "timestamps",
-                    &gettext("Include timestamps"),
-                    false,
-                ))
+                .choice(
+                    Choice::new("format", &gettext("Format"), "plain")
+                        .insert("plain", &gettext("Plain Text"))
+                        .insert("timestamps", &gettext("Plain Text With Timestamps"))
+                        .insert("json", &gettext("JSON Lines With Stream Tags")),
+                )
                 .modal(true);
 
+            let request = if self.imp().time_range.borrow().is_empty() {
+                request
+            } else {
+                request.choice(
+                    Choice::new("range", &gettext("Range"), "range")
+                        .insert("range", &gettext("Selected Time Range"))
+                        .insert("all", &gettext("Whole Log")),
+                )
+            };
+
             utils::show_save_file_dialog(
                 request,
                 self.upcast_ref(),
@@ -636,24 +1116,53 @@ impl ContainerLogPage {
                         let mut writer = BufWriter::new(file);
                         let mut perform = PlainTextPerform::default();
 
-                        let timestamps = files.choices()[0].1 == "true";
+                        let choice = |id: &str| {
+                            files
+                                .choices()
+                                .iter()
+                                .find(|(choice, _)| choice == id)
+                                .map(|(_, value)| value.to_owned())
+                        };
+
+                        let format = choice("format").unwrap_or_else(|| "plain".to_owned());
+                        let json = format == "json";
+
+                        let time_range = if choice("range").as_deref() == Some("all") {
+                            model::LogTimeRange::default()
+                        } else {
+                            obj.imp().time_range.borrow().clone()
+                        };
+                        let opts = obj.logs_opts_builder(false, format != "plain", &time_range);
 
                         utils::run_stream_with_finish_handler(
                             container.api().unwrap(),
-                            move |container| {
-                                container
-                                    .logs(&basic_opts_builder(false, timestamps).build())
-                                    .boxed()
-                            },
+                            move |container| container.logs(&opts.build()).boxed(),
                             clone!(
                                 @weak obj => @default-return glib::ControlFlow::Break,
                                 move |result: podman::Result<podman::conn::TtyChunk>|
                             {
-                                match result.map(Vec::from) {
-                                    Ok(line) => {
-                                        perform.decode(&line);
+                                match result {
+                                    Ok(chunk) => {
+                                        let stream = match chunk {
+                                            podman::conn::TtyChunk::StdErr(_) => "stderr",
+                                            _ => "stdout",
+                                        };
+                                        perform.decode(&Vec::from(chunk));
 
                                         let line = perform.move_out_buffer();
+                                        let line = if json && !line.is_empty() {
+                                            let (time, message) =
+                                                line.split_once(' ').unwrap_or(("", &line));
+                                            serde_json::json!({
+                                                "time": time,
+                                                "stream": stream,
+                                                "message": message,
+                                            })
+                                            .to_string()
+                                        } else {
+                                            line
+                                        };
+
                                         if !line.is_empty() {
                                             match writer
                                                 .write_all(line.as_bytes())
@@ -724,6 +1233,40 @@ impl ContainerLogPage {
     }
 }
 
+/// Renders the level, the time, the message and the remaining fields of a JSON log line.
+fn structured_markup(line: &model::StructuredLogLine) -> String {
+    let mut markup = String::new();
+
+    if let Some(timestamp) = line.timestamp() {
+        markup.push_str(&format!(
+            "<span foreground=\"#865e3c\">{}</span> ",
+            glib::markup_escape_text(&timestamp)
+        ));
+    }
+
+    if let Some(level) = line.level() {
+        markup.push_str(&format!(
+            "<span foreground=\"{}\"><b>{:<5}</b></span> ",
+            level.color(),
+            level.name()
+        ));
+    }
+
+    if let Some(message) = line.message() {
+        markup.push_str(&glib::markup_escape_text(&message));
+    }
+
+    line.other_fields().for_each(|(key, value)| {
+        markup.push_str(&format!(
+            " <span alpha=\"60%\">{}=</span>{}",
+            glib::markup_escape_text(key),
+            glib::markup_escape_text(&value)
+        ));
+    });
+
+    markup
+}
+
 fn basic_opts_builder(follow: bool, timestamps: bool) -> podman::opts::ContainerLogsOptsBuilder {
     podman::opts::ContainerLogsOpts::builder()
         .follow(follow)
@@ -802,7 +1345,7 @@ impl MarkupPerform {
     }
 
     /// Decode the specified bytes. Return true if finished.
-    fn decode(&mut self, ansi_encoded_bytes: &[u8]) -> String {
+    pub(crate) fn decode(&mut self, ansi_encoded_bytes: &[u8]) -> String {
         let mut parser = vte::Parser::new();
 
         self.begin_line();
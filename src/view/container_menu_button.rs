@@ -17,6 +17,8 @@ const ACTION_RESTART: &str = "container-menu-button.restart";
 const ACTION_PAUSE: &str = "container-menu-button.pause";
 const ACTION_RESUME: &str = "container-menu-button.resume";
 const ACTION_RENAME: &str = "container-menu-button.rename";
+const ACTION_EXPORT: &str = "container-menu-button.export";
+const ACTION_CHECKPOINT: &str = "container-menu-button.checkpoint";
 const ACTION_DELETE: &str = "container-menu-button.delete";
 
 mod imp {
@@ -63,6 +65,12 @@ mod imp {
             klass.install_action(ACTION_RENAME, None, |widget, _, _| {
                 view::container::rename(widget.upcast_ref(), widget.container().as_ref());
             });
+            klass.install_action(ACTION_EXPORT, None, |widget, _, _| {
+                view::container::export(widget.upcast_ref(), widget.container().as_ref());
+            });
+            klass.install_action(ACTION_CHECKPOINT, None, |widget, _, _| {
+                view::container::checkpoint(widget.upcast_ref(), widget.container().as_ref());
+            });
 
             klass.install_action(ACTION_DELETE, None, |widget, _, _| {
                 view::container::delete(widget.upcast_ref());
@@ -110,6 +118,13 @@ mod imp {
             container_expr
                 .chain_property::<model::Container>("status")
                 .watch(Some(obj), clone!(@weak obj => move || obj.update_actions()));
+
+            // Whether the client is rootless is only known after its info has been retrieved.
+            container_expr
+                .chain_property::<model::Container>("container-list")
+                .chain_property::<model::ContainerList>("client")
+                .chain_property::<model::Client>("rootless")
+                .watch(Some(obj), clone!(@weak obj => move || obj.update_actions()));
         }
 
         fn dispose(&self) {
@@ -137,6 +152,7 @@ impl ContainerMenuButton {
             self.action_set_enabled(ACTION_RESTART, container.can_restart());
             self.action_set_enabled(ACTION_RESUME, container.can_resume());
             self.action_set_enabled(ACTION_PAUSE, container.can_pause());
+            self.action_set_enabled(ACTION_CHECKPOINT, container.can_checkpoint());
             self.action_set_enabled(ACTION_DELETE, container.can_delete());
         }
     }
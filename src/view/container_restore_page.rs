@@ -0,0 +1,186 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::WindowIdentifier;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_FILE: &str = "container-restore-page.select-file";
+const ACTION_RESTORE: &str = "container-restore-page.restore";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerRestorePage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_restore_page.ui")]
+    pub(crate) struct ContainerRestorePage {
+        pub(super) path: RefCell<Option<PathBuf>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) restore_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) file_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) name_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) volumes_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) tcp_switch_row: TemplateChild<adw::SwitchRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerRestorePage {
+        const NAME: &'static str = "PdsContainerRestorePage";
+        type Type = super::ContainerRestorePage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action_async(ACTION_SELECT_FILE, None, |widget, _, _| async move {
+                widget.select_file().await;
+            });
+            klass.install_action(ACTION_RESTORE, None, |widget, _, _| {
+                widget.restore();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerRestorePage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.update_restore_action();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for ContainerRestorePage {
+        fn root(&self) {
+            self.parent_root();
+            utils::root(self.obj().upcast_ref()).set_default_widget(Some(&*self.restore_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+
+    #[gtk::template_callbacks]
+    impl ContainerRestorePage {
+        #[template_callback]
+        fn update_restore_action(&self) {
+            self.obj().action_set_enabled(
+                ACTION_RESTORE,
+                self.path.borrow().is_some() && !self.name_entry_row.text().trim().is_empty(),
+            );
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerRestorePage(ObjectSubclass<imp::ContainerRestorePage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Client> for ContainerRestorePage {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl ContainerRestorePage {
+    async fn select_file(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Checkpoint Archive").as_str())
+            .filter(
+                FileFilter::new(&gettext("Compressed Tar Archives"))
+                    .mimetype("application/x-compressed-tar")
+                    .glob("*.tar.gz"),
+            )
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    let imp = obj.imp();
+
+                    imp.file_row.set_subtitle(&path.to_string_lossy());
+                    imp.path.replace(Some(path));
+                    imp.update_restore_action();
+                }
+            }),
+        )
+        .await;
+    }
+
+    fn restore(&self) {
+        let imp = self.imp();
+
+        if let Some((path, client)) = imp.path.borrow().clone().zip(self.client()) {
+            let name = imp.name_entry_row.text().trim().to_owned();
+
+            // The original container may still exist, so its static addresses must not be reused.
+            let opts = podman::opts::ContainerRestoreOpts::builder()
+                .name(name.as_str())
+                .ignore_static_ip(true)
+                .ignore_static_mac(true)
+                .ignore_volumes(!imp.volumes_switch_row.is_active())
+                .tcp_established(imp.tcp_switch_row.is_active())
+                .build();
+
+            let page =
+                view::ActionPage::from(&client.action_list().restore_container(&name, path, opts));
+
+            imp.navigation_view.push(
+                &adw::NavigationPage::builder()
+                    .can_pop(false)
+                    .child(&page)
+                    .build(),
+            );
+        }
+    }
+}
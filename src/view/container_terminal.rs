@@ -46,6 +46,7 @@ mod imp {
         pub(super) settings: utils::PodsSettings,
         pub(super) tx_input: RefCell<Option<tokio::sync::mpsc::UnboundedSender<ExecInput>>>,
         pub(super) keep_alive_on_next_unroot: Cell<bool>,
+        pub(super) exec_config: RefCell<model::ExecConfig>,
         #[property(get, set, nullable)]
         pub(super) container: glib::WeakRef<model::Container>,
         #[template_child]
@@ -102,7 +103,15 @@ mod imp {
     impl ObjectImpl for ContainerTerminal {
         fn signals() -> &'static [Signal] {
             static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
-            SIGNALS.get_or_init(|| vec![Signal::builder("terminated").build()])
+            SIGNALS.get_or_init(|| {
+                vec![
+                    Signal::builder("started").build(),
+                    Signal::builder("failed")
+                        .param_types([String::static_type()])
+                        .build(),
+                    Signal::builder("terminated").build(),
+                ]
+            })
         }
 
         fn properties() -> &'static [glib::ParamSpec] {
@@ -376,9 +385,23 @@ impl ContainerTerminal {
         self.imp().terminal.set_font_scale(value);
     }
 
+    /// Sets the options of the session. They are used the next time the terminal connects.
+    pub(crate) fn set_exec_config(&self, exec_config: model::ExecConfig) {
+        self.imp().exec_config.replace(exec_config);
+    }
+
     fn setup_tty_connection(&self, container: &model::Container) {
         let imp = self.imp();
 
+        let exec_config = imp.exec_config.borrow().clone();
+        let opts = match exec_config.create_opts() {
+            Ok(opts) => opts,
+            Err(e) => {
+                self.emit_by_name::<()>("failed", &[&e.to_string()]);
+                return;
+            }
+        };
+
         let container = container.api().unwrap();
 
         let (tx_output, mut rx_output) = tokio::sync::mpsc::channel::<Vec<u8>>(5);
@@ -395,6 +418,14 @@ impl ContainerTerminal {
             _ = tx_input.send(ExecInput::Data(data.as_bytes().to_vec()));
         });
 
+        let (tx_started, rx_started) = tokio::sync::oneshot::channel::<()>();
+
+        glib::spawn_future_local(clone!(@weak self as obj => async move {
+            if rx_started.await.is_ok() {
+                obj.emit_by_name::<()>("started", &[]);
+            }
+        }));
+
         let width = imp.terminal.column_count();
         let height = imp.terminal.row_count();
 
@@ -402,20 +433,19 @@ impl ContainerTerminal {
 
         utils::do_async(
             async move {
-                let opts = podman::opts::ExecCreateOpts::builder()
-                    .attach_stderr(true)
-                    .attach_stdout(true)
-                    .attach_stdin(true)
-                    .tty(true)
-                    .command(["/bin/sh"])
-                    .build();
-                let exec = container.create_exec(&opts).await.unwrap();
+                let exec = container.create_exec(&opts).await?;
 
                 let opts = podman::opts::ExecStartOpts::builder().tty(true).build();
-                let (mut reader, mut writer) = exec.start(&opts).await.unwrap().unwrap().split();
+                let (mut reader, mut writer) = exec
+                    .start(&opts)
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!(gettext("The terminal could not be attached")))?
+                    .split();
 
                 exec.resize(width as usize, height as usize).await?;
 
+                _ = tx_started.send(());
+
                 loop {
                     match future::select(Box::pin(rx_input.recv()), reader.next()).await {
                         future::Either::Left((buf, _)) => match buf {
@@ -462,21 +492,12 @@ impl ContainerTerminal {
 
                 Ok(())
             },
-            clone!(@weak self as obj => move |result: podman::Result<_>| {
-                if result.is_err() {
-                    utils::show_error_toast(
-                        gio::Application::default()
-                            .unwrap()
-                            .downcast::<crate::Application>()
-                            .unwrap()
-                            .main_window()
-                            .toast_overlay()
-                            .upcast_ref(),
-                        &gettext("Terminal error"),
-                        &gettext("'/bin/sh' not found"),
-                    );
-                }
-                obj.emit_by_name::<()>("terminated", &[]);
+            clone!(@weak self as obj => move |result: anyhow::Result<_>| match result {
+                Ok(()) => obj.emit_by_name::<()>("terminated", &[]),
+                Err(e) => obj.emit_by_name::<()>(
+                    "failed",
+                    &[&gettext!("'{}' could not be started: {}", exec_config.command, e)],
+                ),
             }),
         );
     }
@@ -1,7 +1,9 @@
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use gettextrs::gettext;
 use glib::Properties;
 use gtk::gdk;
+use gtk::gio;
 use gtk::glib;
 use gtk::CompositeTemplate;
 
@@ -24,6 +26,9 @@ mod imp {
     pub(crate) struct ContainerTerminalPage {
         #[property(get, set, construct, nullable)]
         pub(super) container: glib::WeakRef<model::Container>,
+        /// The page the terminal has been launched from. It is closed when the terminal has been
+        /// started.
+        pub(super) launcher: glib::WeakRef<view::ContainerExecPage>,
         #[template_child]
         pub(super) zoom_control: TemplateChild<widget::ZoomControl>,
         #[template_child]
@@ -130,6 +135,29 @@ mod imp {
 
     #[gtk::template_callbacks]
     impl ContainerTerminalPage {
+        #[template_callback]
+        fn on_terminal_started(&self) {
+            if let Some(launcher) = self.launcher.upgrade() {
+                self.launcher.set(None);
+                launcher.finish_launch();
+            }
+        }
+
+        #[template_callback]
+        fn on_terminal_failed(&self, message: String) {
+            if self.obj().root().is_some() {
+                self.on_terminal_terminated();
+            }
+
+            match self.launcher.upgrade() {
+                Some(launcher) => {
+                    self.launcher.set(None);
+                    launcher.fail_launch(&message);
+                }
+                None => show_terminal_error(&message),
+            }
+        }
+
         #[template_callback]
         fn on_terminal_terminated(&self) {
             let obj = &*self.obj();
@@ -150,13 +178,20 @@ glib::wrapper! {
         @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
 }
 
-impl From<&model::Container> for ContainerTerminalPage {
-    fn from(image: &model::Container) -> Self {
-        glib::Object::builder().property("container", image).build()
+impl ContainerTerminalPage {
+    pub(crate) fn new(container: &model::Container, launcher: &view::ContainerExecPage) -> Self {
+        let obj: Self = glib::Object::builder().build();
+        let imp = obj.imp();
+
+        imp.launcher.set(Some(launcher));
+
+        // The terminal connects as soon as it has a container, so the options must be set first.
+        imp.terminal.set_exec_config(launcher.exec_config());
+        obj.set_container(Some(container));
+
+        obj
     }
-}
 
-impl ContainerTerminalPage {
     pub(crate) fn pip_out(&self) {
         if let Some(navigation_view) = utils::try_navigation_view(self.upcast_ref()) {
             self.imp().terminal.keep_alive_on_next_unroot();
@@ -184,3 +219,18 @@ impl ContainerTerminalPage {
         }
     }
 }
+
+/// Shows the error in the main window because the terminal page has been closed.
+fn show_terminal_error(message: &str) {
+    utils::show_error_toast(
+        gio::Application::default()
+            .unwrap()
+            .downcast::<crate::Application>()
+            .unwrap()
+            .main_window()
+            .toast_overlay()
+            .upcast_ref(),
+        &gettext("Terminal error"),
+        message,
+    );
+}
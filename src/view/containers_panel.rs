@@ -21,6 +21,7 @@ use crate::utils;
 use crate::view;
 
 const ACTION_CREATE_CONTAINER: &str = "containers-panel.create-container";
+const ACTION_RESTORE_CONTAINER: &str = "containers-panel.restore-container";
 const ACTION_PRUNE_UNUSED_CONTAINERS: &str = "containers-panel.prune-unused-containers";
 const ACTION_ENTER_SELECTION_MODE: &str = "containers-panel.enter-selection-mode";
 const ACTION_EXIT_SELECTION_MODE: &str = "containers-panel.exit-selection-mode";
@@ -32,6 +33,7 @@ const ACTION_START_OR_RESUME_SELECTION: &str = "containers-panel.start-or-resume
 const ACTION_STOP_SELECTION: &str = "containers-panel.stop-selection";
 const ACTION_PAUSE_SELECTION: &str = "containers-panel.pause-selection";
 const ACTION_DELETE_SELECTION: &str = "containers-panel.delete-selection";
+const ACTION_SHOW_SELECTION_LOGS: &str = "containers-panel.show-selection-logs";
 const ACTION_TOGGLE_SHOW_ONLY_RUNNING_CONTAINERS: &str =
     "containers-panel.toggle-show-only-running-containers";
 const ACTION_SHOW_ALL_CONTAINERS: &str = "containers-panel.show-all-containers";
@@ -43,6 +45,7 @@ const ACTIONS_SELECTION: &[&str] = &[
     ACTION_STOP_SELECTION,
     ACTION_PAUSE_SELECTION,
     ACTION_DELETE_SELECTION,
+    ACTION_SHOW_SELECTION_LOGS,
 ];
 
 mod imp {
@@ -96,6 +99,9 @@ mod imp {
             klass.install_action(ACTION_CREATE_CONTAINER, None, move |widget, _, _| {
                 widget.create_container();
             });
+            klass.install_action(ACTION_RESTORE_CONTAINER, None, |widget, _, _| {
+                widget.show_restore_page();
+            });
 
             klass.install_action(ACTION_PRUNE_UNUSED_CONTAINERS, None, |widget, _, _| {
                 widget.show_prune_page();
@@ -137,6 +143,9 @@ mod imp {
             klass.install_action(ACTION_DELETE_SELECTION, None, |widget, _, _| {
                 widget.delete_selection();
             });
+            klass.install_action(ACTION_SHOW_SELECTION_LOGS, None, |widget, _, _| {
+                widget.show_selection_logs();
+            });
 
             klass.install_property_action(
                 ACTION_TOGGLE_SHOW_ONLY_RUNNING_CONTAINERS,
@@ -489,6 +498,16 @@ impl ContainersPanel {
         }
     }
 
+    pub(crate) fn show_restore_page(&self) {
+        if let Some(client) = self.client() {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::ContainerRestorePage::from(&client).upcast_ref(),
+            )
+            .present();
+        }
+    }
+
     pub(crate) fn show_prune_page(&self) {
         if let Some(client) = self.client() {
             utils::Dialog::new(
@@ -698,6 +717,37 @@ impl ContainersPanel {
         dialog.present(Some(self));
     }
 
+    pub(crate) fn show_selection_logs(&self) {
+        if let Some(list) = self.container_list() {
+            let containers = gio::ListStore::new::<model::Container>();
+            containers.extend(
+                list.selected_items()
+                    .iter()
+                    .map(|obj| obj.downcast_ref::<model::Container>().unwrap())
+                    .cloned(),
+            );
+            list.set_selection_mode(false);
+
+            if containers.n_items() == 0 {
+                return;
+            }
+
+            utils::navigation_view(self.upcast_ref()).push(
+                &adw::NavigationPage::builder()
+                    .child(&view::MultiContainerLogPage::new(
+                        containers.upcast_ref(),
+                        &ngettext!(
+                            "{} Container",
+                            "{} Containers",
+                            containers.n_items(),
+                            containers.n_items()
+                        ),
+                    ))
+                    .build(),
+            );
+        }
+    }
+
     fn deselect_hidden_containers(&self, model: &gio::ListModel) {
         let visible_containers = model
             .iter::<glib::Object>()
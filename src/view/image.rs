@@ -57,6 +57,21 @@ pub(crate) fn delete_image(widget: &gtk::Widget, image: &model::Image) {
     }));
 }
 
+pub(crate) fn save(widget: &gtk::Widget, image: Option<model::Image>) {
+    if let Some(image) = image {
+        if let Some(client) = image
+            .image_list()
+            .and_then(|image_list| image_list.client())
+        {
+            utils::Dialog::new(
+                widget,
+                view::ImagesSavePage::new(&client, &[image]).upcast_ref(),
+            )
+            .present();
+        }
+    }
+}
+
 pub(crate) fn create_container(widget: &gtk::Widget, image: Option<model::Image>) {
     if let Some(image) = image {
         utils::Dialog::new(
@@ -19,6 +19,7 @@ const ACTION_TAG: &str = "image-details-page.tag";
 const ACTION_INSPECT_IMAGE: &str = "image-details-page.inspect-image";
 const ACTION_SHOW_HISTORY: &str = "image-details-page.show-history";
 const ACTION_DELETE_IMAGE: &str = "image-details-page.delete-image";
+const ACTION_COPY_LINK: &str = "image-details-page.copy-link";
 
 mod imp {
     use super::*;
@@ -61,6 +62,10 @@ mod imp {
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
 
+            klass.install_action(ACTION_COPY_LINK, None, |widget, _, _| {
+                widget.copy_link();
+            });
+
             klass.install_action(ACTION_TAG, None, |widget, _, _| {
                 widget.tag();
             });
@@ -312,6 +317,26 @@ impl From<&model::Image> for ImageDetailsPage {
 }
 
 impl ImageDetailsPage {
+    fn copy_link(&self) {
+        if let Some((image, client)) = self.image().and_then(|image| {
+            image
+                .image_list()
+                .and_then(|list| list.client())
+                .map(|client| (image, client))
+        }) {
+            utils::copy_to_clipboard(
+                self.upcast_ref(),
+                &model::DeepLink::new(
+                    &client.connection().name(),
+                    model::DeepLinkKind::Image,
+                    &image.id(),
+                )
+                .to_string(),
+                &gettext("Link copied"),
+            );
+        }
+    }
+
     fn tag(&self) {
         self.exec_action(|| {
             if let Some(image) = self.image() {
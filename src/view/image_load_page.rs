@@ -0,0 +1,186 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::WindowIdentifier;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_FILE: &str = "image-load-page.select-file";
+const ACTION_LOAD: &str = "image-load-page.load";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ImageLoadPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/image_load_page.ui")]
+    pub(crate) struct ImageLoadPage {
+        pub(super) path: RefCell<Option<PathBuf>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) load_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) file_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) kind_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) reference_entry_row: TemplateChild<adw::EntryRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageLoadPage {
+        const NAME: &'static str = "PdsImageLoadPage";
+        type Type = super::ImageLoadPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action_async(ACTION_SELECT_FILE, None, |widget, _, _| async move {
+                widget.select_file().await;
+            });
+            klass.install_action(ACTION_LOAD, None, |widget, _, _| {
+                widget.load();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImageLoadPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().action_set_enabled(ACTION_LOAD, false);
+            self.on_kind_combo_row_notify_selected();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for ImageLoadPage {
+        fn root(&self) {
+            self.parent_root();
+            utils::root(self.obj().upcast_ref()).set_default_widget(Some(&*self.load_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+
+    #[gtk::template_callbacks]
+    impl ImageLoadPage {
+        #[template_callback]
+        fn on_kind_combo_row_notify_selected(&self) {
+            self.reference_entry_row
+                .set_visible(self.obj().is_filesystem_archive());
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageLoadPage(ObjectSubclass<imp::ImageLoadPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Client> for ImageLoadPage {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl ImageLoadPage {
+    /// Whether the archive is a container filesystem to be imported as a new image rather than a
+    /// set of saved images.
+    fn is_filesystem_archive(&self) -> bool {
+        self.imp().kind_combo_row.selected() == 1
+    }
+
+    async fn select_file(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Archive").as_str())
+            .filter(
+                FileFilter::new(&gettext("Tar Archives"))
+                    .mimetype("application/x-tar")
+                    .mimetype("application/x-compressed-tar")
+                    .glob("*.tar")
+                    .glob("*.tar.gz"),
+            )
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    let imp = obj.imp();
+
+                    imp.file_row.set_subtitle(&path.to_string_lossy());
+                    imp.path.replace(Some(path));
+                    obj.action_set_enabled(ACTION_LOAD, true);
+                }
+            }),
+        )
+        .await;
+    }
+
+    fn load(&self) {
+        let imp = self.imp();
+
+        if let Some((path, client)) = imp.path.borrow().clone().zip(self.client()) {
+            let action = if self.is_filesystem_archive() {
+                let reference = imp.reference_entry_row.text().trim().to_owned();
+
+                client
+                    .action_list()
+                    .import_image(path, Some(reference).filter(|r| !r.is_empty()))
+            } else {
+                client.action_list().load_images(path)
+            };
+
+            imp.navigation_view.push(
+                &adw::NavigationPage::builder()
+                    .can_pop(false)
+                    .child(&view::ActionPage::from(&action))
+                    .build(),
+            );
+        }
+    }
+}
@@ -11,6 +11,7 @@ use crate::utils;
 use crate::view;
 
 const ACTION_CREATE_CONTAINER: &str = "image-menu-button.create-container";
+const ACTION_SAVE_IMAGE: &str = "image-menu-button.save-image";
 const ACTION_DELETE_IMAGE: &str = "image-menu-button.delete-image";
 
 mod imp {
@@ -38,6 +39,9 @@ mod imp {
             klass.install_action(ACTION_CREATE_CONTAINER, None, |widget, _, _| {
                 widget.create_container();
             });
+            klass.install_action(ACTION_SAVE_IMAGE, None, |widget, _, _| {
+                widget.save_image();
+            });
             klass.install_action(ACTION_DELETE_IMAGE, None, |widget, _, _| {
                 widget.delete_image();
             });
@@ -107,6 +111,10 @@ glib::wrapper! {
 }
 
 impl ImageMenuButton {
+    pub(crate) fn save_image(&self) {
+        view::image::save(self.upcast_ref(), self.image());
+    }
+
     pub(crate) fn delete_image(&self) {
         view::image::delete_image_show_confirmation(self.upcast_ref(), self.image());
     }
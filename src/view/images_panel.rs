@@ -21,11 +21,13 @@ use crate::view;
 
 const ACTION_PULL_IMAGE: &str = "images-panel.pull-image";
 const ACTION_BUILD_IMAGE: &str = "images-panel.build-image";
+const ACTION_LOAD_IMAGES: &str = "images-panel.load-images";
 const ACTION_PRUNE_UNUSED_IMAGES: &str = "images-panel.prune-unused-images";
 const ACTION_ENTER_SELECTION_MODE: &str = "images-panel.enter-selection-mode";
 const ACTION_EXIT_SELECTION_MODE: &str = "images-panel.exit-selection-mode";
 const ACTION_SELECT_VISIBLE: &str = "images-panel.select-visible";
 const ACTION_SELECT_NONE: &str = "images-panel.select-none";
+const ACTION_SAVE_SELECTION: &str = "images-panel.save-selection";
 const ACTION_DELETE_SELECTION: &str = "images-panel.delete-selection";
 const ACTION_TOGGLE_HIDE_INTERMEDIATE_IMAGES: &str = "images-panel.toggle-hide-intermediate-images";
 const ACTION_SHOW_ALL_IMAGES: &str = "images-panel.show-all-images";
@@ -86,6 +88,9 @@ mod imp {
             klass.install_action(ACTION_BUILD_IMAGE, None, |widget, _, _| {
                 widget.show_build_page();
             });
+            klass.install_action(ACTION_LOAD_IMAGES, None, |widget, _, _| {
+                widget.show_load_page();
+            });
 
             klass.install_action(ACTION_PRUNE_UNUSED_IMAGES, None, |widget, _, _| {
                 widget.show_prune_page();
@@ -105,6 +110,9 @@ mod imp {
                 widget.select_none();
             });
 
+            klass.install_action(ACTION_SAVE_SELECTION, None, |widget, _, _| {
+                widget.save_selection();
+            });
             klass.install_action(ACTION_DELETE_SELECTION, None, |widget, _, _| {
                 widget.delete_selection();
             });
@@ -425,6 +433,16 @@ impl ImagesPanel {
         }
     }
 
+    pub(crate) fn show_load_page(&self) {
+        if let Some(client) = self.client() {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::ImageLoadPage::from(&client).upcast_ref(),
+            )
+            .present();
+        }
+    }
+
     pub(crate) fn show_prune_page(&self) {
         if let Some(client) = self.client() {
             utils::Dialog::new(
@@ -469,6 +487,28 @@ impl ImagesPanel {
         }
     }
 
+    pub(crate) fn save_selection(&self) {
+        if let Some((list, client)) = self.image_list().zip(self.client()) {
+            let images = list
+                .selected_items()
+                .into_iter()
+                .map(|obj| obj.downcast::<model::Image>().unwrap())
+                .collect::<Vec<_>>();
+
+            if images.is_empty() {
+                return;
+            }
+
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::ImagesSavePage::new(&client, &images).upcast_ref(),
+            )
+            .present();
+
+            list.set_selection_mode(false);
+        }
+    }
+
     pub(crate) fn delete_selection(&self) {
         if self
             .image_list()
@@ -0,0 +1,192 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::SaveFileRequest;
+use ashpd::WindowIdentifier;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_PATH: &str = "images-save-page.select-path";
+const ACTION_SAVE: &str = "images-save-page.save";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ImagesSavePage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/images_save_page.ui")]
+    pub(crate) struct ImagesSavePage {
+        pub(super) references: RefCell<Vec<String>>,
+        pub(super) path: RefCell<Option<PathBuf>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) save_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) images_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) format_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) path_row: TemplateChild<adw::ActionRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImagesSavePage {
+        const NAME: &'static str = "PdsImagesSavePage";
+        type Type = super::ImagesSavePage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action_async(ACTION_SELECT_PATH, None, |widget, _, _| async move {
+                widget.select_path().await;
+            });
+            klass.install_action(ACTION_SAVE, None, |widget, _, _| {
+                widget.save();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImagesSavePage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().action_set_enabled(ACTION_SAVE, false);
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for ImagesSavePage {
+        fn root(&self) {
+            self.parent_root();
+            utils::root(self.obj().upcast_ref()).set_default_widget(Some(&*self.save_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImagesSavePage(ObjectSubclass<imp::ImagesSavePage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl ImagesSavePage {
+    pub(crate) fn new(client: &model::Client, images: &[model::Image]) -> Self {
+        let obj: Self = glib::Object::builder().property("client", client).build();
+        let imp = obj.imp();
+
+        // Tags are preferred over ids because an archive keeps the names of the images only if
+        // they are referenced by their names.
+        let references = images
+            .iter()
+            .map(|image| {
+                image
+                    .repo_tags()
+                    .get(0)
+                    .map(|repo_tag| repo_tag.full())
+                    .unwrap_or_else(|| image.id())
+            })
+            .collect::<Vec<_>>();
+
+        references.iter().for_each(|reference| {
+            imp.images_group.add(
+                &adw::ActionRow::builder()
+                    .title(utils::escape(reference))
+                    .build(),
+            );
+        });
+
+        imp.references.replace(references);
+
+        obj
+    }
+
+    fn format(&self) -> &'static str {
+        match self.imp().format_combo_row.selected() {
+            0 => "docker-archive",
+            _ => "oci-archive",
+        }
+    }
+
+    async fn select_path(&self) {
+        let request = SaveFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Destination Archive").as_str())
+            .current_name("images.tar")
+            .filter(FileFilter::new(&gettext("Tar Archive")).mimetype("application/x-tar"))
+            .modal(true);
+
+        utils::show_save_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    let imp = obj.imp();
+
+                    imp.path_row.set_subtitle(&path.to_string_lossy());
+                    imp.path.replace(Some(path));
+                    obj.action_set_enabled(ACTION_SAVE, true);
+                }
+            }),
+        )
+        .await;
+    }
+
+    fn save(&self) {
+        let imp = self.imp();
+
+        if let Some((path, client)) = imp.path.borrow().clone().zip(self.client()) {
+            let page = view::ActionPage::from(&client.action_list().save_images(
+                imp.references.borrow().clone(),
+                self.format(),
+                path,
+            ));
+
+            imp.navigation_view.push(
+                &adw::NavigationPage::builder()
+                    .can_pop(false)
+                    .child(&page)
+                    .build(),
+            );
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::WindowIdentifier;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_FILE: &str = "kube-play-page.select-file";
+const ACTION_PLAY: &str = "kube-play-page.play";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::KubePlayPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/kube_play_page.ui")]
+    pub(crate) struct KubePlayPage {
+        pub(super) yaml: RefCell<Option<(String, String)>>,
+        pub(super) preview_rows: RefCell<Vec<(adw::PreferencesGroup, gtk::Widget)>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) show_view_artifact: Cell<bool>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) play_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) file_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) start_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) replace_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) pods_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) volumes_group: TemplateChild<adw::PreferencesGroup>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for KubePlayPage {
+        const NAME: &'static str = "PdsKubePlayPage";
+        type Type = super::KubePlayPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action_async(ACTION_SELECT_FILE, None, |widget, _, _| async move {
+                widget.select_file().await;
+            });
+            klass.install_action(ACTION_PLAY, None, |widget, _, _| {
+                widget.play();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for KubePlayPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().action_set_enabled(ACTION_PLAY, false);
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for KubePlayPage {
+        fn root(&self) {
+            self.parent_root();
+            utils::root(self.obj().upcast_ref()).set_default_widget(Some(&*self.play_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct KubePlayPage(ObjectSubclass<imp::KubePlayPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Client> for KubePlayPage {
+    fn from(client: &model::Client) -> Self {
+        Self::new(client, true)
+    }
+}
+
+impl KubePlayPage {
+    pub(crate) fn new(client: &model::Client, show_view_artifact: bool) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("show-view-artifact", show_view_artifact)
+            .build()
+    }
+
+    async fn select_file(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Kubernetes YAML File").as_str())
+            .filter(
+                FileFilter::new(&gettext("YAML Files"))
+                    .mimetype("application/yaml")
+                    .mimetype("application/x-yaml")
+                    .glob("*.yaml")
+                    .glob("*.yml"),
+            )
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    utils::do_async(
+                        {
+                            let path = path.clone();
+                            async move { tokio::fs::read_to_string(path).await }
+                        },
+                        clone!(@weak obj => move |result| match result {
+                            Ok(yaml) => obj.set_yaml(&path, yaml),
+                            Err(e) => utils::show_error_toast(
+                                obj.upcast_ref(),
+                                &gettext("Error on reading file"),
+                                &e.to_string(),
+                            ),
+                        }),
+                    );
+                }
+            }),
+        )
+        .await;
+    }
+
+    fn set_yaml(&self, path: &std::path::Path, yaml: String) {
+        let imp = self.imp();
+
+        self.clear_preview();
+
+        match model::KubeManifest::parse(&yaml) {
+            Ok(manifest) => {
+                imp.file_row.set_subtitle(&path.display().to_string());
+
+                manifest.pods.iter().for_each(|pod| {
+                    let row = adw::ExpanderRow::builder()
+                        .title(&pod.name)
+                        // Translators: Example: {Deployment} with {2} containers
+                        .subtitle(ngettext!(
+                            "{} with {} container",
+                            "{} with {} containers",
+                            pod.containers.len() as u32,
+                            pod.kind,
+                            pod.containers.len()
+                        ))
+                        .build();
+
+                    pod.containers.iter().for_each(|container| {
+                        row.add_row(
+                            &adw::ActionRow::builder()
+                                .title(&container.name)
+                                .subtitle(&container.image)
+                                .build(),
+                        );
+                    });
+
+                    self.add_preview_row(&imp.pods_group, row.upcast_ref());
+                });
+
+                manifest.volumes.iter().for_each(|volume| {
+                    self.add_preview_row(
+                        &imp.volumes_group,
+                        adw::ActionRow::builder().title(volume).build().upcast_ref(),
+                    );
+                });
+
+                imp.pods_group.set_visible(!manifest.pods.is_empty());
+                imp.volumes_group.set_visible(!manifest.volumes.is_empty());
+
+                imp.yaml.replace(Some((
+                    path.file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_default(),
+                    yaml,
+                )));
+                self.action_set_enabled(ACTION_PLAY, true);
+            }
+            Err(e) => {
+                imp.yaml.replace(None);
+                self.action_set_enabled(ACTION_PLAY, false);
+
+                utils::show_error_toast(
+                    self.upcast_ref(),
+                    &gettext("Invalid Kubernetes YAML"),
+                    &e.to_string(),
+                );
+            }
+        }
+    }
+
+    fn add_preview_row(&self, group: &adw::PreferencesGroup, row: &gtk::Widget) {
+        group.add(row);
+        self.imp()
+            .preview_rows
+            .borrow_mut()
+            .push((group.to_owned(), row.to_owned()));
+    }
+
+    fn clear_preview(&self) {
+        let imp = self.imp();
+
+        imp.preview_rows
+            .take()
+            .into_iter()
+            .for_each(|(group, row)| group.remove(&row));
+
+        imp.pods_group.set_visible(false);
+        imp.volumes_group.set_visible(false);
+    }
+
+    fn play(&self) {
+        let imp = self.imp();
+
+        if let Some(((file_name, yaml), client)) = imp.yaml.borrow().clone().zip(self.client()) {
+            let opts = podman::opts::PlayKubernetesYamlOpts::builder()
+                .start(imp.start_switch_row.is_active())
+                .replace(imp.replace_switch_row.is_active())
+                .build();
+
+            let page = view::ActionPage::new(
+                &client
+                    .action_list()
+                    .play_kubernetes_yaml(&file_name, opts, yaml),
+                self.show_view_artifact(),
+            );
+
+            imp.navigation_view.push(
+                &adw::NavigationPage::builder()
+                    .can_pop(false)
+                    .child(&page)
+                    .build(),
+            );
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use gtk::gdk;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+
+const ACTION_ADD_RULE: &str = "log-highlight-rules-page.add-rule";
+const ACTION_REMOVE_RULE: &str = "log-highlight-rules-page.remove-rule";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/log_highlight_rules_page.ui")]
+    pub(crate) struct LogHighlightRulesPage {
+        pub(super) settings: utils::PodsSettings,
+        #[template_child]
+        pub(super) rules_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) pattern_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) color_dialog_button: TemplateChild<gtk::ColorDialogButton>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for LogHighlightRulesPage {
+        const NAME: &'static str = "PdsLogHighlightRulesPage";
+        type Type = super::LogHighlightRulesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_ADD_RULE, None, |widget, _, _| {
+                widget.add_rule();
+            });
+            klass.install_action(
+                ACTION_REMOVE_RULE,
+                Some(glib::VariantTy::UINT32),
+                |widget, _, data| {
+                    let index: u32 = data.unwrap().get().unwrap();
+                    widget.remove_rule(index as usize);
+                },
+            );
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for LogHighlightRulesPage {
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.color_dialog_button
+                .set_rgba(&gdk::RGBA::parse("#e5a50a").unwrap());
+
+            model::LogHighlightRule::connect_changed(
+                &self.settings,
+                clone!(@weak obj => move |_| {
+                    obj.update_rules();
+                }),
+            );
+            obj.update_rules();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for LogHighlightRulesPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct LogHighlightRulesPage(ObjectSubclass<imp::LogHighlightRulesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for LogHighlightRulesPage {
+    fn default() -> Self {
+        glib::Object::builder().build()
+    }
+}
+
+impl LogHighlightRulesPage {
+    fn update_rules(&self) {
+        let imp = self.imp();
+
+        imp.rules_list_box.remove_all();
+
+        model::LogHighlightRule::load(&imp.settings)
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, rule)| {
+                let row = adw::ActionRow::builder()
+                    .title(&rule.pattern)
+                    .use_markup(false)
+                    .build();
+
+                row.add_prefix(
+                    &gtk::Label::builder()
+                        .label(format!(
+                            "<span foreground=\"{}\">⬤</span>",
+                            glib::markup_escape_text(&rule.color)
+                        ))
+                        .use_markup(true)
+                        .build(),
+                );
+
+                let button = gtk::Button::builder()
+                    .action_name(ACTION_REMOVE_RULE)
+                    .action_target(&(index as u32).to_variant())
+                    .css_classes(["flat"])
+                    .icon_name("user-trash-symbolic")
+                    .tooltip_text(gettext("Remove Rule"))
+                    .valign(gtk::Align::Center)
+                    .build();
+                row.add_suffix(&button);
+
+                imp.rules_list_box.append(&row);
+            });
+    }
+
+    fn add_rule(&self) {
+        let imp = self.imp();
+
+        let rgba = imp.color_dialog_button.rgba();
+        let color = format!(
+            "#{:02x}{:02x}{:02x}",
+            (rgba.red() * 255.0).round() as u8,
+            (rgba.green() * 255.0).round() as u8,
+            (rgba.blue() * 255.0).round() as u8,
+        );
+
+        let result = model::LogHighlightRule::new(imp.pattern_entry_row.text().into(), color)
+            .and_then(|rule| {
+                let mut rules = model::LogHighlightRule::load(&imp.settings);
+                rules.push(rule);
+                model::LogHighlightRule::store(&imp.settings, &rules)
+            });
+
+        match result {
+            Ok(_) => imp.pattern_entry_row.set_text(""),
+            Err(e) => utils::show_error_toast(
+                self.upcast_ref(),
+                &gettext("Error on adding highlight rule"),
+                &e.to_string(),
+            ),
+        }
+    }
+
+    fn remove_rule(&self, index: usize) {
+        let imp = self.imp();
+
+        let mut rules = model::LogHighlightRule::load(&imp.settings);
+        if index < rules.len() {
+            rules.remove(index);
+
+            if let Err(e) = model::LogHighlightRule::store(&imp.settings, &rules) {
+                utils::show_error_toast(
+                    self.upcast_ref(),
+                    &gettext("Error on removing highlight rule"),
+                    &e.to_string(),
+                );
+            }
+        }
+    }
+}
@@ -2,18 +2,25 @@ mod action_page;
 mod action_row;
 mod actions_button;
 mod actions_sidebar;
+mod alert_rules_page;
 mod client_view;
+mod compose_up_page;
 mod connection;
 mod connection_chooser_page;
 mod connection_creation_page;
 mod connection_custom_info_page;
+mod connection_overview_row;
 mod connection_row;
+mod connections_overview_page;
 mod connections_sidebar;
 mod container;
 mod container_card;
+mod container_checkpoint_page;
 mod container_commit_page;
 mod container_creation_page;
 mod container_details_page;
+mod container_exec_page;
+mod container_export_page;
 mod container_files_get_page;
 mod container_files_put_page;
 mod container_health_check_log_row;
@@ -23,6 +30,7 @@ mod container_menu_button;
 mod container_properties_group;
 mod container_renamer;
 mod container_resources;
+mod container_restore_page;
 mod container_row;
 mod container_terminal;
 mod container_terminal_page;
@@ -37,6 +45,7 @@ mod image;
 mod image_build_page;
 mod image_details_page;
 mod image_history_page;
+mod image_load_page;
 mod image_menu_button;
 mod image_pull_page;
 mod image_row;
@@ -47,10 +56,22 @@ mod image_selection_page;
 mod images_panel;
 mod images_prune_page;
 mod images_row;
+mod images_save_page;
 mod info_panel;
 mod info_row;
 mod key_val_row;
+mod kube_play_page;
+mod log_highlight_rules_page;
 mod mount_row;
+mod multi_container_log_page;
+mod network;
+mod network_attachment_row;
+mod network_creation_page;
+mod network_details_page;
+mod network_row;
+mod networks_panel;
+mod networks_prune_page;
+mod networks_row;
 mod pod;
 mod pod_creation_page;
 mod pod_details_page;
@@ -66,9 +87,17 @@ mod repo_tag_push_page;
 mod repo_tag_row;
 mod repo_tag_selection_page;
 mod repo_tag_simple_row;
+mod resources_history;
 mod scalable_text_view_page;
 mod search_panel;
 mod search_row;
+mod secret;
+mod secret_creation_page;
+mod secret_details_page;
+mod secret_mount_row;
+mod secret_row;
+mod secrets_panel;
+mod secrets_row;
 mod top_page;
 mod value_row;
 mod volume;
@@ -87,27 +116,36 @@ pub(crate) use self::action_page::ActionPage;
 pub(crate) use self::action_row::ActionRow;
 pub(crate) use self::actions_button::ActionsButton;
 pub(crate) use self::actions_sidebar::ActionsSidebar;
+pub(crate) use self::alert_rules_page::AlertRulesPage;
 pub(crate) use self::client_view::ClientView;
+pub(crate) use self::compose_up_page::ComposeUpPage;
 pub(crate) use self::connection::show_ongoing_actions_warning_dialog;
 pub(crate) use self::connection_chooser_page::ConnectionChooserPage;
 pub(crate) use self::connection_creation_page::ConnectionCreationPage;
 pub(crate) use self::connection_custom_info_page::ConnectionCustomInfoDialog;
+pub(crate) use self::connection_overview_row::ConnectionOverviewRow;
 pub(crate) use self::connection_row::ConnectionRow;
+pub(crate) use self::connections_overview_page::ConnectionsOverviewPage;
 pub(crate) use self::connections_sidebar::ConnectionsSidebar;
 pub(crate) use self::container::container_status_css_class;
 pub(crate) use self::container_card::ContainerCard;
+pub(crate) use self::container_checkpoint_page::ContainerCheckpointPage;
 pub(crate) use self::container_commit_page::ContainerCommitPage;
 pub(crate) use self::container_creation_page::ContainerCreationPage;
 pub(crate) use self::container_details_page::ContainerDetailsPage;
+pub(crate) use self::container_exec_page::ContainerExecPage;
+pub(crate) use self::container_export_page::ContainerExportPage;
 pub(crate) use self::container_files_get_page::ContainerFilesGetPage;
 pub(crate) use self::container_files_put_page::ContainerFilesPutPage;
 pub(crate) use self::container_health_check_log_row::ContainerHealthCheckLogRow;
 pub(crate) use self::container_health_check_page::ContainerHealthCheckPage;
 pub(crate) use self::container_log_page::ContainerLogPage;
+pub(crate) use self::container_log_page::MarkupPerform;
 pub(crate) use self::container_menu_button::ContainerMenuButton;
 pub(crate) use self::container_properties_group::ContainerPropertiesGroup;
 pub(crate) use self::container_renamer::ContainerRenamer;
 pub(crate) use self::container_resources::ContainerResources;
+pub(crate) use self::container_restore_page::ContainerRestorePage;
 pub(crate) use self::container_row::ContainerRow;
 pub(crate) use self::container_terminal::ContainerTerminal;
 pub(crate) use self::container_terminal_page::ContainerTerminalPage;
@@ -121,6 +159,7 @@ pub(crate) use self::device_row::DeviceRow;
 pub(crate) use self::image_build_page::ImageBuildPage;
 pub(crate) use self::image_details_page::ImageDetailsPage;
 pub(crate) use self::image_history_page::ImageHistoryPage;
+pub(crate) use self::image_load_page::ImageLoadPage;
 pub(crate) use self::image_menu_button::ImageMenuButton;
 pub(crate) use self::image_pull_page::ImagePullPage;
 pub(crate) use self::image_row::ImageRow;
@@ -132,10 +171,21 @@ pub(crate) use self::image_selection_page::ImageSelectionPage;
 pub(crate) use self::images_panel::ImagesPanel;
 pub(crate) use self::images_prune_page::ImagesPrunePage;
 pub(crate) use self::images_row::ImagesRow;
+pub(crate) use self::images_save_page::ImagesSavePage;
 pub(crate) use self::info_panel::InfoPanel;
 pub(crate) use self::info_row::InfoRow;
 pub(crate) use self::key_val_row::KeyValRow;
+pub(crate) use self::kube_play_page::KubePlayPage;
+pub(crate) use self::log_highlight_rules_page::LogHighlightRulesPage;
 pub(crate) use self::mount_row::MountRow;
+pub(crate) use self::multi_container_log_page::MultiContainerLogPage;
+pub(crate) use self::network_attachment_row::NetworkAttachmentRow;
+pub(crate) use self::network_creation_page::NetworkCreationPage;
+pub(crate) use self::network_details_page::NetworkDetailsPage;
+pub(crate) use self::network_row::NetworkRow;
+pub(crate) use self::networks_panel::NetworksPanel;
+pub(crate) use self::networks_prune_page::NetworksPrunePage;
+pub(crate) use self::networks_row::NetworksRow;
 pub(crate) use self::pod::pod_status_css_class;
 pub(crate) use self::pod_creation_page::PodCreationPage;
 pub(crate) use self::pod_details_page::PodDetailsPage;
@@ -151,11 +201,18 @@ pub(crate) use self::repo_tag_push_page::RepoTagPushPage;
 pub(crate) use self::repo_tag_row::RepoTagRow;
 pub(crate) use self::repo_tag_selection_page::RepoTagSelectionPage;
 pub(crate) use self::repo_tag_simple_row::RepoTagSimpleRow;
+pub(crate) use self::resources_history::ResourcesHistory;
 pub(crate) use self::scalable_text_view_page::Entity;
 pub(crate) use self::scalable_text_view_page::Mode as ScalableTextViewMode;
 pub(crate) use self::scalable_text_view_page::ScalableTextViewPage;
 pub(crate) use self::search_panel::SearchPanel;
 pub(crate) use self::search_row::SearchRow;
+pub(crate) use self::secret_creation_page::SecretCreationPage;
+pub(crate) use self::secret_details_page::SecretDetailsPage;
+pub(crate) use self::secret_mount_row::SecretMountRow;
+pub(crate) use self::secret_row::SecretRow;
+pub(crate) use self::secrets_panel::SecretsPanel;
+pub(crate) use self::secrets_row::SecretsRow;
 pub(crate) use self::top_page::TopPage;
 pub(crate) use self::value_row::ValueRow;
 pub(crate) use self::volume_creation_page::VolumeCreationPage;
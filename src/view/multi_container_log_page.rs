@@ -0,0 +1,566 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use futures::prelude::*;
+use glib::clone;
+use glib::Properties;
+use gtk::gdk;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+use sourceview5::prelude::*;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::view;
+use crate::widget;
+
+const ACTION_TOGGLE_SEARCH: &str = "multi-container-log-page.toggle-search";
+const ACTION_EXIT_SEARCH: &str = "multi-container-log-page.exit-search";
+const ACTION_SHOW_TIMESTAMPS: &str = "multi-container-log-page.show-timestamps";
+const ACTION_SCROLL_DOWN: &str = "multi-container-log-page.scroll-down";
+const ACTION_ZOOM_OUT: &str = "multi-container-log-page.zoom-out";
+const ACTION_ZOOM_IN: &str = "multi-container-log-page.zoom-in";
+const ACTION_ZOOM_NORMAL: &str = "multi-container-log-page.zoom-normal";
+
+/// The number of lines that are initially fetched for each container.
+const TAIL_LINES: &str = "256";
+
+/// The colors of the container name prefixes. They are readable on light and dark backgrounds.
+const PREFIX_COLORS: &[&str] = &[
+    "#3584e4", "#2ec27e", "#e5a50a", "#9141ac", "#e01b24", "#986a44", "#ff7800", "#2190a4",
+];
+
+/// A container whose log lines are merged into the page.
+#[derive(Debug)]
+struct Member {
+    container: glib::WeakRef<model::Container>,
+    name: String,
+    color: &'static str,
+    /// The tag that is applied to all lines of the container to be able to hide them.
+    tag: gtk::TextTag,
+    /// The time of the newest line so far. It is used to continue following the log after the
+    /// container has been restarted.
+    last_time: Option<glib::DateTime>,
+    following: bool,
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::MultiContainerLogPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/multi_container_log_page.ui")]
+    pub(crate) struct MultiContainerLogPage {
+        pub(super) settings: utils::PodsSettings,
+        pub(super) members: RefCell<Vec<Member>>,
+        /// The time and the timestamp of each line in the buffer, in chronological order.
+        pub(super) lines: RefCell<Vec<(glib::DateTime, String)>>,
+        pub(super) is_auto_scrolling: Cell<bool>,
+        #[property(get, set, construct_only)]
+        pub(super) containers: OnceCell<gio::ListModel>,
+        #[property(get, set, construct_only)]
+        pub(super) subtitle: OnceCell<String>,
+        #[property(get, set)]
+        pub(super) sticky: Cell<bool>,
+        #[template_child]
+        pub(super) zoom_control: TemplateChild<widget::ZoomControl>,
+        #[template_child]
+        pub(super) timestamps_renderer: TemplateChild<sourceview5::GutterRendererText>,
+        #[template_child]
+        pub(super) menu_button: TemplateChild<gtk::MenuButton>,
+        #[template_child]
+        pub(super) search_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) search_bar: TemplateChild<gtk::SearchBar>,
+        #[template_child]
+        pub(super) search_widget: TemplateChild<widget::SourceViewSearchWidget>,
+        #[template_child]
+        pub(super) members_box: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) scrolled_window: TemplateChild<gtk::ScrolledWindow>,
+        #[template_child]
+        pub(super) scalable_text_view: TemplateChild<widget::ScalableTextView>,
+        #[template_child]
+        pub(super) source_buffer: TemplateChild<sourceview5::Buffer>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for MultiContainerLogPage {
+        const NAME: &'static str = "PdsMultiContainerLogPage";
+        type Type = super::MultiContainerLogPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.add_binding_action(
+                gdk::Key::F,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_TOGGLE_SEARCH,
+            );
+            klass.install_action(ACTION_TOGGLE_SEARCH, None, |widget, _, _| {
+                widget.toggle_search_mode();
+            });
+
+            klass.add_binding_action(
+                gdk::Key::Escape,
+                gdk::ModifierType::empty(),
+                ACTION_EXIT_SEARCH,
+            );
+            klass.install_action(ACTION_EXIT_SEARCH, None, |widget, _, _| {
+                widget.set_search_mode(false);
+            });
+
+            klass.install_property_action(ACTION_SHOW_TIMESTAMPS, "show-timestamps");
+
+            klass.install_action(ACTION_SCROLL_DOWN, None, |widget, _, _| {
+                widget.scroll_down();
+            });
+
+            klass.install_action(ACTION_ZOOM_OUT, None, |widget, _, _| {
+                widget.imp().scalable_text_view.zoom_out();
+            });
+            klass.install_action(ACTION_ZOOM_IN, None, |widget, _, _| {
+                widget.imp().scalable_text_view.zoom_in();
+            });
+            klass.install_action(ACTION_ZOOM_NORMAL, None, |widget, _, _| {
+                widget.imp().scalable_text_view.zoom_normal();
+            });
+
+            klass.add_binding_action(
+                gdk::Key::minus,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_ZOOM_OUT,
+            );
+            klass.add_binding_action(
+                gdk::Key::KP_Subtract,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_ZOOM_OUT,
+            );
+
+            klass.add_binding_action(
+                gdk::Key::plus,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_ZOOM_IN,
+            );
+            klass.add_binding_action(
+                gdk::Key::KP_Add,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_ZOOM_IN,
+            );
+            klass.add_binding_action(
+                gdk::Key::equal,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_ZOOM_IN,
+            );
+
+            klass.add_binding_action(
+                gdk::Key::_0,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_ZOOM_NORMAL,
+            );
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for MultiContainerLogPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: OnceLock<Vec<glib::ParamSpec>> = OnceLock::new();
+            PROPERTIES.get_or_init(|| {
+                Self::derived_properties()
+                    .iter()
+                    .cloned()
+                    .chain(Some(
+                        glib::ParamSpecBoolean::builder("show-timestamps")
+                            .explicit_notify()
+                            .build(),
+                    ))
+                    .collect::<Vec<_>>()
+            })
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            match pspec.name() {
+                "show-timestamps" => self.obj().set_show_timestamps(value.get().unwrap()),
+                _ => self.derived_set_property(id, value, pspec),
+            }
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "show-timestamps" => self.obj().show_timestamps().to_value(),
+                _ => self.derived_property(id, pspec),
+            }
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.settings
+                .bind(
+                    "show-log-timestamps",
+                    &self.timestamps_renderer.get(),
+                    "visible",
+                )
+                .build();
+
+            self.menu_button
+                .popover()
+                .unwrap()
+                .downcast::<gtk::PopoverMenu>()
+                .unwrap()
+                .add_child(&*self.zoom_control, "zoom-control");
+
+            let adw_style_manager = adw::StyleManager::default();
+            obj.on_notify_dark(&adw_style_manager);
+            adw_style_manager.connect_dark_notify(clone!(@weak obj => move |style_manager| {
+                obj.on_notify_dark(style_manager);
+            }));
+
+            <widget::ScalableTextView as ViewExt>::gutter(
+                &*self.scalable_text_view,
+                gtk::TextWindowType::Left,
+            )
+            .insert(&self.timestamps_renderer.get(), 0);
+
+            let adj = self.scrolled_window.vadjustment();
+            obj.on_adjustment_changed(&adj);
+            adj.connect_value_changed(clone!(@weak obj => move |adj| {
+                obj.on_adjustment_changed(adj);
+            }));
+
+            adj.connect_upper_notify(clone!(@weak obj => move |_| {
+                if obj.sticky() || obj.imp().is_auto_scrolling.get() {
+                    obj.scroll_down();
+                }
+            }));
+
+            let containers = obj.containers();
+            containers.connect_items_changed(
+                clone!(@weak obj => move |containers, position, _, added| {
+                    (position..position + added)
+                        .filter_map(|position| containers.item(position).and_downcast())
+                        .for_each(|container| obj.add_member(&container));
+                }),
+            );
+            containers
+                .iter::<model::Container>()
+                .map(Result::unwrap)
+                .for_each(|container| obj.add_member(&container));
+
+            if containers.n_items() == 0 {
+                self.stack.set_visible_child_name("loaded");
+            }
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for MultiContainerLogPage {}
+
+    #[gtk::template_callbacks]
+    impl MultiContainerLogPage {
+        #[template_callback]
+        fn on_timestamps_renderer_query_data(&self, _: &glib::Object, line: u32) {
+            if let Some((_, timestamp)) = self.lines.borrow().get(line as usize) {
+                let date_time = format!("<span foreground=\"#865e3c\">{timestamp}</span>",);
+                self.timestamps_renderer.set_markup(&date_time);
+
+                let (width, _) = self.timestamps_renderer.measure_markup(&date_time);
+                self.timestamps_renderer
+                    .set_width_request(width.max(self.timestamps_renderer.width_request()));
+            }
+        }
+
+        #[template_callback]
+        fn on_timestamps_renderer_notify_visible(&self) {
+            self.obj().notify("show-timestamps");
+        }
+
+        #[template_callback]
+        fn on_scroll(
+            &self,
+            _dx: f64,
+            dy: f64,
+            scroll: gtk::EventControllerScroll,
+        ) -> glib::Propagation {
+            if scroll.current_event_state() == gdk::ModifierType::CONTROL_MASK {
+                let text_view = &*self.scalable_text_view;
+                if dy.is_sign_negative() {
+                    text_view.zoom_in();
+                } else {
+                    text_view.zoom_out();
+                }
+            }
+
+            glib::Propagation::Proceed
+        }
+
+        #[template_callback]
+        fn on_source_buffer_cursor_moved(&self) {
+            self.timestamps_renderer.queue_draw();
+        }
+
+        #[template_callback]
+        fn on_search_bar_search_mode_enabled(&self) {
+            if self.search_bar.is_search_mode() {
+                self.search_widget.grab_focus();
+            } else {
+                self.search_widget.set_text("");
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct MultiContainerLogPage(ObjectSubclass<imp::MultiContainerLogPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Pod> for MultiContainerLogPage {
+    fn from(pod: &model::Pod) -> Self {
+        Self::new(pod.container_list().upcast_ref(), &pod.name())
+    }
+}
+
+impl MultiContainerLogPage {
+    /// Creates a page that merges the logs of all containers of the list by their timestamps.
+    pub(crate) fn new(containers: &gio::ListModel, subtitle: &str) -> Self {
+        glib::Object::builder()
+            .property("containers", containers)
+            .property("subtitle", subtitle)
+            .build()
+    }
+
+    pub(crate) fn show_timestamps(&self) -> bool {
+        self.imp().timestamps_renderer.is_visible()
+    }
+
+    pub(crate) fn set_show_timestamps(&self, value: bool) {
+        if self.show_timestamps() == value {
+            return;
+        }
+
+        self.imp().timestamps_renderer.set_visible(value);
+    }
+
+    pub(crate) fn scroll_down(&self) {
+        let imp = self.imp();
+
+        imp.is_auto_scrolling.set(true);
+        glib::idle_add_local_once(clone!(@weak self as obj => move || {
+            obj.imp().scrolled_window.vadjustment().set_value(f64::MAX);
+        }));
+    }
+
+    fn on_adjustment_changed(&self, adj: &gtk::Adjustment) {
+        let imp = self.imp();
+
+        if imp.is_auto_scrolling.get() {
+            if adj.value() + adj.page_size() >= adj.upper() {
+                imp.is_auto_scrolling.set(false);
+                self.set_sticky(true);
+            }
+        } else {
+            self.set_sticky(adj.value() + adj.page_size() >= adj.upper());
+        }
+    }
+
+    fn add_member(&self, container: &model::Container) {
+        let imp = self.imp();
+
+        let index = imp.members.borrow().len();
+        let name = container.name();
+        let color = PREFIX_COLORS[index % PREFIX_COLORS.len()];
+
+        let tag = gtk::TextTag::new(None);
+        imp.source_buffer.tag_table().add(&tag);
+
+        let toggle_button = gtk::ToggleButton::builder()
+            .active(true)
+            .child(
+                &gtk::Label::builder()
+                    .label(format!(
+                        "<span foreground=\"{color}\"><b>{}</b></span>",
+                        glib::markup_escape_text(&name),
+                    ))
+                    .use_markup(true)
+                    .build(),
+            )
+            .tooltip_text(name.as_str())
+            .build();
+        toggle_button.add_css_class("flat");
+        toggle_button.connect_active_notify(clone!(@weak tag => move |button| {
+            tag.set_invisible(!button.is_active());
+        }));
+        imp.members_box.append(&toggle_button);
+
+        let weak_ref = glib::WeakRef::new();
+        weak_ref.set(Some(container));
+
+        imp.members.borrow_mut().push(Member {
+            container: weak_ref,
+            name,
+            color,
+            tag,
+            last_time: None,
+            following: false,
+        });
+
+        container.connect_notify_local(
+            Some("status"),
+            clone!(@weak self as obj => move |container, _| {
+                if container.status() == model::ContainerStatus::Running {
+                    obj.follow_log(index);
+                }
+            }),
+        );
+
+        self.follow_log(index);
+    }
+
+    /// Follows the log of the member. The first time, the last lines are fetched. Afterwards, the
+    /// log is continued where it stopped.
+    fn follow_log(&self, index: usize) {
+        let (container, since) = {
+            let mut members = self.imp().members.borrow_mut();
+            let member = &mut members[index];
+
+            if member.following {
+                return;
+            }
+
+            match member
+                .container
+                .upgrade()
+                .as_ref()
+                .and_then(model::Container::api)
+            {
+                Some(container) => {
+                    member.following = true;
+                    (container, member.last_time.clone())
+                }
+                None => return,
+            }
+        };
+
+        let opts = podman::opts::ContainerLogsOpts::builder()
+            .follow(true)
+            .stdout(true)
+            .stderr(true)
+            .timestamps(true);
+        let opts = match since {
+            Some(ref since) => opts.since(since.to_unix().to_string()),
+            None => opts.tail(TAIL_LINES),
+        };
+
+        let mut perform = view::MarkupPerform::default();
+
+        utils::run_stream_with_finish_handler(
+            container,
+            move |container| container.logs(&opts.build()).boxed(),
+            clone!(
+                @weak self as obj => @default-return glib::ControlFlow::Break,
+                move |result: podman::Result<podman::conn::TtyChunk>|
+            {
+                obj.imp().stack.set_visible_child_name("loaded");
+
+                match result {
+                    Ok(line) => {
+                        obj.insert(index, &perform.decode(&Vec::from(line)), since.as_ref());
+                        glib::ControlFlow::Continue
+                    }
+                    Err(e) => {
+                        log::warn!("Stopping container log stream due to error: {e}");
+                        glib::ControlFlow::Break
+                    }
+                }
+            }),
+            clone!(@weak self as obj => move || {
+                let imp = obj.imp();
+                imp.stack.set_visible_child_name("loaded");
+                imp.members.borrow_mut()[index].following = false;
+            }),
+        );
+    }
+
+    /// Inserts the decoded line of the member at the position of its timestamp. Lines that are not
+    /// newer than `since` have already been shown and are skipped.
+    fn insert(&self, index: usize, line: &str, since: Option<&glib::DateTime>) {
+        let imp = self.imp();
+
+        let Some((timestamp, message)) = line.split_once(' ') else {
+            return;
+        };
+        let Ok(time) = glib::DateTime::from_iso8601(timestamp, None) else {
+            return;
+        };
+        if since.is_some_and(|since| &time <= since) {
+            return;
+        }
+
+        let mut members = imp.members.borrow_mut();
+        let member = &mut members[index];
+        member.last_time = Some(time.clone());
+
+        let mut lines = imp.lines.borrow_mut();
+        let position = lines.partition_point(|(t, _)| t <= &time);
+
+        // Every line ends with a line break, so the buffer always has an empty last line to
+        // insert at.
+        let source_buffer = &*imp.source_buffer;
+        let mut iter = source_buffer
+            .iter_at_line(position as i32)
+            .unwrap_or_else(|| source_buffer.end_iter());
+        let start = iter.offset();
+
+        source_buffer.insert_markup(
+            &mut iter,
+            &format!(
+                "<span foreground=\"{}\"><b>{}</b></span> {message}\n",
+                member.color,
+                glib::markup_escape_text(&member.name),
+            ),
+        );
+        source_buffer.apply_tag(&member.tag, &source_buffer.iter_at_offset(start), &iter);
+
+        lines.insert(position, (time, timestamp.to_owned()));
+    }
+
+    fn on_notify_dark(&self, style_manager: &adw::StyleManager) {
+        self.imp().source_buffer.set_style_scheme(
+            sourceview5::StyleSchemeManager::default()
+                .scheme(if style_manager.is_dark() {
+                    "Adwaita-dark"
+                } else {
+                    "Adwaita"
+                })
+                .as_ref(),
+        );
+    }
+
+    pub(crate) fn set_search_mode(&self, value: bool) {
+        self.imp().search_bar.set_search_mode(value);
+    }
+
+    pub(crate) fn toggle_search_mode(&self) {
+        self.set_search_mode(!self.imp().search_bar.is_search_mode());
+    }
+}
@@ -0,0 +1,80 @@
+use adw::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use gtk::gio;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+
+pub(crate) fn summary(inner: &model::BoxedNetwork) -> String {
+    let driver = inner.driver.clone().unwrap_or_default();
+
+    let subnets = inner
+        .subnets
+        .iter()
+        .flatten()
+        .filter_map(|subnet| subnet.subnet.as_deref())
+        .collect::<Vec<_>>();
+
+    if subnets.is_empty() {
+        driver
+    } else {
+        format!("{driver} · {}", subnets.join(", "))
+    }
+}
+
+pub(crate) fn delete_network_show_confirmation(
+    widget: &gtk::Widget,
+    network: Option<model::Network>,
+) {
+    if let Some(network) = network {
+        match network.container_list().get(0) {
+            Some(container) => {
+                let dialog = adw::AlertDialog::builder()
+                .heading(gettext("Confirm Network Deletion"))
+                .body_use_markup(true)
+                .body(gettext!(
+                    // Translators: The "{}" is a placeholder for the container name.
+                    "Network is used by container <b>{}</b>. Deleting the network will also delete these containers.",
+                    container.name(),
+                ))
+                .build();
+
+                dialog.add_responses(&[
+                    ("cancel", &gettext("_Cancel")),
+                    ("delete", &gettext("_Delete")),
+                ]);
+                dialog.set_default_response(Some("cancel"));
+                dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+                dialog.choose(
+                    widget,
+                    gio::Cancellable::NONE,
+                    clone!(@weak widget, @weak network => move |response| {
+                        if response == "delete" {
+                            delete_network(&widget, &network, true);
+                        }
+                    }),
+                );
+            }
+            None => delete_network(widget, &network, false),
+        }
+    }
+}
+
+fn delete_network(widget: &gtk::Widget, network: &model::Network, force: bool) {
+    network.delete(
+        force,
+        clone!(@weak widget => move |network, result| {
+            if let Err(e) = result {
+                utils::show_error_toast(
+                    &widget,
+                    // Translators: The "{}" is a placeholder for the network name.
+                    &gettext!("Error on deleting network '{}'", network.name()),
+                    &e.to_string(),
+                );
+            }
+        }),
+    );
+}
@@ -0,0 +1,186 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::ExpanderRowImpl;
+use adw::subclass::prelude::PreferencesRowImpl;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::closure;
+use glib::Properties;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworkAttachmentRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/network_attachment_row.ui")]
+    pub(crate) struct NetworkAttachmentRow {
+        pub(super) bindings: RefCell<Vec<glib::Binding>>,
+        #[property(get, set = Self::set_network_attachment, construct)]
+        pub(super) network_attachment: RefCell<Option<model::NetworkAttachment>>,
+        #[template_child]
+        pub(super) network_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) network_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) aliases_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ipv4_address_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ipv6_address_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) mac_address_entry_row: TemplateChild<adw::EntryRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkAttachmentRow {
+        const NAME: &'static str = "PdsNetworkAttachmentRow";
+        type Type = super::NetworkAttachmentRow;
+        type ParentType = adw::ExpanderRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action("network-attachment-row.remove", None, |widget, _, _| {
+                if let Some(network_attachment) = widget.network_attachment() {
+                    network_attachment.remove_request();
+                }
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworkAttachmentRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let network_name_expr = model::Network::this_expression("inner")
+                .chain_closure::<String>(closure!(
+                    |_: model::Network, inner: model::BoxedNetwork| inner
+                        .name
+                        .clone()
+                        .unwrap_or_default()
+                ));
+            self.network_combo_row
+                .set_expression(Some(&network_name_expr));
+
+            Self::Type::this_expression("network-attachment")
+                .chain_property::<model::NetworkAttachment>("network")
+                .chain_closure::<String>(closure!(
+                    |_: Self::Type, network: Option<model::Network>| {
+                        network
+                            .as_ref()
+                            .map(model::Network::name)
+                            .unwrap_or_else(|| gettext("No Network"))
+                    }
+                ))
+                .bind(&self.network_label.get(), "label", Some(obj));
+        }
+    }
+
+    impl WidgetImpl for NetworkAttachmentRow {}
+    impl ListBoxRowImpl for NetworkAttachmentRow {}
+    impl PreferencesRowImpl for NetworkAttachmentRow {}
+    impl ExpanderRowImpl for NetworkAttachmentRow {}
+
+    impl NetworkAttachmentRow {
+        pub(super) fn set_network_attachment(&self, value: Option<model::NetworkAttachment>) {
+            let obj = &*self.obj();
+            if obj.network_attachment() == value {
+                return;
+            }
+
+            let mut bindings = self.bindings.borrow_mut();
+
+            while let Some(binding) = bindings.pop() {
+                binding.unbind();
+            }
+
+            if let Some(ref network_attachment) = value {
+                if let Some(network_list) = network_attachment
+                    .client()
+                    .as_ref()
+                    .map(model::Client::network_list)
+                {
+                    self.network_combo_row.set_model(Some(&network_list));
+
+                    match network_attachment.network() {
+                        Some(network) => {
+                            if let Some(position) = network_list
+                                .iter::<model::Network>()
+                                .map(Result::unwrap)
+                                .position(|other| other == network)
+                            {
+                                self.network_combo_row.set_selected(position as u32);
+                            }
+                        }
+                        None => network_attachment.set_network(
+                            self.network_combo_row
+                                .selected_item()
+                                .and_downcast_ref::<model::Network>(),
+                        ),
+                    }
+                }
+
+                let binding = self
+                    .network_combo_row
+                    .bind_property("selected-item", network_attachment, "network")
+                    .transform_to(|_, item: Option<glib::Object>| {
+                        Some(item.and_downcast::<model::Network>().to_value())
+                    })
+                    .build();
+                bindings.push(binding);
+
+                for (property, entry_row) in [
+                    ("aliases", &*self.aliases_entry_row),
+                    ("ipv4-address", &*self.ipv4_address_entry_row),
+                    ("ipv6-address", &*self.ipv6_address_entry_row),
+                    ("mac-address", &*self.mac_address_entry_row),
+                ] {
+                    let binding = network_attachment
+                        .bind_property(property, entry_row, "text")
+                        .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                        .build();
+                    bindings.push(binding);
+                }
+            }
+
+            self.network_attachment.replace(value);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkAttachmentRow(ObjectSubclass<imp::NetworkAttachmentRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ExpanderRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::NetworkAttachment> for NetworkAttachmentRow {
+    fn from(network_attachment: &model::NetworkAttachment) -> Self {
+        glib::Object::builder()
+            .property("network-attachment", network_attachment)
+            .build()
+    }
+}
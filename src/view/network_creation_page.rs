@@ -0,0 +1,174 @@
+use std::cell::Cell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use glib::clone;
+use glib::Properties;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::view;
+use crate::widget;
+
+const ACTION_CREATE_NETWORK: &str = "network-creation-page.create-network";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworkCreationPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/network_creation_page.ui")]
+    pub(crate) struct NetworkCreationPage {
+        #[property(get, set, construct_only)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) show_view_artifact: Cell<bool>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) create_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) name_entry_row: TemplateChild<widget::RandomNameEntryRow>,
+        #[template_child]
+        pub(super) driver_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) subnet_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) gateway_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) ipv6_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) internal_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) dns_switch_row: TemplateChild<adw::SwitchRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkCreationPage {
+        const NAME: &'static str = "PdsNetworkCreationPage";
+        type Type = super::NetworkCreationPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_CREATE_NETWORK, None, |widget, _, _| {
+                widget.create_network();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworkCreationPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for NetworkCreationPage {
+        fn root(&self) {
+            self.parent_root();
+
+            let widget = &*self.obj();
+
+            glib::idle_add_local(
+                clone!(@weak widget => @default-return glib::ControlFlow::Break, move || {
+                    widget.imp().name_entry_row.grab_focus();
+                    glib::ControlFlow::Break
+                }),
+            );
+            utils::root(widget.upcast_ref()).set_default_widget(Some(&*self.create_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkCreationPage(ObjectSubclass<imp::NetworkCreationPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Client> for NetworkCreationPage {
+    fn from(client: &model::Client) -> Self {
+        Self::new(client, true)
+    }
+}
+
+impl NetworkCreationPage {
+    pub(crate) fn new(client: &model::Client, show_view_artifact: bool) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("show-view-artifact", show_view_artifact)
+            .build()
+    }
+
+    fn create_network(&self) {
+        if let Some(client) = self.client() {
+            let imp = self.imp();
+
+            let name = imp.name_entry_row.text();
+
+            let mut opts = podman::opts::NetworkCreateOpts::builder()
+                .name(name.as_str())
+                .driver(
+                    imp.driver_combo_row
+                        .selected_item()
+                        .and_downcast::<gtk::StringObject>()
+                        .unwrap()
+                        .string()
+                        .as_str(),
+                )
+                .ipv6_enabled(imp.ipv6_switch_row.is_active())
+                .internal(imp.internal_switch_row.is_active())
+                .dns_enabled(imp.dns_switch_row.is_active());
+
+            let subnet = imp.subnet_entry_row.text();
+            if !subnet.is_empty() {
+                let gateway = imp.gateway_entry_row.text();
+
+                opts = opts.subnets(vec![podman::models::Subnet {
+                    subnet: Some(subnet.to_string()),
+                    gateway: Some(gateway.to_string()).filter(|gateway| !gateway.is_empty()),
+                    lease_range: None,
+                }]);
+            }
+
+            let page = view::ActionPage::new(
+                &client
+                    .action_list()
+                    .create_network(name.as_str(), opts.build()),
+                self.show_view_artifact(),
+            );
+
+            imp.navigation_view.push(
+                &adw::NavigationPage::builder()
+                    .can_pop(false)
+                    .child(&page)
+                    .build(),
+            );
+        }
+    }
+}
@@ -0,0 +1,473 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use glib::closure;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+use crate::widget;
+
+const ACTION_INSPECT_NETWORK: &str = "network-details-page.inspect-network";
+const ACTION_DELETE_NETWORK: &str = "network-details-page.delete-network";
+const ACTION_CONNECT_CONTAINER: &str = "network-details-page.connect-container";
+const ACTION_DISCONNECT_CONTAINER: &str = "network-details-page.disconnect-container";
+const ACTION_COPY_LINK: &str = "network-details-page.copy-link";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworkDetailsPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/network_details_page.ui")]
+    pub(crate) struct NetworkDetailsPage {
+        pub(super) handler_id: RefCell<Option<glib::SignalHandlerId>>,
+        #[property(get, set = Self::set_network, construct, explicit_notify, nullable)]
+        pub(super) network: glib::WeakRef<model::Network>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) name_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) id_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) created_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) driver_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) interface_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) subnets_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) gateways_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) ipv6_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) internal_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) dns_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) containers_list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworkDetailsPage {
+        const NAME: &'static str = "PdsNetworkDetailsPage";
+        type Type = super::NetworkDetailsPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_COPY_LINK, None, |widget, _, _| {
+                widget.copy_link();
+            });
+
+            klass.install_action(ACTION_INSPECT_NETWORK, None, |widget, _, _| {
+                widget.show_inspection();
+            });
+
+            klass.install_action(ACTION_DELETE_NETWORK, None, |widget, _, _| {
+                widget.delete_network();
+            });
+
+            klass.install_action(ACTION_CONNECT_CONTAINER, None, |widget, _, _| {
+                widget.connect_container();
+            });
+
+            klass.install_action(
+                ACTION_DISCONNECT_CONTAINER,
+                Some(glib::VariantTy::STRING),
+                |widget, _, data| {
+                    widget.disconnect_container(&data.unwrap().get::<String>().unwrap());
+                },
+            );
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworkDetailsPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let network_expr = Self::Type::this_expression("network");
+            let network_inner_expr = network_expr.chain_property::<model::Network>("inner");
+
+            network_expr
+                .chain_property::<model::Network>("to-be-deleted")
+                .watch(
+                    Some(obj),
+                    clone!(@weak obj => move || {
+                        obj.action_set_enabled(
+                            ACTION_DELETE_NETWORK,
+                            obj.network()
+                                .map(|network| !network.to_be_deleted() && !network.is_default())
+                                .unwrap_or(false),
+                        );
+                    }),
+                );
+
+            network_expr
+                .chain_property::<model::Network>("action-ongoing")
+                .watch(
+                    Some(obj),
+                    clone!(@weak obj => move || {
+                        let enabled = obj
+                            .network()
+                            .map(|network| !network.action_ongoing())
+                            .unwrap_or(false);
+                        obj.action_set_enabled(ACTION_CONNECT_CONTAINER, enabled);
+                        obj.action_set_enabled(ACTION_DISCONNECT_CONTAINER, enabled);
+                    }),
+                );
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    inner.name.clone().unwrap_or_default()
+                }))
+                .bind(&*self.name_row, "value", Some(obj));
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    utils::format_id(inner.id.as_deref().unwrap_or_default())
+                }))
+                .bind(&*self.id_row, "value", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                &[
+                    &Self::Type::this_expression("root")
+                        .chain_property::<gtk::Window>("application")
+                        .chain_property::<crate::Application>("ticks"),
+                    &network_inner_expr,
+                ],
+                closure!(|_: Self::Type, _ticks: u64, inner: &model::BoxedNetwork| {
+                    utils::format_ago(utils::timespan_now(
+                        inner.created.map(|dt| dt.timestamp()).unwrap_or(0),
+                    ))
+                }),
+            )
+            .bind(&*self.created_row, "value", Some(obj));
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    inner.driver.clone().unwrap_or_default()
+                }))
+                .bind(&*self.driver_row, "value", Some(obj));
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    inner.network_interface.clone().unwrap_or_default()
+                }))
+                .bind(&*self.interface_row, "value", Some(obj));
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    inner
+                        .subnets
+                        .iter()
+                        .flatten()
+                        .filter_map(|subnet| subnet.subnet.as_deref())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                }))
+                .bind(&*self.subnets_row, "value", Some(obj));
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    inner
+                        .subnets
+                        .iter()
+                        .flatten()
+                        .filter_map(|subnet| subnet.gateway.as_deref())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                }))
+                .bind(&*self.gateways_row, "value", Some(obj));
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    yes_no(inner.ipv6_enabled.unwrap_or(false))
+                }))
+                .bind(&*self.ipv6_row, "value", Some(obj));
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    yes_no(inner.internal.unwrap_or(false))
+                }))
+                .bind(&*self.internal_row, "value", Some(obj));
+
+            network_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedNetwork| {
+                    yes_no(inner.dns_enabled.unwrap_or(false))
+                }))
+                .bind(&*self.dns_row, "value", Some(obj));
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for NetworkDetailsPage {}
+
+    impl NetworkDetailsPage {
+        pub(super) fn set_network(&self, value: Option<&model::Network>) {
+            let obj = &*self.obj();
+            if obj.network().as_ref() == value {
+                return;
+            }
+
+            self.window_title.set_subtitle("");
+            if let Some(network) = obj.network() {
+                network.disconnect(self.handler_id.take().unwrap());
+            }
+
+            if let Some(network) = value {
+                self.window_title.set_subtitle(&network.name());
+
+                let handler_id = network.connect_deleted(clone!(@weak obj => move |network| {
+                    utils::show_toast(obj.upcast_ref(), gettext!("Network '{}' has been deleted", network.name()));
+                    utils::navigation_view(obj.upcast_ref()).pop();
+                }));
+                self.handler_id.replace(Some(handler_id));
+
+                let model = gtk::SortListModel::new(
+                    Some(network.container_list()),
+                    Some(gtk::StringSorter::new(Some(
+                        model::Container::this_expression("name"),
+                    ))),
+                );
+                self.containers_list_box.bind_model(Some(&model), |item| {
+                    super::container_row(item.downcast_ref().unwrap()).upcast()
+                });
+            }
+
+            self.network.set(value);
+            obj.notify("network");
+        }
+    }
+
+    fn yes_no(value: bool) -> String {
+        if value {
+            gettext("Yes")
+        } else {
+            gettext("No")
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworkDetailsPage(ObjectSubclass<imp::NetworkDetailsPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Network> for NetworkDetailsPage {
+    fn from(network: &model::Network) -> Self {
+        glib::Object::builder().property("network", network).build()
+    }
+}
+
+impl NetworkDetailsPage {
+    fn copy_link(&self) {
+        if let Some((network, client)) = self.network().and_then(|network| {
+            network
+                .network_list()
+                .and_then(|list| list.client())
+                .map(|client| (network, client))
+        }) {
+            utils::copy_to_clipboard(
+                self.upcast_ref(),
+                &model::DeepLink::new(
+                    &client.connection().name(),
+                    model::DeepLinkKind::Network,
+                    &network.name(),
+                )
+                .to_string(),
+                &gettext("Link copied"),
+            );
+        }
+    }
+
+    pub(crate) fn show_inspection(&self) {
+        self.exec_action(|| {
+            if let Some(network) = self.network() {
+                let weak_ref = glib::WeakRef::new();
+                weak_ref.set(Some(&network));
+
+                utils::navigation_view(self.upcast_ref()).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ScalableTextViewPage::from(view::Entity::Network(
+                            weak_ref,
+                        )))
+                        .build(),
+                );
+            }
+        });
+    }
+
+    pub(crate) fn delete_network(&self) {
+        self.exec_action(|| {
+            view::network::delete_network_show_confirmation(self.upcast_ref(), self.network());
+        });
+    }
+
+    pub(crate) fn connect_container(&self) {
+        let Some(network) = self.network() else {
+            return;
+        };
+        let Some(client) = network.network_list().and_then(|list| list.client()) else {
+            return;
+        };
+
+        let candidates = client
+            .container_list()
+            .iter::<model::Container>()
+            .map(Result::unwrap)
+            .filter(|container| {
+                network
+                    .container_list()
+                    .get_container(container.id().as_str())
+                    .is_none()
+            })
+            .collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            utils::show_toast(
+                self.upcast_ref(),
+                gettext("All containers are already connected to this network"),
+            );
+            return;
+        }
+
+        let names = candidates
+            .iter()
+            .map(model::Container::name)
+            .collect::<Vec<_>>();
+        let drop_down =
+            gtk::DropDown::from_strings(&names.iter().map(String::as_str).collect::<Vec<_>>());
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Connect Container"))
+            .body(gettext!(
+                // Translators: The "{}" is a placeholder for the network name.
+                "Select the container to connect to network '{}'.",
+                network.name()
+            ))
+            .extra_child(&drop_down)
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("connect", &gettext("C_onnect")),
+        ]);
+        dialog.set_default_response(Some("connect"));
+        dialog.set_response_appearance("connect", adw::ResponseAppearance::Suggested);
+
+        dialog.choose(
+            self,
+            gio::Cancellable::NONE,
+            clone!(@weak self as obj, @weak network => move |response| {
+                if response != "connect" {
+                    return;
+                }
+
+                if let Some(container) = candidates.get(drop_down.selected() as usize) {
+                    network.connect_container(
+                        container,
+                        clone!(@weak obj, @weak container => move |result| {
+                            if let Err(e) = result {
+                                utils::show_error_toast(
+                                    obj.upcast_ref(),
+                                    &gettext!(
+                                        "Error on connecting container '{}'",
+                                        container.name()
+                                    ),
+                                    &e.to_string(),
+                                );
+                            }
+                        }),
+                    );
+                }
+            }),
+        );
+    }
+
+    pub(crate) fn disconnect_container(&self, id: &str) {
+        let Some(network) = self.network() else {
+            return;
+        };
+        let Some(container) = network.container_list().get_container(id) else {
+            return;
+        };
+
+        network.disconnect_container(
+            &container,
+            clone!(@weak self as obj, @weak container => move |result| {
+                if let Err(e) = result {
+                    utils::show_error_toast(
+                        obj.upcast_ref(),
+                        &gettext!("Error on disconnecting container '{}'", container.name()),
+                        &e.to_string(),
+                    );
+                }
+            }),
+        );
+    }
+
+    fn exec_action<F: Fn()>(&self, op: F) {
+        if utils::navigation_view(self.upcast_ref())
+            .visible_page()
+            .filter(|page| page.child().as_ref() == Some(self.upcast_ref()))
+            .is_some()
+        {
+            op();
+        }
+    }
+}
+
+fn container_row(container: &model::Container) -> adw::ActionRow {
+    let row = adw::ActionRow::builder()
+        .icon_name("package-x-generic-symbolic")
+        .build();
+    container
+        .bind_property("name", &row, "title")
+        .sync_create()
+        .build();
+
+    let disconnect_button = gtk::Button::builder()
+        .action_name(ACTION_DISCONNECT_CONTAINER)
+        .action_target(&container.id().to_variant())
+        .css_classes(["flat"])
+        .icon_name("network-offline-symbolic")
+        .tooltip_text(gettext("Disconnect"))
+        .valign(gtk::Align::Center)
+        .build();
+    row.add_suffix(&disconnect_button);
+
+    row
+}
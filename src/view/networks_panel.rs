@@ -0,0 +1,518 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::clone;
+use glib::closure;
+use glib::Properties;
+use gtk::gdk;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::model::SelectableListExt;
+use crate::utils;
+use crate::view;
+
+const ACTION_CREATE_NETWORK: &str = "networks-panel.create-network";
+const ACTION_PRUNE_NETWORKS: &str = "networks-panel.prune-networks";
+const ACTION_ENTER_SELECTION_MODE: &str = "networks-panel.enter-selection-mode";
+const ACTION_EXIT_SELECTION_MODE: &str = "networks-panel.exit-selection-mode";
+const ACTION_SELECT_VISIBLE: &str = "networks-panel.select-visible";
+const ACTION_SELECT_NONE: &str = "networks-panel.select-none";
+const ACTION_DELETE_SELECTION: &str = "networks-panel.delete-selection";
+const ACTION_TOGGLE_SHOW_ONLY_USED_NETWORKS: &str = "networks-panel.toggle-show-only-used-networks";
+const ACTION_SHOW_ALL_NETWORKS: &str = "networks-panel.show-all-networks";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::NetworksPanel)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/networks_panel.ui")]
+    pub(crate) struct NetworksPanel {
+        pub(super) settings: utils::PodsSettings,
+        pub(super) filter: OnceCell<gtk::Filter>,
+        pub(super) sorter: OnceCell<gtk::Sorter>,
+        pub(super) search_term: RefCell<String>,
+        #[property(get, set = Self::set_network_list, explicit_notify, nullable)]
+        pub(super) network_list: glib::WeakRef<model::NetworkList>,
+        #[property(get, set)]
+        pub(super) show_only_used_networks: Cell<bool>,
+        #[template_child]
+        pub(super) main_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) header_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) selected_networks_button: TemplateChild<gtk::MenuButton>,
+        #[template_child]
+        pub(super) search_bar: TemplateChild<gtk::SearchBar>,
+        #[template_child]
+        pub(super) search_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub(super) filter_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for NetworksPanel {
+        const NAME: &'static str = "PdsNetworksPanel";
+        type Type = super::NetworksPanel;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.add_binding_action(
+                gdk::Key::N,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_CREATE_NETWORK,
+            );
+            klass.install_action(ACTION_CREATE_NETWORK, None, move |widget, _, _| {
+                widget.create_network();
+            });
+
+            klass.install_action(ACTION_PRUNE_NETWORKS, None, |widget, _, _| {
+                widget.show_prune_page();
+            });
+
+            klass.install_action(ACTION_ENTER_SELECTION_MODE, None, |widget, _, _| {
+                widget.enter_selection_mode();
+            });
+            klass.install_action(ACTION_EXIT_SELECTION_MODE, None, |widget, _, _| {
+                widget.exit_selection_mode();
+            });
+
+            klass.install_action(ACTION_SELECT_VISIBLE, None, |widget, _, _| {
+                widget.select_visible();
+            });
+            klass.install_action(ACTION_SELECT_NONE, None, |widget, _, _| {
+                widget.select_none();
+            });
+
+            klass.install_action(ACTION_DELETE_SELECTION, None, |widget, _, _| {
+                widget.delete_selection();
+            });
+
+            klass.install_property_action(
+                ACTION_TOGGLE_SHOW_ONLY_USED_NETWORKS,
+                "show-only-used-networks",
+            );
+
+            klass.install_action(ACTION_SHOW_ALL_NETWORKS, None, |widget, _, _| {
+                widget.set_show_only_used_networks(false);
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for NetworksPanel {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.settings
+                .bind("show-only-used-networks", obj, "show-only-used-networks")
+                .build();
+
+            let network_list_expr = Self::Type::this_expression("network-list");
+            let network_list_len_expr =
+                network_list_expr.chain_property::<model::NetworkList>("len");
+            let selection_mode_expr =
+                network_list_expr.chain_property::<model::NetworkList>("selection-mode");
+            let not_selection_mode_expr = selection_mode_expr.chain_closure::<bool>(closure!(
+                |_: Self::Type, selection_mode: bool| { !selection_mode }
+            ));
+
+            gtk::ClosureExpression::new::<Option<String>>(
+                [
+                    &network_list_len_expr,
+                    &network_list_expr.chain_property::<model::NetworkList>("listing"),
+                    &network_list_expr.chain_property::<model::NetworkList>("initialized"),
+                ],
+                closure!(
+                    |_: Self::Type, len: u32, listing: bool, initialized: bool| {
+                        if len == 0 {
+                            if initialized {
+                                Some("empty")
+                            } else if listing {
+                                Some("spinner")
+                            } else {
+                                None
+                            }
+                        } else {
+                            Some("networks")
+                        }
+                    }
+                ),
+            )
+            .bind(&self.main_stack.get(), "visible-child-name", Some(obj));
+
+            selection_mode_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, selection_mode: bool| {
+                    if !selection_mode {
+                        "main"
+                    } else {
+                        "selection"
+                    }
+                }))
+                .bind(&self.header_stack.get(), "visible-child-name", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                &[
+                    network_list_len_expr,
+                    network_list_expr.chain_property::<model::NetworkList>("unused"),
+                ],
+                closure!(|_: Self::Type, len: u32, unused: u32| {
+                    if len == 0 {
+                        String::new()
+                    } else if len == 1 {
+                        if unused == 1 {
+                            gettext("1 network, unused")
+                        } else {
+                            gettext("1 network, used")
+                        }
+                    } else {
+                        ngettext!(
+                            "{} networks total, {} unused",
+                            "{} networks total, {} unused",
+                            len,
+                            len,
+                            unused,
+                        )
+                    }
+                }),
+            )
+            .bind(&self.window_title.get(), "subtitle", Some(obj));
+
+            network_list_expr
+                .chain_property::<model::NetworkList>("num-selected")
+                .chain_closure::<String>(closure!(|_: Self::Type, selected: u32| ngettext!(
+                    "{} Selected Network",
+                    "{} Selected Networks",
+                    selected,
+                    selected
+                )))
+                .bind(&self.selected_networks_button.get(), "label", Some(obj));
+
+            not_selection_mode_expr.bind(&self.search_bar.get(), "visible", Some(obj));
+
+            let search_filter =
+                gtk::CustomFilter::new(clone!(@weak obj => @default-return false, move |item| {
+                    let term = &*obj.imp().search_term.borrow();
+                    item.downcast_ref::<model::Network>()
+                        .unwrap()
+                        .name()
+                        .to_lowercase()
+                        .contains(term)
+                }));
+
+            let state_filter = gtk::AnyFilter::new();
+            state_filter.append(gtk::CustomFilter::new(
+                clone!(@weak obj => @default-return false, move |_| {
+                    !obj.show_only_used_networks()
+                }),
+            ));
+            state_filter.append(gtk::BoolFilter::new(Some(
+                model::Network::this_expression("container-list")
+                    .chain_property::<model::SimpleContainerList>("len")
+                    .chain_closure::<bool>(closure!(|_: model::Network, len: u32| len > 0)),
+            )));
+
+            let filter = gtk::EveryFilter::new();
+            filter.append(search_filter);
+            filter.append(state_filter);
+
+            let sorter = gtk::StringSorter::new(Some(
+                model::Network::this_expression("inner").chain_closure::<String>(closure!(
+                    |_: model::Network, inner: model::BoxedNetwork| inner
+                        .name
+                        .clone()
+                        .unwrap_or_default()
+                )),
+            ));
+
+            self.filter.set(filter.upcast()).unwrap();
+            self.sorter.set(sorter.upcast()).unwrap();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for NetworksPanel {}
+
+    #[gtk::template_callbacks]
+    impl NetworksPanel {
+        #[template_callback]
+        fn on_notify_show_only_used_networks(&self) {
+            self.update_filter(if self.obj().show_only_used_networks() {
+                gtk::FilterChange::MoreStrict
+            } else {
+                gtk::FilterChange::LessStrict
+            });
+        }
+
+        #[template_callback]
+        fn on_notify_search_mode_enabled(&self) {
+            if self.search_bar.is_search_mode() {
+                self.search_entry.grab_focus();
+            } else {
+                self.search_entry.set_text("");
+            }
+        }
+
+        #[template_callback]
+        fn on_search_changed(&self) {
+            let term = self.search_entry.text().trim().to_lowercase();
+
+            let filter_change = if self.search_term.borrow().contains(&term) {
+                gtk::FilterChange::LessStrict
+            } else {
+                gtk::FilterChange::MoreStrict
+            };
+
+            self.search_term.replace(term);
+            self.update_filter(filter_change);
+        }
+
+        pub(super) fn set_network_list(&self, value: Option<&model::NetworkList>) {
+            let obj = &*self.obj();
+            if obj.network_list().as_ref() == value {
+                return;
+            }
+
+            obj.action_set_enabled(ACTION_DELETE_SELECTION, false);
+
+            if let Some(network_list) = value {
+                network_list.connect_notify_local(
+                    Some("num-selected"),
+                    clone!(@weak obj => move |list, _| {
+                        obj.action_set_enabled(ACTION_DELETE_SELECTION, list.num_selected() > 0);
+                    }),
+                );
+
+                network_list.connect_notify_local(
+                    Some("used"),
+                    clone!(@weak obj => move |_, _| {
+                        obj.imp().update_filter(gtk::FilterChange::Different);
+                    }),
+                );
+
+                let model = gtk::SortListModel::new(
+                    Some(gtk::FilterListModel::new(
+                        Some(network_list.to_owned()),
+                        self.filter.get().cloned(),
+                    )),
+                    self.sorter.get().cloned(),
+                );
+
+                self.list_box.bind_model(Some(&model), |item| {
+                    view::NetworkRow::from(item.downcast_ref().unwrap()).upcast()
+                });
+
+                self.filter_stack
+                    .set_visible_child_name(if model.n_items() > 0 { "list" } else { "empty" });
+                model.connect_items_changed(clone!(@weak obj => move |model, _, removed, _| {
+                    obj.imp()
+                        .filter_stack
+                        .set_visible_child_name(if model.n_items() > 0 { "list" } else { "empty" });
+
+                    if removed > 0 {
+                        obj.deselect_hidden_networks(model.upcast_ref());
+                    }
+                }));
+            }
+
+            self.network_list.set(value);
+            obj.notify("network-list");
+        }
+
+        fn update_filter(&self, filter_change: gtk::FilterChange) {
+            if let Some(filter) = self.filter.get() {
+                filter.changed(filter_change);
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct NetworksPanel(ObjectSubclass<imp::NetworksPanel>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for NetworksPanel {
+    fn default() -> Self {
+        glib::Object::builder().build()
+    }
+}
+
+impl NetworksPanel {
+    pub(crate) fn set_search_mode(&self, value: bool) {
+        self.imp().search_bar.set_search_mode(value);
+    }
+
+    pub(crate) fn toggle_search_mode(&self) {
+        self.set_search_mode(!self.imp().search_bar.is_search_mode());
+    }
+
+    pub(crate) fn create_network(&self) {
+        if let Some(client) = self
+            .network_list()
+            .as_ref()
+            .and_then(model::NetworkList::client)
+        {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::NetworkCreationPage::from(&client).upcast_ref(),
+            )
+            .present();
+        }
+    }
+
+    pub(crate) fn show_prune_page(&self) {
+        if let Some(client) = self.network_list().and_then(|list| list.client()) {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::NetworksPrunePage::from(&client).upcast_ref(),
+            )
+            .follows_content_size(true)
+            .present();
+        }
+    }
+
+    pub(crate) fn enter_selection_mode(&self) {
+        if let Some(list) = self.network_list().filter(|list| list.len() > 0) {
+            list.select_none();
+            list.set_selection_mode(true);
+        }
+    }
+
+    pub(crate) fn exit_selection_mode(&self) {
+        if let Some(list) = self.network_list() {
+            list.set_selection_mode(false);
+        }
+    }
+
+    pub(crate) fn select_visible(&self) {
+        (0..)
+            .map(|pos| self.imp().list_box.row_at_index(pos))
+            .take_while(Option::is_some)
+            .flatten()
+            .for_each(|row| {
+                row.downcast_ref::<view::NetworkRow>()
+                    .unwrap()
+                    .network()
+                    .unwrap()
+                    .set_selected(row.is_visible());
+            });
+    }
+
+    pub(crate) fn select_none(&self) {
+        if let Some(list) = self.network_list().filter(|list| list.is_selection_mode()) {
+            list.select_none();
+        }
+    }
+
+    pub(crate) fn delete_selection(&self) {
+        if self
+            .network_list()
+            .map(|list| list.num_selected())
+            .unwrap_or(0)
+            == 0
+        {
+            return;
+        }
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Confirm Forced Deletion of Multiple Networks"))
+            .body(gettext(
+                "There may be containers connected to some of the networks, which will also be removed!",
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("delete", &gettext("_Delete")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+        dialog.connect_response(
+            None,
+            clone!(@weak self as obj => move |_, response| if response == "delete" {
+                if let Some(list) = obj.network_list() {
+                    list
+                        .selected_items()
+                        .iter()
+                        .map(|obj| obj.downcast_ref::<model::Network>().unwrap())
+                        .filter(|network| !network.is_default())
+                        .for_each(|network|
+                    {
+                        network.delete(true, clone!(@weak obj => move |network, result| {
+                            if let Err(e) = result {
+                                utils::show_error_toast(
+                                    obj.upcast_ref(),
+                                    &gettext!(
+                                        "Error on deleting network '{}'",
+                                        network.name()
+                                    ),
+                                    &e.to_string()
+                                );
+                            }
+                        }));
+                    });
+                    list.set_selection_mode(false);
+                }
+            }),
+        );
+
+        dialog.present(Some(self));
+    }
+
+    fn deselect_hidden_networks(&self, model: &gio::ListModel) {
+        let visible_networks = model
+            .iter::<glib::Object>()
+            .map(Result::unwrap)
+            .map(|item| item.downcast::<model::Network>().unwrap())
+            .collect::<Vec<_>>();
+
+        self.network_list()
+            .unwrap()
+            .iter::<model::Network>()
+            .map(Result::unwrap)
+            .filter(model::Network::selected)
+            .for_each(|network| {
+                if !visible_networks.contains(&network) {
+                    network.set_selected(false);
+                }
+            });
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use adw::prelude::*;
 use gettextrs::gettext;
 use glib::clone;
@@ -82,6 +84,113 @@ pub(crate) fn show_delete_confirmation_dialog(widget: &gtk::Widget) {
     }
 }
 
+pub(crate) fn show_tear_down_confirmation_dialog(widget: &gtk::Widget) {
+    if let Some(pod) =
+        <gtk::Widget as gtk::prelude::ObjectExt>::property::<Option<model::Pod>>(widget, "pod")
+    {
+        if let Some(project) = pod.compose_project() {
+            // Volumes may hold data that outlives the stack, so they are only deleted on request.
+            let delete_volumes_button = gtk::CheckButton::builder()
+                .label(gettext("Also Delete _Volumes"))
+                .use_underline(true)
+                .build();
+
+            let dialog = adw::AlertDialog::builder()
+                .heading(gettext("Tear Down Compose Stack?"))
+                .body_use_markup(true)
+                .body(gettext!(
+                    // Translators: The "{}" is a placeholder for the compose project name.
+                    "All containers of stack <b>{}</b> will be deleted, along with the networks that were created for it.",
+                    project
+                ))
+                .extra_child(&delete_volumes_button)
+                .build();
+
+            dialog.add_responses(&[
+                ("cancel", &gettext("_Cancel")),
+                ("tear-down", &gettext("_Tear Down")),
+            ]);
+            dialog.set_default_response(Some("cancel"));
+            dialog.set_response_appearance("tear-down", adw::ResponseAppearance::Destructive);
+
+            dialog.choose(
+                widget,
+                gio::Cancellable::NONE,
+                clone!(@weak widget, @weak pod => move |response| {
+                    if response == "tear-down" {
+                        tear_down(&widget, &pod, project, delete_volumes_button.is_active());
+                    }
+                }),
+            );
+        }
+    }
+}
+
+fn tear_down(widget: &gtk::Widget, pod: &model::Pod, project: String, delete_volumes: bool) {
+    let client = match pod.pod_list().and_then(|pod_list| pod_list.client()) {
+        Some(client) => client,
+        None => return,
+    };
+
+    pod.delete(
+        true,
+        clone!(@weak widget, @weak client => move |result| match result {
+            Ok(_) => {
+                let is_part_of_project = |labels: Option<&HashMap<String, String>>| {
+                    labels
+                        .and_then(|labels| labels.get(model::COMPOSE_PROJECT_LABEL))
+                        .is_some_and(|label| label == &project)
+                };
+
+                if delete_volumes {
+                    client
+                        .volume_list()
+                        .iter::<model::Volume>()
+                        .map(Result::unwrap)
+                        .filter(|volume| is_part_of_project(Some(&volume.inner().labels)))
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .for_each(|volume| {
+                            volume.delete(false, clone!(@weak widget => move |_, result| {
+                                if let Err(e) = result {
+                                    utils::show_error_toast(
+                                        &widget,
+                                        &gettext("Error on deleting volume"),
+                                        &e.to_string(),
+                                    );
+                                }
+                            }));
+                        });
+                }
+
+                client
+                    .network_list()
+                    .iter::<model::Network>()
+                    .map(Result::unwrap)
+                    .filter(|network| is_part_of_project(network.inner().labels.as_ref()))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .for_each(|network| {
+                        network.delete(false, clone!(@weak widget => move |_, result| {
+                            if let Err(e) = result {
+                                utils::show_error_toast(
+                                    &widget,
+                                    &gettext("Error on deleting network"),
+                                    &e.to_string(),
+                                );
+                            }
+                        }));
+                    });
+            }
+            Err(e) => utils::show_error_toast(
+                &widget,
+                &gettext("Error on tearing down compose stack"),
+                &e.to_string(),
+            ),
+        }),
+    );
+}
+
 pub(crate) fn create_container(widget: &gtk::Widget, pod: Option<model::Pod>) {
     if let Some(pod) = pod {
         utils::Dialog::new(widget, view::ContainerCreationPage::from(&pod).upcast_ref()).present();
@@ -22,6 +22,7 @@ const ACTION_ADD_LABEL: &str = "pod-creation-page.add-label";
 const ACTION_ADD_HOST: &str = "pod-creation-page.add-host";
 const ACTION_ADD_PORT_MAPPING: &str = "pod-creation-page.add-port-mapping";
 const ACTION_ADD_DEVICE: &str = "pod-creation-page.add-device";
+const ACTION_ADD_NETWORK: &str = "pod-creation-page.add-network";
 const ACTION_ADD_INFRA_CMD_ARGS: &str = "pod-creation-page.add-infra-cmd-arg";
 const ACTION_ADD_POD_CREATE_CMD_ARGS: &str = "pod-creation-page.add-pod-create-cmd-arg";
 
@@ -36,6 +37,7 @@ mod imp {
         pub(super) hosts: OnceCell<gio::ListStore>,
         pub(super) port_mappings: OnceCell<gio::ListStore>,
         pub(super) devices: OnceCell<gio::ListStore>,
+        pub(super) network_attachments: OnceCell<gio::ListStore>,
         pub(super) pod_create_cmd_args: OnceCell<gio::ListStore>,
         pub(super) infra_cmd_args: OnceCell<gio::ListStore>,
         pub(super) command_row_handler:
@@ -67,6 +69,14 @@ mod imp {
         #[template_child]
         pub(super) devices_list_box: TemplateChild<gtk::ListBox>,
         #[template_child]
+        pub(super) network_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) dns_servers_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) dns_search_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) dns_options_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
         pub(super) enable_hosts_switch: TemplateChild<gtk::Switch>,
         #[template_child]
         pub(super) disable_resolv_switch_row: TemplateChild<adw::SwitchRow>,
@@ -113,6 +123,9 @@ mod imp {
             klass.install_action(ACTION_ADD_DEVICE, None, |widget, _, _| {
                 widget.add_device();
             });
+            klass.install_action(ACTION_ADD_NETWORK, None, |widget, _, _| {
+                widget.add_network_attachment();
+            });
             klass.install_action(ACTION_ADD_POD_CREATE_CMD_ARGS, None, |widget, _, _| {
                 widget.add_pod_create_cmd_arg();
             });
@@ -190,6 +203,19 @@ mod imp {
                 &gettext("Add Device"),
             );
 
+            bind_model(
+                &self.network_list_box,
+                self.network_attachments(),
+                |item| {
+                    view::NetworkAttachmentRow::from(
+                        item.downcast_ref::<model::NetworkAttachment>().unwrap(),
+                    )
+                    .upcast()
+                },
+                ACTION_ADD_NETWORK,
+                &gettext("Add Network"),
+            );
+
             bind_model(
                 &self.pod_create_command_arg_list_box,
                 self.pod_create_cmd_args(),
@@ -302,6 +328,11 @@ mod imp {
                 .get_or_init(gio::ListStore::new::<model::Device>)
         }
 
+        pub(super) fn network_attachments(&self) -> &gio::ListStore {
+            self.network_attachments
+                .get_or_init(gio::ListStore::new::<model::NetworkAttachment>)
+        }
+
         pub(super) fn pod_create_cmd_args(&self) -> &gio::ListStore {
             self.pod_create_cmd_args
                 .get_or_init(gio::ListStore::new::<model::Value>)
@@ -354,6 +385,12 @@ impl PodCreationPage {
         add_device(self.imp().devices());
     }
 
+    fn add_network_attachment(&self) {
+        if let Some(ref client) = self.client() {
+            add_network_attachment(self.imp().network_attachments(), client);
+        }
+    }
+
     fn add_pod_create_cmd_arg(&self) {
         add_value(self.imp().pod_create_cmd_args());
     }
@@ -469,7 +506,16 @@ impl PodCreationPage {
                         protocol: Some(port_mapping.protocol().to_string()),
                         range: None,
                     }),
-            );
+            )
+            .networks(
+                imp.network_attachments()
+                    .iter::<model::NetworkAttachment>()
+                    .map(Result::unwrap)
+                    .filter_map(|attachment| attachment.per_network_options()),
+            )
+            .dns_server(utils::split_list(&imp.dns_servers_entry_row.text()))
+            .dns_search(utils::split_list(&imp.dns_search_entry_row.text()))
+            .dns_option(utils::split_list(&imp.dns_options_entry_row.text()));
 
         if imp.disable_infra_switch_row.is_active() {
             opts = opts.no_infra(true);
@@ -640,6 +686,18 @@ fn add_port_mapping(model: &gio::ListStore) -> model::PortMapping {
     port_mapping
 }
 
+fn add_network_attachment(model: &gio::ListStore, client: &model::Client) {
+    let network_attachment = model::NetworkAttachment::from(client);
+
+    network_attachment.connect_remove_request(clone!(@weak model => move |network_attachment| {
+        if let Some(pos) = model.find(network_attachment) {
+            model.remove(pos);
+        }
+    }));
+
+    model.append(&network_attachment);
+}
+
 fn add_device(model: &gio::ListStore) {
     let device = model::Device::default();
 
@@ -24,7 +24,12 @@ const ACTION_RESUME: &str = "pod-details-page.resume";
 const ACTION_DELETE: &str = "pod-details-page.delete";
 const ACTION_INSPECT_POD: &str = "pod-details-page.inspect-pod";
 const ACTION_GENERATE_KUBE: &str = "pod-details-page.generate-kube";
+const ACTION_GENERATE_QUADLET: &str = "pod-details-page.generate-quadlet";
+const ACTION_GENERATE_SYSTEMD: &str = "pod-details-page.generate-systemd";
+const ACTION_SHOW_LOG: &str = "pod-details-page.show-log";
 const ACTION_SHOW_PROCESSES: &str = "pod-details-page.show-processes";
+const ACTION_SHOW_ALERTS: &str = "pod-details-page.show-alerts";
+const ACTION_COPY_LINK: &str = "pod-details-page.copy-link";
 
 mod imp {
     use super::*;
@@ -67,6 +72,10 @@ mod imp {
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
 
+            klass.install_action(ACTION_COPY_LINK, None, |widget, _, _| {
+                widget.copy_link();
+            });
+
             klass.install_action(ACTION_START_OR_RESUME, None, |widget, _, _| {
                 if widget.pod().map(|pod| pod.can_start()).unwrap_or(false) {
                     view::pod::start(widget.upcast_ref());
@@ -99,9 +108,21 @@ mod imp {
             klass.install_action(ACTION_GENERATE_KUBE, None, |widget, _, _| {
                 widget.show_kube();
             });
+            klass.install_action(ACTION_GENERATE_QUADLET, None, |widget, _, _| {
+                widget.show_quadlet();
+            });
+            klass.install_action(ACTION_GENERATE_SYSTEMD, None, |widget, _, _| {
+                widget.show_systemd();
+            });
+            klass.install_action(ACTION_SHOW_LOG, None, |widget, _, _| {
+                widget.show_log();
+            });
             klass.install_action(ACTION_SHOW_PROCESSES, None, |widget, _, _| {
                 widget.show_processes();
             });
+            klass.install_action(ACTION_SHOW_ALERTS, None, |widget, _, _| {
+                widget.show_alerts();
+            });
 
             // For displaying a mnemonic.
             klass.add_binding_action(
@@ -254,6 +275,25 @@ impl From<&model::Pod> for PodDetailsPage {
 }
 
 impl PodDetailsPage {
+    fn copy_link(&self) {
+        if let Some((pod, client)) = self.pod().and_then(|pod| {
+            pod.pod_list()
+                .and_then(|list| list.client())
+                .map(|client| (pod, client))
+        }) {
+            utils::copy_to_clipboard(
+                self.upcast_ref(),
+                &model::DeepLink::new(
+                    &client.connection().name(),
+                    model::DeepLinkKind::Pod,
+                    &pod.id(),
+                )
+                .to_string(),
+                &gettext("Link copied"),
+            );
+        }
+    }
+
     fn update_actions(&self) {
         if let Some(pod) = self.pod() {
             let imp = self.imp();
@@ -289,6 +329,14 @@ impl PodDetailsPage {
         self.show_kube_inspection_or_kube(view::ScalableTextViewMode::Kube);
     }
 
+    fn show_quadlet(&self) {
+        self.show_kube_inspection_or_kube(view::ScalableTextViewMode::Quadlet);
+    }
+
+    fn show_systemd(&self) {
+        self.show_kube_inspection_or_kube(view::ScalableTextViewMode::Systemd);
+    }
+
     fn show_kube_inspection_or_kube(&self, mode: view::ScalableTextViewMode) {
         self.exec_action(|| {
             if let Some(pod) = self.pod() {
@@ -307,7 +355,19 @@ impl PodDetailsPage {
         });
     }
 
-    fn show_processes(&self) {
+    pub(crate) fn show_log(&self) {
+        self.exec_action(|| {
+            if let Some(pod) = self.pod() {
+                utils::navigation_view(self.upcast_ref()).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::MultiContainerLogPage::from(&pod))
+                        .build(),
+                );
+            }
+        });
+    }
+
+    pub(crate) fn show_processes(&self) {
         self.exec_action(|| {
             if let Some(pod) = self.pod() {
                 utils::navigation_view(self.upcast_ref()).push(
@@ -319,6 +379,25 @@ impl PodDetailsPage {
         });
     }
 
+    fn show_alerts(&self) {
+        if let Some((pod, client)) = self.pod().and_then(|pod| {
+            pod.pod_list()
+                .and_then(|list| list.client())
+                .map(|client| (pod, client))
+        }) {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::AlertRulesPage::new(
+                    &client.alert_monitor(),
+                    model::AlertSubject::Pod,
+                    &pod.name(),
+                )
+                .upcast_ref(),
+            )
+            .present();
+        }
+    }
+
     fn create_container(&self) {
         self.exec_action(|| {
             view::pod::create_container(self.upcast_ref(), self.pod());
@@ -18,6 +18,7 @@ const ACTION_RESTART: &str = "pod-menu-button.restart";
 const ACTION_PAUSE: &str = "pod-menu-button.pause";
 const ACTION_RESUME: &str = "pod-menu-button.resume";
 const ACTION_DELETE: &str = "pod-menu-button.delete";
+const ACTION_TEAR_DOWN: &str = "pod-menu-button.tear-down";
 
 mod imp {
     use super::*;
@@ -67,6 +68,9 @@ mod imp {
             klass.install_action(ACTION_DELETE, None, |widget, _, _| {
                 view::pod::show_delete_confirmation_dialog(widget.upcast_ref());
             });
+            klass.install_action(ACTION_TEAR_DOWN, None, |widget, _, _| {
+                view::pod::show_tear_down_confirmation_dialog(widget.upcast_ref());
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -142,6 +146,7 @@ impl PodMenuButton {
             self.action_set_enabled(ACTION_RESUME, pod.can_resume());
             self.action_set_enabled(ACTION_PAUSE, pod.can_pause());
             self.action_set_enabled(ACTION_DELETE, pod.can_delete());
+            self.action_set_enabled(ACTION_TEAR_DOWN, pod.compose_project().is_some());
         }
     }
 }
@@ -20,6 +20,8 @@ use crate::utils;
 use crate::view;
 
 const ACTION_CREATE_POD: &str = "pods-panel.create-pod";
+const ACTION_PLAY_KUBE: &str = "pods-panel.play-kube";
+const ACTION_COMPOSE_UP: &str = "pods-panel.compose-up";
 const ACTION_PRUNE_PODS: &str = "pods-panel.prune-pods";
 const ACTION_ENTER_SELECTION_MODE: &str = "pods-panel.enter-selection-mode";
 
@@ -96,6 +98,14 @@ mod imp {
                 widget.create_pod();
             });
 
+            klass.install_action(ACTION_PLAY_KUBE, None, |widget, _, _| {
+                widget.play_kube();
+            });
+
+            klass.install_action(ACTION_COMPOSE_UP, None, |widget, _, _| {
+                widget.compose_up();
+            });
+
             klass.install_action(ACTION_PRUNE_PODS, None, |widget, _, _| {
                 widget.prune_pods();
             });
@@ -421,6 +431,26 @@ impl PodsPanel {
         }
     }
 
+    pub(crate) fn play_kube(&self) {
+        if let Some(client) = self.pod_list().as_ref().and_then(model::PodList::client) {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::KubePlayPage::from(&client).upcast_ref(),
+            )
+            .present();
+        }
+    }
+
+    pub(crate) fn compose_up(&self) {
+        if let Some(client) = self.pod_list().as_ref().and_then(model::PodList::client) {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::ComposeUpPage::from(&client).upcast_ref(),
+            )
+            .present();
+        }
+    }
+
     pub(crate) fn prune_pods(&self) {
         if let Some(client) = self.pod_list().and_then(|pod_list| pod_list.client()) {
             utils::Dialog::new(
@@ -0,0 +1,270 @@
+use std::cell::RefCell;
+use std::time::Duration;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::widget;
+
+/// The selectable time spans in seconds.
+const DURATIONS: &[u32] = &[5 * 60, 15 * 60, 60 * 60, 6 * 60 * 60, 24 * 60 * 60];
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ResourcesHistory)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/resources_history.ui")]
+    pub(crate) struct ResourcesHistory {
+        pub(super) settings: utils::PodsSettings,
+        pub(super) timeout: RefCell<Option<glib::SourceId>>,
+        #[property(get, set = Self::set_container, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[property(get, set = Self::set_pod, nullable)]
+        pub(super) pod: glib::WeakRef<model::Pod>,
+        #[template_child]
+        pub(super) duration_drop_down: TemplateChild<gtk::DropDown>,
+        #[template_child]
+        pub(super) cpu_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) cpu_chart: TemplateChild<widget::StatsChart>,
+        #[template_child]
+        pub(super) memory_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) memory_chart: TemplateChild<widget::StatsChart>,
+        #[template_child]
+        pub(super) network_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) network_chart: TemplateChild<widget::StatsChart>,
+        #[template_child]
+        pub(super) block_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) block_chart: TemplateChild<widget::StatsChart>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ResourcesHistory {
+        const NAME: &'static str = "PdsResourcesHistory";
+        type Type = super::ResourcesHistory;
+        type ParentType = adw::PreferencesGroup;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ResourcesHistory {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.settings
+                .bind(
+                    "stats-history-duration",
+                    &*self.duration_drop_down,
+                    "selected",
+                )
+                .mapping(|variant, _| {
+                    variant.get::<u32>().map(|duration| {
+                        (DURATIONS
+                            .iter()
+                            .position(|d| *d >= duration)
+                            .unwrap_or(DURATIONS.len() - 1) as u32)
+                            .to_value()
+                    })
+                })
+                .set_mapping(|value, _| {
+                    value
+                        .get::<u32>()
+                        .ok()
+                        .and_then(|position| DURATIONS.get(position as usize))
+                        .map(|duration| duration.to_variant())
+                })
+                .build();
+
+            self.settings.connect_changed(
+                Some("stats-history-duration"),
+                clone!(@weak obj => move |_, _| obj.update()),
+            );
+        }
+
+        fn dispose(&self) {
+            if let Some(source_id) = self.timeout.take() {
+                source_id.remove();
+            }
+        }
+    }
+
+    impl WidgetImpl for ResourcesHistory {
+        fn map(&self) {
+            self.parent_map();
+
+            let obj = &*self.obj();
+            obj.update();
+
+            self.timeout.replace(Some(glib::timeout_add_local(
+                Duration::from_secs(1),
+                clone!(@weak obj => @default-return glib::ControlFlow::Break, move || {
+                    obj.update();
+                    glib::ControlFlow::Continue
+                }),
+            )));
+        }
+
+        fn unmap(&self) {
+            if let Some(source_id) = self.timeout.take() {
+                source_id.remove();
+            }
+            self.parent_unmap();
+        }
+    }
+
+    impl PreferencesGroupImpl for ResourcesHistory {}
+
+    impl ResourcesHistory {
+        pub(super) fn set_container(&self, value: Option<&model::Container>) {
+            let obj = &*self.obj();
+            if obj.container().as_ref() == value {
+                return;
+            }
+            self.container.set(value);
+            obj.update();
+            obj.notify_container();
+        }
+
+        pub(super) fn set_pod(&self, value: Option<&model::Pod>) {
+            let obj = &*self.obj();
+            if obj.pod().as_ref() == value {
+                return;
+            }
+            self.pod.set(value);
+            obj.update();
+            obj.notify_pod();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ResourcesHistory(ObjectSubclass<imp::ResourcesHistory>)
+        @extends gtk::Widget, adw::PreferencesGroup,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl ResourcesHistory {
+    /// Redraws the charts. A pod shows the sum of the resources used by its containers.
+    fn update(&self) {
+        let imp = self.imp();
+
+        let duration = imp.settings.uint("stats-history-duration");
+
+        let points = if let Some(container) = self.container() {
+            container.stats_points()
+        } else if let Some(pod) = self.pod() {
+            model::StatsHistory::aggregate(
+                duration,
+                pod.container_list()
+                    .iter::<model::Container>()
+                    .map(Result::unwrap)
+                    .map(|container| container.stats_points()),
+            )
+        } else {
+            Vec::new()
+        };
+
+        let end = glib::real_time() / 1_000_000;
+        let range = (end - duration as i64, end);
+        // Allow a few missing samples before the line is interrupted.
+        let gap = model::StatsHistory::bucket_size(duration) * 3;
+
+        let series = |value: fn(&model::StatsPoint) -> f64| {
+            points
+                .iter()
+                .map(|point| (point.time, value(point)))
+                .collect::<Vec<_>>()
+        };
+        let peak =
+            |value: fn(&model::StatsPoint) -> f64| points.iter().map(value).fold(0.0, f64::max);
+        // Only show current values while samples are still coming in.
+        let last = points.last().filter(|point| end - point.time <= gap);
+
+        imp.cpu_chart
+            .set_values(range, gap, series(|point| point.cpu), Vec::new());
+        imp.cpu_label.set_label(&match last {
+            Some(last) => gettext!(
+                // Translators: For example 5.0 % (peak 80.1 %).
+                "{} % (peak {} %)",
+                format!("{:.1}", last.cpu),
+                format!("{:.1}", peak(|point| point.cpu)),
+            ),
+            None => gettext("?"),
+        });
+
+        imp.memory_chart
+            .set_values(range, gap, series(|point| point.mem_usage), Vec::new());
+        imp.memory_label.set_label(&match last {
+            Some(last) => gettext!(
+                // Translators: For example 50 MB (peak 80 MB).
+                "{} (peak {})",
+                glib::format_size(last.mem_usage as u64),
+                glib::format_size(peak(|point| point.mem_usage) as u64),
+            ),
+            None => gettext("?"),
+        });
+
+        imp.network_chart.set_values(
+            range,
+            gap,
+            series(|point| point.net_input),
+            series(|point| point.net_output),
+        );
+        imp.network_label.set_label(&match last {
+            Some(last) => gettext!(
+                // Translators: For example ↓ 5 MB / s ↑ 1 MB / s.
+                "↓ {} / s ↑ {} / s",
+                glib::format_size(last.net_input as u64),
+                glib::format_size(last.net_output as u64),
+            ),
+            None => gettext("?"),
+        });
+
+        imp.block_chart.set_values(
+            range,
+            gap,
+            series(|point| point.block_input),
+            series(|point| point.block_output),
+        );
+        imp.block_label.set_label(&match last {
+            Some(last) => gettext!(
+                // Translators: For example ↓ 5 MB / s ↑ 1 MB / s.
+                "↓ {} / s ↑ {} / s",
+                glib::format_size(last.block_input as u64),
+                glib::format_size(last.block_output as u64),
+            ),
+            None => gettext("?"),
+        });
+    }
+}
@@ -1,10 +1,13 @@
 use std::cell::OnceCell;
+use std::cell::RefCell;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::OpenFileRequest;
 use ashpd::desktop::file_chooser::SaveFileRequest;
 use ashpd::WindowIdentifier;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use gtk::gdk;
 use gtk::gio;
 use gtk::glib;
@@ -13,12 +16,14 @@ use gtk::CompositeTemplate;
 use sourceview5::prelude::*;
 
 use crate::model;
+use crate::podman;
 use crate::utils;
 use crate::widget;
 
 const ACTION_TOGGLE_SEARCH: &str = "source-view-page.toggle-search";
 const ACTION_EXIT_SEARCH: &str = "source-view-page.exit-search";
 const ACTION_SAVE_TO_FILE: &str = "source-view-page.save-to-file";
+const ACTION_INSTALL: &str = "source-view-page.install";
 const ACTION_ZOOM_OUT: &str = "source-view-page.zoom-out";
 const ACTION_ZOOM_IN: &str = "source-view-page.zoom-in";
 const ACTION_ZOOM_NORMAL: &str = "source-view-page.zoom-normal";
@@ -35,6 +40,8 @@ pub(crate) enum Entity {
         mode: Mode,
     },
     Volume(glib::WeakRef<model::Volume>),
+    Network(glib::WeakRef<model::Network>),
+    Secret(glib::WeakRef<model::Secret>),
 }
 impl Entity {
     fn filename(&self) -> String {
@@ -48,11 +55,20 @@ impl Entity {
                 )
             }
             Self::Pod { pod, mode } => {
-                format!("{}.{}", pod.upgrade().unwrap().name(), mode.file_ext())
+                format!(
+                    "{}.{}",
+                    pod.upgrade().unwrap().name(),
+                    match mode {
+                        Mode::Quadlet => "pod",
+                        _ => mode.file_ext(),
+                    }
+                )
             }
             Self::Volume(volume) => {
                 format!("{}.json", volume.upgrade().unwrap().inner().name)
             }
+            Self::Network(network) => format!("{}.json", network.upgrade().unwrap().name()),
+            Self::Secret(secret) => format!("{}.json", secret.upgrade().unwrap().name()),
         }
     }
 }
@@ -61,12 +77,24 @@ impl Entity {
 pub(crate) enum Mode {
     Inspect,
     Kube,
+    Quadlet,
+    Systemd,
 }
 impl Mode {
     fn file_ext(&self) -> &str {
         match self {
             Self::Inspect => "json",
             Self::Kube => "yaml",
+            Self::Quadlet => "container",
+            Self::Systemd => "service",
+        }
+    }
+
+    fn language(&self) -> &str {
+        match self {
+            Self::Inspect => "json",
+            Self::Kube => "yaml",
+            Self::Quadlet | Self::Systemd => "ini",
         }
     }
 }
@@ -78,6 +106,7 @@ mod imp {
     #[template(resource = "/com/github/marhkb/Pods/ui/view/scalable_text_view_page.ui")]
     pub(crate) struct ScalableTextViewPage {
         pub(super) entity: OnceCell<Entity>,
+        pub(super) units: RefCell<Vec<model::SystemdUnit>>,
         #[template_child]
         pub(super) zoom_control: TemplateChild<widget::ZoomControl>,
         #[template_child]
@@ -130,6 +159,10 @@ mod imp {
                 widget.save_to_file().await;
             });
 
+            klass.install_action(ACTION_INSTALL, None, |widget, _, _| {
+                widget.install();
+            });
+
             klass.install_action(ACTION_ZOOM_OUT, None, |widget, _, _| {
                 widget.imp().source_view.zoom_out();
             });
@@ -185,6 +218,8 @@ mod imp {
 
             let obj = &*self.obj();
 
+            obj.action_set_enabled(ACTION_INSTALL, false);
+
             self.menu_button
                 .popover()
                 .unwrap()
@@ -273,6 +308,8 @@ impl From<Entity> for ScalableTextViewPage {
                 imp.window_title.set_title(&match mode {
                     Mode::Inspect => gettext("Container Inspection"),
                     Mode::Kube => gettext("Container Kube Generation"),
+                    Mode::Quadlet => gettext("Container Quadlet Generation"),
+                    Mode::Systemd => gettext("Container Systemd Unit Generation"),
                 });
                 model::Container::this_expression("name").bind(
                     &*imp.window_title,
@@ -284,6 +321,8 @@ impl From<Entity> for ScalableTextViewPage {
                 imp.window_title.set_title(&match mode {
                     Mode::Inspect => gettext("Pod Inspection"),
                     Mode::Kube => gettext("Pod Kube Generation"),
+                    Mode::Quadlet => gettext("Pod Quadlet Generation"),
+                    Mode::Systemd => gettext("Pod Systemd Unit Generation"),
                 });
                 if let Some(pod) = pod.upgrade() {
                     imp.window_title.set_subtitle(&pod.name());
@@ -296,18 +335,23 @@ impl From<Entity> for ScalableTextViewPage {
                         .set_subtitle(&utils::format_volume_name(&volume.inner().name));
                 }
             }
+            Entity::Network(network) => {
+                imp.window_title.set_title(&gettext("Network Inspection"));
+                if let Some(network) = network.upgrade() {
+                    imp.window_title.set_subtitle(&network.name());
+                }
+            }
+            Entity::Secret(secret) => {
+                imp.window_title.set_title(&gettext("Secret Inspection"));
+                if let Some(secret) = secret.upgrade() {
+                    imp.window_title.set_subtitle(&secret.name());
+                }
+            }
         }
 
         let language = match &entity {
-            Entity::Image(_) | Entity::Volume(_) => "json",
-            Entity::Container { mode, .. } => match mode {
-                Mode::Inspect => "json",
-                Mode::Kube => "yaml",
-            },
-            Entity::Pod { mode, .. } => match mode {
-                Mode::Inspect => "json",
-                Mode::Kube => "yaml",
-            },
+            Entity::Image(_) | Entity::Volume(_) | Entity::Network(_) | Entity::Secret(_) => "json",
+            Entity::Container { mode, .. } | Entity::Pod { mode, .. } => mode.language(),
         };
 
         match sourceview5::LanguageManager::default().language(language) {
@@ -338,48 +382,122 @@ impl From<Entity> for ScalableTextViewPage {
                 );
             }
             Entity::Container { container, mode } => {
-                let api = container.upgrade().unwrap().api().unwrap();
-
-                utils::do_async(
-                    async move {
-                        match mode {
-                            Mode::Inspect => api
-                                .inspect()
-                                .await
-                                .map_err(anyhow::Error::from)
-                                .and_then(|data| {
-                                    serde_json::to_string_pretty(&data).map_err(anyhow::Error::from)
-                                }),
-                            Mode::Kube => api
-                                .generate_kube_yaml(false)
-                                .await
-                                .map_err(anyhow::Error::from),
-                        }
-                    },
-                    clone!(@weak obj => move |result| obj.init(result, mode)),
-                );
+                let container = container.upgrade().unwrap();
+                let api = container.api().unwrap();
+
+                match mode {
+                    Mode::Inspect | Mode::Kube => utils::do_async(
+                        async move {
+                            match mode {
+                                Mode::Kube => api
+                                    .generate_kube_yaml(false)
+                                    .await
+                                    .map_err(anyhow::Error::from),
+                                _ => api.inspect().await.map_err(anyhow::Error::from).and_then(
+                                    |data| {
+                                        serde_json::to_string_pretty(&data)
+                                            .map_err(anyhow::Error::from)
+                                    },
+                                ),
+                            }
+                        },
+                        clone!(@weak obj => move |result| obj.init(result, mode)),
+                    ),
+                    Mode::Quadlet | Mode::Systemd => {
+                        let pod_name = container.pod().map(|pod| pod.name());
+
+                        utils::do_async(
+                            async move {
+                                match mode {
+                                    Mode::Systemd => api
+                                        .generate_systemd_units(&systemd_units_opts())
+                                        .await
+                                        .map(|units| model::SystemdUnit::from_generated(&units))
+                                        .map_err(anyhow::Error::from),
+                                    _ => api
+                                        .inspect()
+                                        .await
+                                        .map_err(anyhow::Error::from)
+                                        .and_then(|data| {
+                                            serde_json::to_value(&data).map_err(anyhow::Error::from)
+                                        })
+                                        .map(|data| {
+                                            vec![model::SystemdUnit::quadlet_container(
+                                                &data,
+                                                pod_name.as_deref(),
+                                            )]
+                                        }),
+                                }
+                            },
+                            clone!(@weak obj => move |result| obj.init_units(result, mode)),
+                        );
+                    }
+                }
             }
             Entity::Pod { pod, mode } => {
-                let api = pod.upgrade().unwrap().api().unwrap();
-
-                utils::do_async(
-                    async move {
-                        match mode {
-                            Mode::Inspect => api
-                                .inspect()
-                                .await
-                                .map_err(anyhow::Error::from)
-                                .and_then(|data| {
-                                    serde_json::to_string_pretty(&data).map_err(anyhow::Error::from)
-                                }),
-                            Mode::Kube => api
-                                .generate_kube_yaml(false)
-                                .await
-                                .map_err(anyhow::Error::from),
-                        }
-                    },
-                    clone!(@weak obj => move |result| obj.init(result, mode)),
-                );
+                let pod = pod.upgrade().unwrap();
+                let api = pod.api().unwrap();
+
+                match mode {
+                    Mode::Inspect | Mode::Kube => utils::do_async(
+                        async move {
+                            match mode {
+                                Mode::Kube => api
+                                    .generate_kube_yaml(false)
+                                    .await
+                                    .map_err(anyhow::Error::from),
+                                _ => api.inspect().await.map_err(anyhow::Error::from).and_then(
+                                    |data| {
+                                        serde_json::to_string_pretty(&data)
+                                            .map_err(anyhow::Error::from)
+                                    },
+                                ),
+                            }
+                        },
+                        clone!(@weak obj => move |result| obj.init(result, mode)),
+                    ),
+                    Mode::Quadlet | Mode::Systemd => {
+                        let pod_name = pod.name();
+                        // The infra container is created by Quadlet from the `.pod` file.
+                        let container_apis = pod
+                            .container_list()
+                            .iter::<model::Container>()
+                            .map(Result::unwrap)
+                            .filter(|container| !container.is_infra())
+                            .filter_map(|container| container.api())
+                            .collect::<Vec<_>>();
+
+                        utils::do_async(
+                            async move {
+                                match mode {
+                                    Mode::Systemd => api
+                                        .generate_systemd_units(&systemd_units_opts())
+                                        .await
+                                        .map(|units| model::SystemdUnit::from_generated(&units))
+                                        .map_err(anyhow::Error::from),
+                                    _ => {
+                                        let data = serde_json::to_value(api.inspect().await?)?;
+                                        let mut units =
+                                            vec![model::SystemdUnit::quadlet_pod(&data)];
+
+                                        for container_api in container_apis {
+                                            let data = serde_json::to_value(
+                                                container_api.inspect().await?,
+                                            )?;
+                                            units.push(model::SystemdUnit::quadlet_container(
+                                                &data,
+                                                Some(&pod_name),
+                                            ));
+                                        }
+
+                                        Ok(units)
+                                    }
+                                }
+                            },
+                            clone!(@weak obj => move |result| obj.init_units(result, mode)),
+                        );
+                    }
+                }
             }
             Entity::Volume(volume) => {
                 obj.init(
@@ -388,6 +506,20 @@ impl From<Entity> for ScalableTextViewPage {
                     Mode::Inspect,
                 );
             }
+            Entity::Network(network) => {
+                obj.init(
+                    serde_json::to_string_pretty(&*network.upgrade().unwrap().inner())
+                        .map_err(anyhow::Error::from),
+                    Mode::Inspect,
+                );
+            }
+            Entity::Secret(secret) => {
+                obj.init(
+                    serde_json::to_string_pretty(&*secret.upgrade().unwrap().inner())
+                        .map_err(anyhow::Error::from),
+                    Mode::Inspect,
+                );
+            }
         };
 
         imp.entity.set(entity).unwrap();
@@ -411,6 +543,8 @@ impl ScalableTextViewPage {
                     &match mode {
                         Mode::Inspect => gettext("Inspection error"),
                         Mode::Kube => gettext("Kube generation error"),
+                        Mode::Quadlet => gettext("Quadlet generation error"),
+                        Mode::Systemd => gettext("Systemd unit generation error"),
                     },
                     &e.to_string(),
                 );
@@ -419,9 +553,139 @@ impl ScalableTextViewPage {
         }
     }
 
+    fn init_units(&self, result: anyhow::Result<Vec<model::SystemdUnit>>, mode: Mode) {
+        let result = result.map(|units| {
+            let text = units
+                .iter()
+                .map(|unit| unit.content.trim_end())
+                .collect::<Vec<_>>()
+                .join("\n\n");
+
+            self.action_set_enabled(
+                ACTION_INSTALL,
+                !units.is_empty()
+                    && self
+                        .client()
+                        .map(|client| client.connection().is_local())
+                        .unwrap_or(false),
+            );
+            self.imp().units.replace(units);
+
+            text
+        });
+
+        self.init(result, mode);
+    }
+
+    fn client(&self) -> Option<model::Client> {
+        match self.imp().entity.get()? {
+            Entity::Container { container, .. } => container
+                .upgrade()?
+                .container_list()
+                .as_ref()
+                .and_then(model::ContainerList::client),
+            Entity::Pod { pod, .. } => pod
+                .upgrade()?
+                .pod_list()
+                .as_ref()
+                .and_then(model::PodList::client),
+            _ => None,
+        }
+    }
+
+    fn install(&self) {
+        let units = self.imp().units.borrow().clone();
+
+        let installed = units
+            .iter()
+            .filter(|unit| unit.is_installed())
+            .map(|unit| unit.file_name.as_str())
+            .collect::<Vec<_>>();
+
+        if installed.is_empty() {
+            self.install_units(units);
+            return;
+        }
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Replace Existing Units?"))
+            .body(ngettext!(
+                // Translators: The "{}" is a placeholder for a list of file names.
+                "{} is already installed and will be replaced.",
+                "{} are already installed and will be replaced.",
+                installed.len() as u32,
+                installed.join(", ")
+            ))
+            .default_response("cancel")
+            .close_response("cancel")
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("replace", &gettext("_Replace")),
+        ]);
+        dialog.set_response_appearance("replace", adw::ResponseAppearance::Destructive);
+
+        dialog.choose(
+            self,
+            gio::Cancellable::NONE,
+            clone!(@weak self as obj => move |response| if response == "replace" {
+                obj.install_units(units);
+            }),
+        );
+    }
+
+    fn install_units(&self, units: Vec<model::SystemdUnit>) {
+        let install_dir = units
+            .first()
+            .map(|unit| unit.install_dir().to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        utils::do_async(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    units.iter().try_for_each(|unit| unit.install().map(|_| ()))
+                })
+                .await
+                .map_err(anyhow::Error::from)?
+                .map_err(anyhow::Error::from)
+            },
+            clone!(@weak self as obj => move |result| {
+                if let Err(e) = result {
+                    utils::show_error_toast(
+                        obj.upcast_ref(),
+                        &gettext("Error on installing systemd units"),
+                        &e.to_string(),
+                    );
+                    return;
+                }
+
+                glib::MainContext::default().spawn_local(clone!(@weak obj => async move {
+                    match model::SystemdUnit::reload_user_units().await {
+                        Ok(_) => utils::show_toast(
+                            obj.upcast_ref(),
+                            gettext!("Installed to {}", install_dir),
+                        ),
+                        Err(e) => utils::show_error_toast(
+                            obj.upcast_ref(),
+                            &gettext("Error on reloading systemd"),
+                            &e.to_string(),
+                        ),
+                    }
+                }));
+            }),
+        );
+    }
+
     async fn save_to_file(&self) {
         let imp = self.imp();
 
+        // Every unit needs its own file to be picked up by systemd.
+        if imp.units.borrow().len() > 1 {
+            self.save_units_to_folder().await;
+            return;
+        }
+
         let request = SaveFileRequest::default()
             .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
             .current_name(imp.entity.get().unwrap().filename().as_str())
@@ -458,6 +722,45 @@ impl ScalableTextViewPage {
         .await;
     }
 
+    async fn save_units_to_folder(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Folder for Units").as_str())
+            .directory(true)
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(dir) = file.path() {
+                    let units = obj.imp().units.borrow().clone();
+
+                    utils::do_async(
+                        async move {
+                            tokio::task::spawn_blocking(move || {
+                                units.iter().try_for_each(|unit| unit.write_to(&dir).map(|_| ()))
+                            })
+                            .await
+                            .map_err(anyhow::Error::from)?
+                            .map_err(anyhow::Error::from)
+                        },
+                        clone!(@weak obj => move |result| if let Err(e) = result {
+                            utils::show_error_toast(
+                                obj.upcast_ref(),
+                                &gettext("Error"),
+                                &e.to_string(),
+                            );
+                        }),
+                    );
+                }
+            }),
+        )
+        .await;
+    }
+
     pub(crate) fn set_search_mode(&self, value: bool) {
         self.imp().search_bar.set_search_mode(value);
     }
@@ -466,3 +769,10 @@ impl ScalableTextViewPage {
         self.set_search_mode(!self.imp().search_bar.is_search_mode());
     }
 }
+
+fn systemd_units_opts() -> podman::opts::SystemdUnitsOpts {
+    podman::opts::SystemdUnitsOpts::builder()
+        .new(true)
+        .use_name(true)
+        .build()
+}
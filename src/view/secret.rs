@@ -0,0 +1,64 @@
+use adw::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use gtk::gio;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+
+pub(crate) fn summary(secret: &model::Secret) -> String {
+    let driver = secret.driver();
+
+    match secret.inner().created_at.as_ref() {
+        Some(created_at) => format!(
+            "{driver} · {}",
+            utils::format_ago(utils::timespan_now(created_at.timestamp()))
+        ),
+        None => driver,
+    }
+}
+
+pub(crate) fn delete_secret_show_confirmation(widget: &gtk::Widget, secret: Option<model::Secret>) {
+    if let Some(secret) = secret {
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Confirm Secret Deletion"))
+            .body_use_markup(true)
+            .body(gettext!(
+                // Translators: The "{}" is a placeholder for the secret name.
+                "Containers using secret <b>{}</b> will not be able to start anymore.",
+                secret.name(),
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("delete", &gettext("_Delete")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+        dialog.choose(
+            widget,
+            gio::Cancellable::NONE,
+            clone!(@weak widget, @weak secret => move |response| {
+                if response == "delete" {
+                    delete_secret(&widget, &secret);
+                }
+            }),
+        );
+    }
+}
+
+fn delete_secret(widget: &gtk::Widget, secret: &model::Secret) {
+    secret.delete(clone!(@weak widget => move |secret, result| {
+        if let Err(e) = result {
+            utils::show_error_toast(
+                &widget,
+                // Translators: The "{}" is a placeholder for the secret name.
+                &gettext!("Error on deleting secret '{}'", secret.name()),
+                &e.to_string(),
+            );
+        }
+    }));
+}
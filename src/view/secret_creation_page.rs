@@ -0,0 +1,238 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::WindowIdentifier;
+use gettextrs::gettext;
+use glib::clone;
+use glib::Properties;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::view;
+
+const ACTION_SELECT_FILE: &str = "secret-creation-page.select-file";
+const ACTION_CREATE_SECRET: &str = "secret-creation-page.create-secret";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretCreationPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secret_creation_page.ui")]
+    pub(crate) struct SecretCreationPage {
+        pub(super) file_data: RefCell<Option<String>>,
+        #[property(get, set, construct_only)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[property(get, set, construct_only)]
+        pub(super) show_view_artifact: Cell<bool>,
+        #[template_child]
+        pub(super) navigation_view: TemplateChild<adw::NavigationView>,
+        #[template_child]
+        pub(super) create_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) name_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) source_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) text_entry_row: TemplateChild<adw::PasswordEntryRow>,
+        #[template_child]
+        pub(super) file_row: TemplateChild<adw::ActionRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretCreationPage {
+        const NAME: &'static str = "PdsSecretCreationPage";
+        type Type = super::SecretCreationPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.install_action_async(ACTION_SELECT_FILE, None, |widget, _, _| async move {
+                widget.select_file().await;
+            });
+            klass.install_action(ACTION_CREATE_SECRET, None, |widget, _, _| {
+                widget.create_secret();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretCreationPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.on_source_changed();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for SecretCreationPage {
+        fn root(&self) {
+            self.parent_root();
+
+            let widget = &*self.obj();
+
+            glib::idle_add_local(
+                clone!(@weak widget => @default-return glib::ControlFlow::Break, move || {
+                    widget.imp().name_entry_row.grab_focus();
+                    glib::ControlFlow::Break
+                }),
+            );
+            utils::root(widget.upcast_ref()).set_default_widget(Some(&*self.create_button));
+        }
+
+        fn unroot(&self) {
+            utils::root(self.obj().upcast_ref()).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot()
+        }
+    }
+
+    #[gtk::template_callbacks]
+    impl SecretCreationPage {
+        #[template_callback]
+        fn on_source_changed(&self) {
+            let from_file = self.source_combo_row.selected() == 1;
+
+            self.text_entry_row.set_visible(!from_file);
+            self.file_row.set_visible(from_file);
+
+            self.update_create_action();
+        }
+
+        #[template_callback]
+        pub(super) fn update_create_action(&self) {
+            let has_data = if self.source_combo_row.selected() == 1 {
+                self.file_data.borrow().is_some()
+            } else {
+                !self.text_entry_row.text().is_empty()
+            };
+
+            self.obj().action_set_enabled(
+                ACTION_CREATE_SECRET,
+                has_data && !self.name_entry_row.text().trim().is_empty(),
+            );
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretCreationPage(ObjectSubclass<imp::SecretCreationPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Client> for SecretCreationPage {
+    fn from(client: &model::Client) -> Self {
+        Self::new(client, true)
+    }
+}
+
+impl SecretCreationPage {
+    pub(crate) fn new(client: &model::Client, show_view_artifact: bool) -> Self {
+        glib::Object::builder()
+            .property("client", client)
+            .property("show-view-artifact", show_view_artifact)
+            .build()
+    }
+
+    async fn select_file(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Secret File").as_str())
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    utils::do_async(
+                        {
+                            let path = path.clone();
+                            async move { tokio::fs::read_to_string(path).await }
+                        },
+                        clone!(@weak obj => move |result| {
+                            let imp = obj.imp();
+                            match result {
+                                Ok(data) => {
+                                    imp.file_row.set_subtitle(path.to_str().unwrap());
+                                    imp.file_data.replace(Some(data));
+                                }
+                                Err(e) => {
+                                    imp.file_data.replace(None);
+                                    utils::show_error_toast(
+                                        obj.upcast_ref(),
+                                        &gettext("Error on reading file"),
+                                        &e.to_string(),
+                                    );
+                                }
+                            }
+                            imp.update_create_action();
+                        }),
+                    );
+                }
+            }),
+        )
+        .await;
+    }
+
+    fn create_secret(&self) {
+        if let Some(client) = self.client() {
+            let imp = self.imp();
+
+            let data = if imp.source_combo_row.selected() == 1 {
+                imp.file_data.borrow().clone()
+            } else {
+                Some(imp.text_entry_row.text().to_string())
+            };
+
+            if let Some(data) = data {
+                let name = imp.name_entry_row.text().trim().to_owned();
+
+                let page = view::ActionPage::new(
+                    &client.action_list().create_secret(
+                        &name,
+                        podman::opts::SecretCreateOpts::builder(&name).build(),
+                        data,
+                    ),
+                    self.show_view_artifact(),
+                );
+
+                imp.navigation_view.push(
+                    &adw::NavigationPage::builder()
+                        .can_pop(false)
+                        .child(&page)
+                        .build(),
+                );
+            }
+        }
+    }
+}
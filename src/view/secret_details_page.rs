@@ -0,0 +1,239 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use glib::closure;
+use glib::Properties;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+use crate::widget;
+
+const ACTION_INSPECT_SECRET: &str = "secret-details-page.inspect-secret";
+const ACTION_DELETE_SECRET: &str = "secret-details-page.delete-secret";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretDetailsPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secret_details_page.ui")]
+    pub(crate) struct SecretDetailsPage {
+        pub(super) handler_id: RefCell<Option<glib::SignalHandlerId>>,
+        #[property(get, set = Self::set_secret, construct, explicit_notify, nullable)]
+        pub(super) secret: glib::WeakRef<model::Secret>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) name_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) id_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) driver_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) created_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) updated_row: TemplateChild<widget::PropertyRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretDetailsPage {
+        const NAME: &'static str = "PdsSecretDetailsPage";
+        type Type = super::SecretDetailsPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_INSPECT_SECRET, None, |widget, _, _| {
+                widget.show_inspection();
+            });
+
+            klass.install_action(ACTION_DELETE_SECRET, None, |widget, _, _| {
+                widget.delete_secret();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretDetailsPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let secret_expr = Self::Type::this_expression("secret");
+            let secret_inner_expr = secret_expr.chain_property::<model::Secret>("inner");
+            let ticks_expr = Self::Type::this_expression("root")
+                .chain_property::<gtk::Window>("application")
+                .chain_property::<crate::Application>("ticks");
+
+            secret_expr
+                .chain_property::<model::Secret>("to-be-deleted")
+                .watch(
+                    Some(obj),
+                    clone!(@weak obj => move || {
+                        obj.action_set_enabled(
+                            ACTION_DELETE_SECRET,
+                            obj.secret().map(|secret| !secret.to_be_deleted()).unwrap_or(false),
+                        );
+                    }),
+                );
+
+            secret_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedSecret| {
+                    inner
+                        .spec
+                        .as_ref()
+                        .and_then(|spec| spec.name.clone())
+                        .unwrap_or_default()
+                }))
+                .bind(&*self.name_row, "value", Some(obj));
+
+            secret_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedSecret| {
+                    utils::format_id(inner.id.as_deref().unwrap_or_default())
+                }))
+                .bind(&*self.id_row, "value", Some(obj));
+
+            secret_inner_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, inner: &model::BoxedSecret| {
+                    inner
+                        .spec
+                        .as_ref()
+                        .and_then(|spec| spec.driver.as_ref())
+                        .and_then(|driver| driver.name.clone())
+                        .unwrap_or_default()
+                }))
+                .bind(&*self.driver_row, "value", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [&ticks_expr, &secret_inner_expr],
+                closure!(|_: Self::Type, _ticks: u64, inner: &model::BoxedSecret| {
+                    utils::format_ago(utils::timespan_now(
+                        inner
+                            .created_at
+                            .as_ref()
+                            .map(|created_at| created_at.timestamp())
+                            .unwrap_or(0),
+                    ))
+                }),
+            )
+            .bind(&*self.created_row, "value", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [&ticks_expr, &secret_inner_expr],
+                closure!(|_: Self::Type, _ticks: u64, inner: &model::BoxedSecret| {
+                    utils::format_ago(utils::timespan_now(
+                        inner
+                            .updated_at
+                            .as_ref()
+                            .map(|updated_at| updated_at.timestamp())
+                            .unwrap_or(0),
+                    ))
+                }),
+            )
+            .bind(&*self.updated_row, "value", Some(obj));
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for SecretDetailsPage {}
+
+    impl SecretDetailsPage {
+        pub(super) fn set_secret(&self, value: Option<&model::Secret>) {
+            let obj = &*self.obj();
+            if obj.secret().as_ref() == value {
+                return;
+            }
+
+            self.window_title.set_subtitle("");
+            if let Some(secret) = obj.secret() {
+                secret.disconnect(self.handler_id.take().unwrap());
+            }
+
+            if let Some(secret) = value {
+                self.window_title.set_subtitle(&secret.name());
+
+                let handler_id = secret.connect_deleted(clone!(@weak obj => move |secret| {
+                    utils::show_toast(obj.upcast_ref(), gettext!("Secret '{}' has been deleted", secret.name()));
+                    utils::navigation_view(obj.upcast_ref()).pop();
+                }));
+                self.handler_id.replace(Some(handler_id));
+            }
+
+            self.secret.set(value);
+            obj.notify("secret");
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretDetailsPage(ObjectSubclass<imp::SecretDetailsPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Secret> for SecretDetailsPage {
+    fn from(secret: &model::Secret) -> Self {
+        glib::Object::builder().property("secret", secret).build()
+    }
+}
+
+impl SecretDetailsPage {
+    pub(crate) fn show_inspection(&self) {
+        self.exec_action(|| {
+            if let Some(secret) = self.secret() {
+                let weak_ref = glib::WeakRef::new();
+                weak_ref.set(Some(&secret));
+
+                utils::navigation_view(self.upcast_ref()).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ScalableTextViewPage::from(view::Entity::Secret(
+                            weak_ref,
+                        )))
+                        .build(),
+                );
+            }
+        });
+    }
+
+    pub(crate) fn delete_secret(&self) {
+        self.exec_action(|| {
+            view::secret::delete_secret_show_confirmation(self.upcast_ref(), self.secret());
+        });
+    }
+
+    fn exec_action<F: Fn()>(&self, op: F) {
+        if utils::navigation_view(self.upcast_ref())
+            .visible_page()
+            .filter(|page| page.child().as_ref() == Some(self.upcast_ref()))
+            .is_some()
+        {
+            op();
+        }
+    }
+}
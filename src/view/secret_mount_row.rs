@@ -0,0 +1,241 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::ExpanderRowImpl;
+use adw::subclass::prelude::PreferencesRowImpl;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::closure;
+use glib::Properties;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretMountRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secret_mount_row.ui")]
+    pub(crate) struct SecretMountRow {
+        pub(super) bindings: RefCell<Vec<glib::Binding>>,
+        #[property(get, set = Self::set_secret_mount, construct)]
+        pub(super) secret_mount: RefCell<Option<model::SecretMount>>,
+        #[template_child]
+        pub(super) secret_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) target_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) secret_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) mount_type_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) target_entry_row: TemplateChild<adw::EntryRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretMountRow {
+        const NAME: &'static str = "PdsSecretMountRow";
+        type Type = super::SecretMountRow;
+        type ParentType = adw::ExpanderRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action("secret-mount-row.remove", None, |widget, _, _| {
+                if let Some(secret_mount) = widget.secret_mount() {
+                    secret_mount.remove_request();
+                }
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretMountRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let secret_name_expr = model::Secret::this_expression("inner").chain_closure::<String>(
+                closure!(|_: model::Secret, inner: model::BoxedSecret| inner
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| spec.name.clone())
+                    .unwrap_or_default()),
+            );
+            self.secret_combo_row
+                .set_expression(Some(&secret_name_expr));
+
+            let secret_mount_expr = Self::Type::this_expression("secret-mount");
+            let secret_expr = secret_mount_expr.chain_property::<model::SecretMount>("secret");
+            let mount_type_expr =
+                secret_mount_expr.chain_property::<model::SecretMount>("mount-type");
+
+            secret_expr
+                .chain_closure::<String>(closure!(
+                    |_: Self::Type, secret: Option<model::Secret>| {
+                        secret
+                            .as_ref()
+                            .map(model::Secret::name)
+                            .unwrap_or_else(|| gettext("No Secret"))
+                    }
+                ))
+                .bind(&self.secret_label.get(), "label", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [
+                    &mount_type_expr,
+                    &secret_mount_expr.chain_property::<model::SecretMount>("target"),
+                    &secret_expr,
+                ],
+                closure!(|_: Self::Type,
+                          mount_type: model::SecretMountType,
+                          target: String,
+                          secret: Option<model::Secret>| {
+                    let target = if target.trim().is_empty() {
+                        secret.as_ref().map(model::Secret::name).unwrap_or_default()
+                    } else {
+                        target.trim().to_owned()
+                    };
+
+                    match mount_type {
+                        model::SecretMountType::File => format!("/run/secrets/{target}"),
+                        model::SecretMountType::Env => format!("${target}"),
+                    }
+                }),
+            )
+            .bind(&self.target_label.get(), "label", Some(obj));
+
+            mount_type_expr
+                .chain_closure::<String>(closure!(
+                    |_: Self::Type, mount_type: model::SecretMountType| {
+                        match mount_type {
+                            model::SecretMountType::File => gettext("File Name"),
+                            model::SecretMountType::Env => gettext("Variable Name"),
+                        }
+                    }
+                ))
+                .bind(&self.target_entry_row.get(), "title", Some(obj));
+        }
+    }
+
+    impl WidgetImpl for SecretMountRow {}
+    impl ListBoxRowImpl for SecretMountRow {}
+    impl PreferencesRowImpl for SecretMountRow {}
+    impl ExpanderRowImpl for SecretMountRow {}
+
+    impl SecretMountRow {
+        pub(super) fn set_secret_mount(&self, value: Option<model::SecretMount>) {
+            let obj = &*self.obj();
+            if obj.secret_mount() == value {
+                return;
+            }
+
+            let mut bindings = self.bindings.borrow_mut();
+
+            while let Some(binding) = bindings.pop() {
+                binding.unbind();
+            }
+
+            if let Some(ref secret_mount) = value {
+                if let Some(secret_list) = secret_mount
+                    .client()
+                    .as_ref()
+                    .map(model::Client::secret_list)
+                {
+                    self.secret_combo_row.set_model(Some(&secret_list));
+
+                    match secret_mount.secret() {
+                        Some(secret) => {
+                            if let Some(position) = secret_list
+                                .iter::<model::Secret>()
+                                .map(Result::unwrap)
+                                .position(|other| other == secret)
+                            {
+                                self.secret_combo_row.set_selected(position as u32);
+                            }
+                        }
+                        None => secret_mount.set_secret(
+                            self.secret_combo_row
+                                .selected_item()
+                                .and_downcast_ref::<model::Secret>(),
+                        ),
+                    }
+                }
+
+                let binding = self
+                    .secret_combo_row
+                    .bind_property("selected-item", secret_mount, "secret")
+                    .transform_to(|_, item: Option<glib::Object>| {
+                        Some(item.and_downcast::<model::Secret>().to_value())
+                    })
+                    .build();
+                bindings.push(binding);
+
+                let binding = secret_mount
+                    .bind_property("mount-type", &*self.mount_type_combo_row, "selected")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .transform_to(|_, mount_type: model::SecretMountType| {
+                        Some(
+                            match mount_type {
+                                model::SecretMountType::File => 0_u32,
+                                model::SecretMountType::Env => 1_u32,
+                            }
+                            .to_value(),
+                        )
+                    })
+                    .transform_from(|_, position: u32| {
+                        Some(
+                            if position == 0 {
+                                model::SecretMountType::File
+                            } else {
+                                model::SecretMountType::Env
+                            }
+                            .to_value(),
+                        )
+                    })
+                    .build();
+                bindings.push(binding);
+
+                let binding = secret_mount
+                    .bind_property("target", &*self.target_entry_row, "text")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+                bindings.push(binding);
+            }
+
+            self.secret_mount.replace(value);
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretMountRow(ObjectSubclass<imp::SecretMountRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ExpanderRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::SecretMount> for SecretMountRow {
+    fn from(secret_mount: &model::SecretMount) -> Self {
+        glib::Object::builder()
+            .property("secret-mount", secret_mount)
+            .build()
+    }
+}
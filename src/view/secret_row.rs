@@ -0,0 +1,215 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use glib::closure;
+use glib::property::PropertySet;
+use glib::Properties;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::model::SelectableExt;
+use crate::model::SelectableListExt;
+use crate::utils;
+use crate::view;
+
+const ACTION_DELETE_SECRET: &str = "secret-row.delete-secret";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretRow)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secret_row.ui")]
+    pub(crate) struct SecretRow {
+        #[property(get, set = Self::set_secret, construct, nullable)]
+        pub(super) secret: RefCell<Option<model::Secret>>,
+        pub(super) bindings: RefCell<Vec<glib::Binding>>,
+        #[template_child]
+        pub(super) check_button_revealer: TemplateChild<gtk::Revealer>,
+        #[template_child]
+        pub(super) check_button: TemplateChild<gtk::CheckButton>,
+        #[template_child]
+        pub(super) name_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) details_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) end_box_revealer: TemplateChild<gtk::Revealer>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretRow {
+        const NAME: &'static str = "PdsSecretRow";
+        type Type = super::SecretRow;
+        type ParentType = gtk::ListBoxRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action("secret-row.activate", None, |widget, _, _| {
+                widget.activate();
+            });
+
+            klass.install_action(ACTION_DELETE_SECRET, None, |widget, _, _| {
+                widget.delete_secret();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let secret_expr = Self::Type::this_expression("secret");
+            let secret_inner_expr = secret_expr.chain_property::<model::Secret>("inner");
+            let secret_to_be_deleted_expr =
+                secret_expr.chain_property::<model::Secret>("to-be-deleted");
+
+            let selection_mode_expr = secret_expr
+                .chain_property::<model::Secret>("secret-list")
+                .chain_property::<model::SecretList>("selection-mode");
+
+            selection_mode_expr.bind(&*self.check_button_revealer, "reveal-child", Some(obj));
+            selection_mode_expr
+                .chain_closure::<bool>(closure!(|_: Self::Type, is_selection_mode: bool| {
+                    !is_selection_mode
+                }))
+                .bind(&*self.end_box_revealer, "reveal-child", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [&secret_inner_expr, &secret_to_be_deleted_expr],
+                closure!(
+                    |_: Self::Type, inner: model::BoxedSecret, to_be_deleted: bool| {
+                        let name = inner
+                            .spec
+                            .as_ref()
+                            .and_then(|spec| spec.name.clone())
+                            .unwrap_or_default();
+                        if to_be_deleted {
+                            format!("<s>{name}</s>")
+                        } else {
+                            name
+                        }
+                    }
+                ),
+            )
+            .bind(&*self.name_label, "label", Some(obj));
+
+            secret_inner_expr
+                .chain_closure::<String>(closure!(|obj: Self::Type, _: model::BoxedSecret| {
+                    obj.secret()
+                        .as_ref()
+                        .map(view::secret::summary)
+                        .unwrap_or_default()
+                }))
+                .bind(&*self.details_label, "label", Some(obj));
+
+            secret_to_be_deleted_expr.watch(
+                Some(obj),
+                clone!(@weak obj, @strong secret_to_be_deleted_expr => move || {
+                    obj.action_set_enabled(
+                        ACTION_DELETE_SECRET,
+                        !secret_to_be_deleted_expr.evaluate_as::<bool, _>(Some(&obj)).unwrap()
+                    );
+                }),
+            );
+
+            if let Some(secret) = obj.secret() {
+                obj.action_set_enabled("secret.show-details", !secret.to_be_deleted());
+                secret.connect_notify_local(
+                    Some("to-be-deleted"),
+                    clone!(@weak obj => move|secret, _| {
+                        obj.action_set_enabled("secret.show-details", !secret.to_be_deleted());
+                    }),
+                );
+            }
+        }
+    }
+
+    impl WidgetImpl for SecretRow {}
+    impl ListBoxRowImpl for SecretRow {}
+
+    impl SecretRow {
+        pub(super) fn set_secret(&self, value: Option<model::Secret>) {
+            let obj = &*self.obj();
+            if obj.secret() == value {
+                return;
+            }
+
+            let mut bindings = self.bindings.borrow_mut();
+            while let Some(binding) = bindings.pop() {
+                binding.unbind();
+            }
+
+            if let Some(ref secret) = value {
+                let binding = secret
+                    .bind_property("selected", &*self.check_button, "active")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+
+                bindings.push(binding);
+            }
+
+            self.secret.set(value);
+            obj.notify("secret")
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretRow(ObjectSubclass<imp::SecretRow>)
+        @extends gtk::Widget, gtk::ListBoxRow,
+        @implements gtk::Accessible, gtk::Buildable, gtk::Actionable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Secret> for SecretRow {
+    fn from(secret: &model::Secret) -> Self {
+        glib::Object::builder().property("secret", secret).build()
+    }
+}
+
+impl SecretRow {
+    pub(crate) fn activate(&self) {
+        if let Some(secret) = self.secret().as_ref() {
+            if secret
+                .secret_list()
+                .map(|list| list.is_selection_mode())
+                .unwrap_or(false)
+            {
+                secret.select();
+            } else {
+                utils::navigation_view(self.upcast_ref()).push(
+                    &adw::NavigationPage::builder()
+                        .title(gettext!("Secret {}", secret.name()))
+                        .child(&view::SecretDetailsPage::from(secret))
+                        .build(),
+                );
+            }
+        }
+    }
+
+    pub(crate) fn delete_secret(&self) {
+        view::secret::delete_secret_show_confirmation(self.upcast_ref(), self.secret());
+    }
+}
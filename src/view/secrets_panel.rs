@@ -0,0 +1,436 @@
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::clone;
+use glib::closure;
+use glib::Properties;
+use gtk::gdk;
+use gtk::gio;
+use gtk::glib;
+use gtk::CompositeTemplate;
+
+use crate::model;
+use crate::model::SelectableListExt;
+use crate::utils;
+use crate::view;
+
+const ACTION_CREATE_SECRET: &str = "secrets-panel.create-secret";
+const ACTION_ENTER_SELECTION_MODE: &str = "secrets-panel.enter-selection-mode";
+const ACTION_EXIT_SELECTION_MODE: &str = "secrets-panel.exit-selection-mode";
+const ACTION_SELECT_VISIBLE: &str = "secrets-panel.select-visible";
+const ACTION_SELECT_NONE: &str = "secrets-panel.select-none";
+const ACTION_DELETE_SELECTION: &str = "secrets-panel.delete-selection";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::SecretsPanel)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/secrets_panel.ui")]
+    pub(crate) struct SecretsPanel {
+        pub(super) filter: OnceCell<gtk::Filter>,
+        pub(super) sorter: OnceCell<gtk::Sorter>,
+        pub(super) search_term: RefCell<String>,
+        #[property(get, set = Self::set_secret_list, explicit_notify, nullable)]
+        pub(super) secret_list: glib::WeakRef<model::SecretList>,
+        #[template_child]
+        pub(super) main_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) header_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) selected_secrets_button: TemplateChild<gtk::MenuButton>,
+        #[template_child]
+        pub(super) search_bar: TemplateChild<gtk::SearchBar>,
+        #[template_child]
+        pub(super) search_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub(super) filter_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SecretsPanel {
+        const NAME: &'static str = "PdsSecretsPanel";
+        type Type = super::SecretsPanel;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+            klass.bind_template_callbacks();
+
+            klass.add_binding_action(
+                gdk::Key::N,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_CREATE_SECRET,
+            );
+            klass.install_action(ACTION_CREATE_SECRET, None, move |widget, _, _| {
+                widget.create_secret();
+            });
+
+            klass.install_action(ACTION_ENTER_SELECTION_MODE, None, |widget, _, _| {
+                widget.enter_selection_mode();
+            });
+            klass.install_action(ACTION_EXIT_SELECTION_MODE, None, |widget, _, _| {
+                widget.exit_selection_mode();
+            });
+
+            klass.install_action(ACTION_SELECT_VISIBLE, None, |widget, _, _| {
+                widget.select_visible();
+            });
+            klass.install_action(ACTION_SELECT_NONE, None, |widget, _, _| {
+                widget.select_none();
+            });
+
+            klass.install_action(ACTION_DELETE_SELECTION, None, |widget, _, _| {
+                widget.delete_selection();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SecretsPanel {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            let secret_list_expr = Self::Type::this_expression("secret-list");
+            let secret_list_len_expr = secret_list_expr.chain_property::<model::SecretList>("len");
+            let selection_mode_expr =
+                secret_list_expr.chain_property::<model::SecretList>("selection-mode");
+            let not_selection_mode_expr = selection_mode_expr.chain_closure::<bool>(closure!(
+                |_: Self::Type, selection_mode: bool| { !selection_mode }
+            ));
+
+            gtk::ClosureExpression::new::<Option<String>>(
+                [
+                    &secret_list_len_expr,
+                    &secret_list_expr.chain_property::<model::SecretList>("listing"),
+                    &secret_list_expr.chain_property::<model::SecretList>("initialized"),
+                ],
+                closure!(
+                    |_: Self::Type, len: u32, listing: bool, initialized: bool| {
+                        if len == 0 {
+                            if initialized {
+                                Some("empty")
+                            } else if listing {
+                                Some("spinner")
+                            } else {
+                                None
+                            }
+                        } else {
+                            Some("secrets")
+                        }
+                    }
+                ),
+            )
+            .bind(&self.main_stack.get(), "visible-child-name", Some(obj));
+
+            selection_mode_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, selection_mode: bool| {
+                    if !selection_mode {
+                        "main"
+                    } else {
+                        "selection"
+                    }
+                }))
+                .bind(&self.header_stack.get(), "visible-child-name", Some(obj));
+
+            secret_list_len_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, len: u32| {
+                    if len == 0 {
+                        String::new()
+                    } else {
+                        ngettext!("{} secret", "{} secrets", len, len)
+                    }
+                }))
+                .bind(&self.window_title.get(), "subtitle", Some(obj));
+
+            secret_list_expr
+                .chain_property::<model::SecretList>("num-selected")
+                .chain_closure::<String>(closure!(|_: Self::Type, selected: u32| ngettext!(
+                    "{} Selected Secret",
+                    "{} Selected Secrets",
+                    selected,
+                    selected
+                )))
+                .bind(&self.selected_secrets_button.get(), "label", Some(obj));
+
+            not_selection_mode_expr.bind(&self.search_bar.get(), "visible", Some(obj));
+
+            let search_filter =
+                gtk::CustomFilter::new(clone!(@weak obj => @default-return false, move |item| {
+                    let term = &*obj.imp().search_term.borrow();
+                    item.downcast_ref::<model::Secret>()
+                        .unwrap()
+                        .name()
+                        .to_lowercase()
+                        .contains(term)
+                }));
+
+            let filter = gtk::EveryFilter::new();
+            filter.append(search_filter);
+
+            let sorter = gtk::StringSorter::new(Some(
+                model::Secret::this_expression("inner").chain_closure::<String>(closure!(
+                    |_: model::Secret, inner: model::BoxedSecret| inner
+                        .spec
+                        .as_ref()
+                        .and_then(|spec| spec.name.clone())
+                        .unwrap_or_default()
+                )),
+            ));
+
+            self.filter.set(filter.upcast()).unwrap();
+            self.sorter.set(sorter.upcast()).unwrap();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(self.obj().upcast_ref());
+        }
+    }
+
+    impl WidgetImpl for SecretsPanel {}
+
+    #[gtk::template_callbacks]
+    impl SecretsPanel {
+        #[template_callback]
+        fn on_notify_search_mode_enabled(&self) {
+            if self.search_bar.is_search_mode() {
+                self.search_entry.grab_focus();
+            } else {
+                self.search_entry.set_text("");
+            }
+        }
+
+        #[template_callback]
+        fn on_search_changed(&self) {
+            let term = self.search_entry.text().trim().to_lowercase();
+
+            let filter_change = if self.search_term.borrow().contains(&term) {
+                gtk::FilterChange::LessStrict
+            } else {
+                gtk::FilterChange::MoreStrict
+            };
+
+            self.search_term.replace(term);
+            self.update_filter(filter_change);
+        }
+
+        pub(super) fn set_secret_list(&self, value: Option<&model::SecretList>) {
+            let obj = &*self.obj();
+            if obj.secret_list().as_ref() == value {
+                return;
+            }
+
+            obj.action_set_enabled(ACTION_DELETE_SELECTION, false);
+
+            if let Some(secret_list) = value {
+                secret_list.connect_notify_local(
+                    Some("num-selected"),
+                    clone!(@weak obj => move |list, _| {
+                        obj.action_set_enabled(ACTION_DELETE_SELECTION, list.num_selected() > 0);
+                    }),
+                );
+
+                let model = gtk::SortListModel::new(
+                    Some(gtk::FilterListModel::new(
+                        Some(secret_list.to_owned()),
+                        self.filter.get().cloned(),
+                    )),
+                    self.sorter.get().cloned(),
+                );
+
+                self.list_box.bind_model(Some(&model), |item| {
+                    view::SecretRow::from(item.downcast_ref().unwrap()).upcast()
+                });
+
+                self.filter_stack
+                    .set_visible_child_name(if model.n_items() > 0 { "list" } else { "empty" });
+                model.connect_items_changed(clone!(@weak obj => move |model, _, removed, _| {
+                    obj.imp()
+                        .filter_stack
+                        .set_visible_child_name(if model.n_items() > 0 { "list" } else { "empty" });
+
+                    if removed > 0 {
+                        obj.deselect_hidden_secrets(model.upcast_ref());
+                    }
+                }));
+            }
+
+            self.secret_list.set(value);
+            obj.notify("secret-list");
+        }
+
+        fn update_filter(&self, filter_change: gtk::FilterChange) {
+            if let Some(filter) = self.filter.get() {
+                filter.changed(filter_change);
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct SecretsPanel(ObjectSubclass<imp::SecretsPanel>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for SecretsPanel {
+    fn default() -> Self {
+        glib::Object::builder().build()
+    }
+}
+
+impl SecretsPanel {
+    pub(crate) fn set_search_mode(&self, value: bool) {
+        self.imp().search_bar.set_search_mode(value);
+    }
+
+    pub(crate) fn toggle_search_mode(&self) {
+        self.set_search_mode(!self.imp().search_bar.is_search_mode());
+    }
+
+    pub(crate) fn create_secret(&self) {
+        if let Some(client) = self
+            .secret_list()
+            .as_ref()
+            .and_then(model::SecretList::client)
+        {
+            utils::Dialog::new(
+                self.upcast_ref(),
+                view::SecretCreationPage::from(&client).upcast_ref(),
+            )
+            .present();
+        }
+    }
+
+    pub(crate) fn enter_selection_mode(&self) {
+        if let Some(list) = self.secret_list().filter(|list| list.len() > 0) {
+            list.select_none();
+            list.set_selection_mode(true);
+        }
+    }
+
+    pub(crate) fn exit_selection_mode(&self) {
+        if let Some(list) = self.secret_list() {
+            list.set_selection_mode(false);
+        }
+    }
+
+    pub(crate) fn select_visible(&self) {
+        (0..)
+            .map(|pos| self.imp().list_box.row_at_index(pos))
+            .take_while(Option::is_some)
+            .flatten()
+            .for_each(|row| {
+                row.downcast_ref::<view::SecretRow>()
+                    .unwrap()
+                    .secret()
+                    .unwrap()
+                    .set_selected(row.is_visible());
+            });
+    }
+
+    pub(crate) fn select_none(&self) {
+        if let Some(list) = self.secret_list().filter(|list| list.is_selection_mode()) {
+            list.select_none();
+        }
+    }
+
+    pub(crate) fn delete_selection(&self) {
+        if self
+            .secret_list()
+            .map(|list| list.num_selected())
+            .unwrap_or(0)
+            == 0
+        {
+            return;
+        }
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Confirm Deletion of Multiple Secrets"))
+            .body(gettext(
+                "Containers using any of these secrets will not be able to start anymore.",
+            ))
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("delete", &gettext("_Delete")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+        dialog.connect_response(
+            None,
+            clone!(@weak self as obj => move |_, response| if response == "delete" {
+                if let Some(list) = obj.secret_list() {
+                    list
+                        .selected_items()
+                        .iter()
+                        .map(|obj| obj.downcast_ref::<model::Secret>().unwrap())
+                        .for_each(|secret|
+                    {
+                        secret.delete(clone!(@weak obj => move |secret, result| {
+                            if let Err(e) = result {
+                                utils::show_error_toast(
+                                    obj.upcast_ref(),
+                                    &gettext!(
+                                        "Error on deleting secret '{}'",
+                                        secret.name()
+                                    ),
+                                    &e.to_string()
+                                );
+                            }
+                        }));
+                    });
+                    list.set_selection_mode(false);
+                }
+            }),
+        );
+
+        dialog.present(Some(self));
+    }
+
+    fn deselect_hidden_secrets(&self, model: &gio::ListModel) {
+        let visible_secrets = model
+            .iter::<glib::Object>()
+            .map(Result::unwrap)
+            .map(|item| item.downcast::<model::Secret>().unwrap())
+            .collect::<Vec<_>>();
+
+        self.secret_list()
+            .unwrap()
+            .iter::<model::Secret>()
+            .map(Result::unwrap)
+            .filter(model::Secret::selected)
+            .for_each(|secret| {
+                if !visible_secrets.contains(&secret) {
+                    secret.set_selected(false);
+                }
+            });
+    }
+}
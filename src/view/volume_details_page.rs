@@ -17,6 +17,7 @@ use crate::widget;
 
 const ACTION_INSPECT_VOLUME: &str = "volume-details-page.inspect-volume";
 const ACTION_DELETE_VOLUME: &str = "volume-details-page.delete-volume";
+const ACTION_COPY_LINK: &str = "volume-details-page.copy-link";
 
 mod imp {
     use super::*;
@@ -49,6 +50,10 @@ mod imp {
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
 
+            klass.install_action(ACTION_COPY_LINK, None, |widget, _, _| {
+                widget.copy_link();
+            });
+
             klass.install_action(ACTION_INSPECT_VOLUME, None, |widget, _, _| {
                 widget.show_inspection();
             });
@@ -199,6 +204,26 @@ impl From<&model::Volume> for VolumeDetailsPage {
 }
 
 impl VolumeDetailsPage {
+    fn copy_link(&self) {
+        if let Some((volume, client)) = self.volume().and_then(|volume| {
+            volume
+                .volume_list()
+                .and_then(|list| list.client())
+                .map(|client| (volume, client))
+        }) {
+            utils::copy_to_clipboard(
+                self.upcast_ref(),
+                &model::DeepLink::new(
+                    &client.connection().name(),
+                    model::DeepLinkKind::Volume,
+                    &volume.inner().name,
+                )
+                .to_string(),
+                &gettext("Link copied"),
+            );
+        }
+    }
+
     pub(crate) fn show_inspection(&self) {
         self.exec_action(|| {
             if let Some(volume) = self.volume() {
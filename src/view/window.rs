@@ -1,9 +1,17 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::OnceLock;
 
 use adw::prelude::*;
 use adw::subclass::prelude::AdwApplicationWindowImpl;
 use adw::subclass::prelude::*;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::desktop::file_chooser::SaveFileRequest;
+use ashpd::WindowIdentifier;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use gtk::gdk;
 use gtk::gio;
 use gtk::glib;
@@ -18,7 +26,10 @@ use crate::view;
 
 const ACTION_CLOSE: &str = "win.close";
 const ACTION_CREATE_CONNECTION: &str = "win.create-connection";
+const ACTION_EXPORT_CONNECTIONS: &str = "win.export-connections";
+const ACTION_IMPORT_CONNECTIONS: &str = "win.import-connections";
 const ACTION_REMOVE_CONNECTION: &str = "win.remove-connection";
+const ACTION_SHOW_CONNECTIONS_OVERVIEW: &str = "win.show-connections-overview";
 
 mod imp {
     use super::*;
@@ -28,6 +39,9 @@ mod imp {
     pub(crate) struct Window {
         pub(super) settings: utils::PodsSettings,
         pub(super) connection_manager: model::ConnectionManager,
+        pub(super) setup_finished: Cell<bool>,
+        /// The links that were opened before the connections have been loaded.
+        pub(super) pending_deep_links: RefCell<Vec<model::DeepLink>>,
         #[template_child]
         pub(super) toast_overlay: TemplateChild<adw::ToastOverlay>,
         #[template_child]
@@ -63,6 +77,25 @@ mod imp {
                 },
             );
 
+            klass.install_action_async(
+                ACTION_IMPORT_CONNECTIONS,
+                None,
+                |widget, _, _| async move {
+                    widget.import_connections().await;
+                },
+            );
+            klass.install_action_async(
+                ACTION_EXPORT_CONNECTIONS,
+                None,
+                |widget, _, _| async move {
+                    widget.export_connections().await;
+                },
+            );
+
+            klass.install_action(ACTION_SHOW_CONNECTIONS_OVERVIEW, None, |widget, _, _| {
+                widget.show_connections_overview();
+            });
+
             klass.add_binding_action(gdk::Key::W, gdk::ModifierType::CONTROL_MASK, ACTION_CLOSE);
             klass.install_action(ACTION_CLOSE, None, |widget, _, _| {
                 widget.close();
@@ -137,25 +170,28 @@ mod imp {
                 }),
             );
 
-            self.connection_manager.connect_client_notify(
-                clone!(@weak obj => move |manager| match manager.client() {
-                    Some(client) => client.check_service(
-                        clone!(@weak obj, @weak client => move || {
+            self.connection_manager.connect_client_added(
+                clone!(@weak obj => move |manager, client| client.check_service(
+                    clone!(@weak obj, @weak manager, @weak client => move || {
+                        if manager.client().as_ref() == Some(&client) {
                             obj
                                 .imp()
                                 .main_stack
                                 .set_visible_child_full("client", gtk::StackTransitionType::None);
-                        }),
-                        clone!(@weak obj => move |e| obj.client_err_op(e)),
-                        clone!(@weak obj, @weak manager => move |e| {
-                            utils::show_error_toast(
-                                obj.imp().toast_overlay.upcast_ref(),
-                                "Connection lost",
-                                &e.to_string()
-                            );
-                            manager.unset_client();
-                        }),
-                    ),
+                        }
+                    }),
+                    clone!(@weak obj => move |e| obj.client_err_op(e)),
+                )),
+            );
+
+            self.connection_manager.connect_client_notify(
+                clone!(@weak obj => move |manager| match manager.client() {
+                    // Clients that are still loading are shown as soon as they are started.
+                    Some(client) => if client.started() {
+                        obj.imp()
+                            .main_stack
+                            .set_visible_child_full("client", gtk::StackTransitionType::None);
+                    },
                     None => {
                         obj.imp().main_stack.set_visible_child_full(
                             if manager.n_items() > 0 {
@@ -169,11 +205,20 @@ mod imp {
                 }),
             );
 
-            self.connection_manager.setup(
-                clone!(@weak obj => move |result| if let Err(e) = result {
-                    obj.on_connection_manager_setup_error(e);
-                }),
-            );
+            self.connection_manager
+                .setup(clone!(@weak obj => move |result| {
+                    if let Err(e) = result {
+                        obj.on_connection_manager_setup_error(e);
+                    }
+                }));
+
+            // The connections have been loaded at this point, even if the last used connection
+            // is still connecting.
+            self.setup_finished.set(true);
+            self.pending_deep_links
+                .take()
+                .into_iter()
+                .for_each(|deep_link| obj.open_deep_link(deep_link));
         }
     }
 
@@ -227,6 +272,267 @@ impl Window {
         self.imp().client_view.navigation_view()
     }
 
+    /// Shows the details page of a container, connecting to its connection first if needed. The
+    /// container may also be given by its name or a prefix of its id.
+    pub(crate) fn show_container(&self, connection_uuid: &str, id: &str) {
+        self.open_deep_link(model::DeepLink::new(
+            connection_uuid,
+            model::DeepLinkKind::Container,
+            id,
+        ));
+    }
+
+    /// Shows the details page of a pod, connecting to its connection first if needed. The pod
+    /// may also be given by its name or a prefix of its id.
+    pub(crate) fn show_pod(&self, connection_uuid: &str, id: &str) {
+        self.open_deep_link(model::DeepLink::new(
+            connection_uuid,
+            model::DeepLinkKind::Pod,
+            id,
+        ));
+    }
+
+    /// Opens a `pods://` link.
+    pub(crate) fn open_uri(&self, uri: &str) {
+        match model::DeepLink::parse(uri) {
+            Ok(deep_link) => self.open_deep_link(deep_link),
+            Err(e) => utils::show_error_toast(
+                self.imp().toast_overlay.upcast_ref(),
+                &gettext("Error on opening link"),
+                &e.to_string(),
+            ),
+        }
+    }
+
+    /// Switches to the connection of the link and pushes the page of the object it points to.
+    pub(crate) fn open_deep_link(&self, deep_link: model::DeepLink) {
+        let imp = self.imp();
+
+        if !imp.setup_finished.get() {
+            imp.pending_deep_links.borrow_mut().push(deep_link);
+            return;
+        }
+
+        let connection_manager = self.connection_manager();
+        let connection = match connection_manager
+            .connection_by_uuid(&deep_link.connection)
+            .or_else(|| connection_manager.connection_by_name(&deep_link.connection))
+        {
+            Some(connection) => connection,
+            None => {
+                utils::show_error_toast(
+                    imp.toast_overlay.upcast_ref(),
+                    &gettext("Error on opening link"),
+                    &gettext!("Unknown connection '{}'", deep_link.connection),
+                );
+                return;
+            }
+        };
+
+        self.with_client(&connection.uuid(), move |obj, client| {
+            match deep_link.kind {
+                model::DeepLinkKind::Container => {
+                    let container_list = client.container_list();
+                    utils::on_initialized(
+                        container_list.upcast_ref(),
+                        clone!(@weak obj, @weak container_list => move || {
+                            let id = &deep_link.id;
+                            match container_list.get_container(id).or_else(|| {
+                                container_list
+                                    .iter::<model::Container>()
+                                    .map(Result::unwrap)
+                                    .find(|container| {
+                                        &container.name() == id || container.id().starts_with(id)
+                                    })
+                            }) {
+                                Some(container) => {
+                                    let page = view::ContainerDetailsPage::from(&container);
+                                    obj.navigation_view().push(
+                                        &adw::NavigationPage::builder()
+                                            .title(gettext!("Container {}", container.name()))
+                                            .child(&page)
+                                            .build(),
+                                    );
+
+                                    match deep_link.page {
+                                        model::DeepLinkPage::Details => {}
+                                        model::DeepLinkPage::Logs => page.show_log(),
+                                        model::DeepLinkPage::Terminal => page.show_tty(),
+                                        model::DeepLinkPage::Top => page.show_processes(),
+                                    }
+                                }
+                                None => utils::show_error_toast(
+                                    obj.imp().toast_overlay.upcast_ref(),
+                                    &gettext("Error on showing container"),
+                                    &gettext("Container has been deleted"),
+                                ),
+                            }
+                        }),
+                    );
+                }
+                model::DeepLinkKind::Pod => {
+                    let pod_list = client.pod_list();
+                    utils::on_initialized(
+                        pod_list.upcast_ref(),
+                        clone!(@weak obj, @weak pod_list => move || {
+                            let id = &deep_link.id;
+                            match pod_list.get_pod(id).or_else(|| {
+                                pod_list
+                                    .iter::<model::Pod>()
+                                    .map(Result::unwrap)
+                                    .find(|pod| &pod.name() == id || pod.id().starts_with(id))
+                            }) {
+                                Some(pod) => {
+                                    let page = view::PodDetailsPage::from(&pod);
+                                    obj.navigation_view().push(
+                                        &adw::NavigationPage::builder()
+                                            .title(gettext("Pod Details"))
+                                            .child(&page)
+                                            .build(),
+                                    );
+
+                                    if deep_link.page == model::DeepLinkPage::Top {
+                                        page.show_processes();
+                                    }
+                                }
+                                None => utils::show_error_toast(
+                                    obj.imp().toast_overlay.upcast_ref(),
+                                    &gettext("Error on showing pod"),
+                                    &gettext("Pod has been deleted"),
+                                ),
+                            }
+                        }),
+                    );
+                }
+                model::DeepLinkKind::Image => {
+                    let image_list = client.image_list();
+                    utils::on_initialized(
+                        image_list.upcast_ref(),
+                        clone!(@weak obj, @weak image_list => move || {
+                            let id = deep_link.id.as_str();
+                            match image_list.get_image(id).or_else(|| {
+                                image_list
+                                    .iter::<model::Image>()
+                                    .map(Result::unwrap)
+                                    .find(|image| image.id().starts_with(id))
+                            }) {
+                                Some(image) => obj.navigation_view().push(
+                                    &adw::NavigationPage::builder()
+                                        .child(&view::ImageDetailsPage::from(&image))
+                                        .build(),
+                                ),
+                                None => utils::show_error_toast(
+                                    obj.imp().toast_overlay.upcast_ref(),
+                                    &gettext("Error on showing image"),
+                                    &gettext("Image has been deleted"),
+                                ),
+                            }
+                        }),
+                    );
+                }
+                model::DeepLinkKind::Volume => {
+                    let volume_list = client.volume_list();
+                    utils::on_initialized(
+                        volume_list.upcast_ref(),
+                        clone!(@weak obj, @weak volume_list => move || {
+                            match volume_list.get_volume(deep_link.id.as_str()) {
+                                Some(volume) => obj.navigation_view().push(
+                                    &adw::NavigationPage::builder()
+                                        .child(&view::VolumeDetailsPage::from(&volume))
+                                        .build(),
+                                ),
+                                None => utils::show_error_toast(
+                                    obj.imp().toast_overlay.upcast_ref(),
+                                    &gettext("Error on showing volume"),
+                                    &gettext("Volume has been deleted"),
+                                ),
+                            }
+                        }),
+                    );
+                }
+                model::DeepLinkKind::Network => {
+                    let network_list = client.network_list();
+                    utils::on_initialized(
+                        network_list.upcast_ref(),
+                        clone!(@weak obj, @weak network_list => move || {
+                            match network_list.get_network(deep_link.id.as_str()) {
+                                Some(network) => obj.navigation_view().push(
+                                    &adw::NavigationPage::builder()
+                                        .child(&view::NetworkDetailsPage::from(&network))
+                                        .build(),
+                                ),
+                                None => utils::show_error_toast(
+                                    obj.imp().toast_overlay.upcast_ref(),
+                                    &gettext("Error on showing network"),
+                                    &gettext("Network has been deleted"),
+                                ),
+                            }
+                        }),
+                    );
+                }
+            }
+        });
+    }
+
+    fn with_client<F>(&self, connection_uuid: &str, op: F)
+    where
+        F: FnOnce(&Self, model::Client) + 'static,
+    {
+        let connection_manager = self.connection_manager();
+
+        match connection_manager
+            .client()
+            .filter(|client| client.connection().uuid() == connection_uuid)
+        {
+            Some(client) => op(self, client),
+            None => {
+                // Starting another attempt would abort the one that is still running.
+                if let Some(connection) = connection_manager
+                    .connection_by_uuid(connection_uuid)
+                    .filter(model::Connection::connecting)
+                {
+                    let op = RefCell::new(Some(op));
+                    let handler = Rc::new(RefCell::new(None));
+                    handler.replace(Some(connection.connect_connecting_notify(
+                        clone!(@weak self as obj, @strong handler => move |connection| {
+                            if connection.connecting() {
+                                return;
+                            }
+                            if let Some(handler) = handler.take() {
+                                connection.disconnect(handler);
+                            }
+                            if let Some(op) = op.take() {
+                                obj.with_client(&connection.uuid(), op);
+                            }
+                        }),
+                    )));
+                    return;
+                }
+
+                let op = RefCell::new(Some(op));
+                connection_manager.set_client_from(
+                    connection_uuid,
+                    clone!(@weak self as obj, @weak connection_manager => move |result| {
+                        match result {
+                            Ok(_) => {
+                                if let Some((op, client)) =
+                                    op.take().zip(connection_manager.client())
+                                {
+                                    op(&obj, client);
+                                }
+                            }
+                            Err(e) => utils::show_error_toast(
+                                obj.imp().toast_overlay.upcast_ref(),
+                                &gettext("Error on switching connection"),
+                                &e.to_string(),
+                            ),
+                        }
+                    }),
+                );
+            }
+        }
+    }
+
     pub(crate) fn add_connection(&self) {
         utils::Dialog::new(
             self.upcast_ref(),
@@ -235,10 +541,165 @@ impl Window {
         .present();
     }
 
+    pub(crate) fn show_connections_overview(&self) {
+        utils::Dialog::new(
+            self.upcast_ref(),
+            view::ConnectionsOverviewPage::from(&self.connection_manager()).upcast_ref(),
+        )
+        .present();
+    }
+
     pub(crate) fn remove_connection(&self, uuid: &str) {
         self.connection_manager().remove_connection(uuid);
     }
 
+    async fn import_connections(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Import Connections").as_str())
+            .filter(
+                FileFilter::new(&gettext("JSON Files"))
+                    .mimetype("application/json")
+                    .glob("*.json"),
+            )
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    utils::do_async(
+                        async move {
+                            let buf = tokio::fs::read(path).await?;
+                            model::connection_infos_from_slice(&buf)
+                        },
+                        clone!(@weak obj => move |result| match result {
+                            Ok(connection_infos) => obj.resolve_import_conflicts(connection_infos),
+                            Err(e) => utils::show_error_toast(
+                                obj.imp().toast_overlay.upcast_ref(),
+                                &gettext("Error on importing connections"),
+                                &e.to_string(),
+                            ),
+                        }),
+                    );
+                }
+            }),
+        )
+        .await;
+    }
+
+    /// Asks how to handle connections whose name or UUID is already in use before importing.
+    fn resolve_import_conflicts(&self, connection_infos: Vec<model::ConnectionInfo>) {
+        let conflicts = self
+            .connection_manager()
+            .import_conflicts(&connection_infos);
+
+        if conflicts == 0 {
+            self.finish_import_connections(connection_infos, model::ImportConflictResolution::Skip);
+            return;
+        }
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Conflicting Connections"))
+            .body(ngettext!(
+                "{} imported connection has the same name or identifier as an existing one.",
+                "{} imported connections have the same name or identifier as existing ones.",
+                conflicts as u32,
+                conflicts
+            ))
+            .default_response("keep-both")
+            .close_response("cancel")
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("skip", &gettext("_Skip")),
+            ("replace", &gettext("_Replace")),
+            ("keep-both", &gettext("_Keep Both")),
+        ]);
+        dialog.set_response_appearance("replace", adw::ResponseAppearance::Destructive);
+        dialog.set_response_appearance("keep-both", adw::ResponseAppearance::Suggested);
+
+        let connection_infos = RefCell::new(Some(connection_infos));
+        dialog.connect_response(
+            None,
+            clone!(@weak self as obj => move |_, response| {
+                let resolution = match response {
+                    "skip" => model::ImportConflictResolution::Skip,
+                    "replace" => model::ImportConflictResolution::Replace,
+                    "keep-both" => model::ImportConflictResolution::KeepBoth,
+                    _ => return,
+                };
+
+                if let Some(connection_infos) = connection_infos.take() {
+                    obj.finish_import_connections(connection_infos, resolution);
+                }
+            }),
+        );
+
+        dialog.present(Some(self));
+    }
+
+    fn finish_import_connections(
+        &self,
+        connection_infos: Vec<model::ConnectionInfo>,
+        resolution: model::ImportConflictResolution,
+    ) {
+        let imported = self
+            .connection_manager()
+            .import_connections(connection_infos, resolution);
+
+        self.imp().toast_overlay.add_toast(
+            adw::Toast::builder()
+                .title(ngettext!(
+                    "Imported {} connection",
+                    "Imported {} connections",
+                    imported as u32,
+                    imported
+                ))
+                .timeout(3)
+                .build(),
+        );
+    }
+
+    async fn export_connections(&self) {
+        let request = SaveFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Export Connections").as_str())
+            .current_name("pods-connections.json")
+            .modal(true);
+
+        let buf = self.connection_manager().export_connections();
+
+        utils::show_save_file_dialog(
+            request,
+            self.upcast_ref(),
+            clone!(@weak self as obj => move |files| {
+                let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                if let Some(path) = file.path() {
+                    utils::do_async(
+                        {
+                            let buf = buf.clone();
+                            async move { tokio::fs::write(path, buf).await }
+                        },
+                        clone!(@weak obj => move |result| if let Err(e) = result {
+                            utils::show_error_toast(
+                                obj.imp().toast_overlay.upcast_ref(),
+                                &gettext("Error on exporting connections"),
+                                &e.to_string(),
+                            );
+                        }),
+                    );
+                }
+            }),
+        )
+        .await;
+    }
+
     pub(crate) fn save_window_size(&self) -> Result<(), glib::BoolError> {
         let (width, height) = self.default_size();
 
@@ -276,6 +737,8 @@ impl Window {
                     model::ClientError::Containers => gettext("Error on loading containers"),
                     model::ClientError::Pods => gettext("Error on loading pods"),
                     model::ClientError::Volumes => gettext("Error on loading volumes"),
+                    model::ClientError::Networks => gettext("Error on loading networks"),
+                    model::ClientError::Secrets => gettext("Error on loading secrets"),
                 })
                 .timeout(3)
                 .priority(adw::ToastPriority::High)
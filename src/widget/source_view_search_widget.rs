@@ -16,6 +16,9 @@ use crate::widget;
 const ACTION_SEARCH_BACKWARDS: &str = "source-view-search-widget.search-backward";
 const ACTION_SEARCH_FORWARD: &str = "source-view-search-widget.search-forward";
 
+/// The category of the source marks that are used as bookmarks.
+const BOOKMARK_CATEGORY: &str = "bookmark";
+
 mod imp {
     use super::*;
 
@@ -158,6 +161,10 @@ mod imp {
                 }));
 
                 self.search_context.replace(Some(search_context));
+
+                let attributes = sourceview5::MarkAttributes::new();
+                attributes.set_icon_name("user-bookmarks-symbolic");
+                source_view.set_mark_attributes(BOOKMARK_CATEGORY, &attributes, 0);
             }
 
             self.source_view.set(value);
@@ -273,4 +280,121 @@ impl SourceViewSearchWidget {
             self.update_search_occurrences();
         }
     }
+
+    /// Adds a bookmark to the line of the cursor or removes it if there is already one.
+    pub(crate) fn toggle_bookmark(&self) {
+        if let Some(source_view) = self.source_view() {
+            let source_buffer = source_buffer(&source_view);
+
+            let line = source_buffer
+                .iter_at_mark(&source_buffer.get_insert())
+                .line();
+            let marks = source_buffer.source_marks_at_line(line, Some(BOOKMARK_CATEGORY));
+
+            if marks.is_empty() {
+                source_view.set_show_line_marks(true);
+                if let Some(iter) = source_buffer.iter_at_line(line) {
+                    source_buffer.create_source_mark(None, BOOKMARK_CATEGORY, &iter);
+                }
+            } else {
+                marks
+                    .iter()
+                    .for_each(|mark| source_buffer.delete_mark(mark));
+            }
+        }
+    }
+
+    pub(crate) fn bookmark_backward(&self) {
+        if let Some(source_view) = self.source_view() {
+            let source_buffer = source_buffer(&source_view);
+
+            let mut iter = source_buffer.iter_at_mark(&source_buffer.get_insert());
+            iter.set_line_offset(0);
+
+            if !source_buffer.backward_iter_to_source_mark(&mut iter, Some(BOOKMARK_CATEGORY)) {
+                iter = source_buffer.end_iter();
+                if !source_buffer.backward_iter_to_source_mark(&mut iter, Some(BOOKMARK_CATEGORY)) {
+                    return;
+                }
+            }
+
+            source_view.scroll_to_iter(&mut iter, 0.0, false, 0.0, 0.0);
+            source_buffer.place_cursor(&iter);
+        }
+    }
+
+    pub(crate) fn bookmark_forward(&self) {
+        if let Some(source_view) = self.source_view() {
+            let source_buffer = source_buffer(&source_view);
+
+            let mut iter = source_buffer.iter_at_mark(&source_buffer.get_insert());
+            iter.set_line_offset(0);
+
+            if !source_buffer.forward_iter_to_source_mark(&mut iter, Some(BOOKMARK_CATEGORY)) {
+                // Wrap around, a bookmark at the very start isn't found by moving forward.
+                iter = source_buffer.start_iter();
+                if source_buffer
+                    .source_marks_at_iter(&iter, Some(BOOKMARK_CATEGORY))
+                    .is_empty()
+                    && !source_buffer
+                        .forward_iter_to_source_mark(&mut iter, Some(BOOKMARK_CATEGORY))
+                {
+                    return;
+                }
+            }
+
+            source_view.scroll_to_iter(&mut iter, 0.0, false, 0.0, 0.0);
+            source_buffer.place_cursor(&iter);
+        }
+    }
+
+    /// Returns the lines that have a bookmark, e.g. to restore them after the text has been
+    /// replaced.
+    pub(crate) fn bookmarked_lines(&self) -> Vec<i32> {
+        self.source_view()
+            .map(|source_view| {
+                let source_buffer = source_buffer(&source_view);
+
+                let mut lines = Vec::new();
+                let mut iter = source_buffer.start_iter();
+                if !source_buffer
+                    .source_marks_at_iter(&iter, Some(BOOKMARK_CATEGORY))
+                    .is_empty()
+                {
+                    lines.push(iter.line());
+                }
+                while source_buffer.forward_iter_to_source_mark(&mut iter, Some(BOOKMARK_CATEGORY))
+                {
+                    lines.push(iter.line());
+                }
+                lines
+            })
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn set_bookmarked_lines(&self, lines: &[i32]) {
+        if let Some(source_view) = self.source_view() {
+            let source_buffer = source_buffer(&source_view);
+
+            source_buffer.remove_source_marks(
+                &source_buffer.start_iter(),
+                &source_buffer.end_iter(),
+                Some(BOOKMARK_CATEGORY),
+            );
+
+            lines
+                .iter()
+                .filter_map(|line| source_buffer.iter_at_line(*line))
+                .for_each(|iter| {
+                    source_buffer.create_source_mark(None, BOOKMARK_CATEGORY, &iter);
+                });
+        }
+    }
+}
+
+fn source_buffer(source_view: &sourceview5::View) -> sourceview5::Buffer {
+    source_view
+        .buffer()
+        .downcast::<sourceview5::Buffer>()
+        .unwrap()
 }
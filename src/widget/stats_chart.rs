@@ -0,0 +1,187 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gtk::gdk;
+use gtk::glib;
+use gtk::graphene;
+use gtk::gsk;
+
+const HEIGHT: i32 = 64;
+const LINE_WIDTH: f32 = 1.5;
+const GRID_LINES: u32 = 4;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct StatsChart {
+        pub(super) range: Cell<(i64, i64)>,
+        pub(super) gap: Cell<i64>,
+        pub(super) primary: RefCell<Vec<(i64, f64)>>,
+        pub(super) secondary: RefCell<Vec<(i64, f64)>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for StatsChart {
+        const NAME: &'static str = "PdsStatsChart";
+        type Type = super::StatsChart;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_css_name("statschart");
+        }
+    }
+
+    impl ObjectImpl for StatsChart {}
+
+    impl WidgetImpl for StatsChart {
+        fn measure(&self, orientation: gtk::Orientation, _for_size: i32) -> (i32, i32, i32, i32) {
+            match orientation {
+                gtk::Orientation::Horizontal => (0, 0, -1, -1),
+                _ => (HEIGHT, HEIGHT, -1, -1),
+            }
+        }
+
+        fn snapshot(&self, snapshot: &gtk::Snapshot) {
+            let widget = &*self.obj();
+
+            let width = widget.width() as f32;
+            let height = widget.height() as f32;
+            if width <= 0.0 || height <= 0.0 {
+                return;
+            }
+
+            let style_manager = adw::StyleManager::default();
+            let style_context = widget.style_context();
+
+            let grid_color = style_context
+                .lookup_color("window_fg_color")
+                .map(|color| gdk::RGBA::new(color.red(), color.green(), color.blue(), 0.1))
+                .unwrap_or_else(|| {
+                    if style_manager.is_dark() {
+                        gdk::RGBA::new(1.0, 1.0, 1.0, 0.1)
+                    } else {
+                        gdk::RGBA::new(0.0, 0.0, 0.0, 0.1)
+                    }
+                });
+            let primary_color = style_context
+                .lookup_color("accent_color")
+                .unwrap_or_else(|| gdk::RGBA::new(0.11, 0.443, 0.847, 1.0));
+            let secondary_color = style_context
+                .lookup_color("success_color")
+                .unwrap_or_else(|| gdk::RGBA::new(0.106, 0.522, 0.325, 1.0));
+
+            (1..GRID_LINES).for_each(|i| {
+                let y = (height * i as f32 / GRID_LINES as f32).round();
+                snapshot.append_color(&grid_color, &graphene::Rect::new(0.0, y, width, 1.0));
+            });
+
+            let primary = self.primary.borrow();
+            let secondary = self.secondary.borrow();
+
+            let max = primary
+                .iter()
+                .chain(secondary.iter())
+                .map(|(_, value)| *value)
+                .fold(0.0, f64::max);
+            if max <= 0.0 {
+                return;
+            }
+
+            let (start, end) = self.range.get();
+            let span = (end - start).max(1) as f32;
+            let gap = self.gap.get();
+
+            let point = |(time, value): (i64, f64)| {
+                (
+                    (time - start) as f32 / span * width,
+                    height - (value / max) as f32 * (height - LINE_WIDTH),
+                )
+            };
+
+            [
+                (&*primary, primary_color, true),
+                (&*secondary, secondary_color, false),
+            ]
+            .into_iter()
+            .filter(|(values, _, _)| !values.is_empty())
+            .for_each(|(values, color, filled)| {
+                let line = gsk::PathBuilder::new();
+                let area = gsk::PathBuilder::new();
+
+                values
+                    .iter()
+                    .fold(None::<(i64, f32)>, |prev, &(time, value)| {
+                        let (x, y) = point((time, value));
+
+                        match prev {
+                            Some((prev_time, _)) if time - prev_time <= gap => {
+                                line.line_to(x, y);
+                                area.line_to(x, y);
+                            }
+                            _ => {
+                                if let Some((_, prev_x)) = prev {
+                                    area.line_to(prev_x, height);
+                                    area.close();
+                                }
+                                line.move_to(x, y);
+                                area.move_to(x, height);
+                                area.line_to(x, y);
+                            }
+                        }
+
+                        Some((time, x))
+                    })
+                    .into_iter()
+                    .for_each(|(_, x)| {
+                        area.line_to(x, height);
+                        area.close();
+                    });
+
+                if filled {
+                    snapshot.append_fill(
+                        &area.to_path(),
+                        gsk::FillRule::Winding,
+                        &gdk::RGBA::new(color.red(), color.green(), color.blue(), 0.25),
+                    );
+                }
+                snapshot.append_stroke(&line.to_path(), &gsk::Stroke::new(LINE_WIDTH), &color);
+            });
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct StatsChart(ObjectSubclass<imp::StatsChart>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for StatsChart {
+    fn default() -> Self {
+        glib::Object::builder().build()
+    }
+}
+
+impl StatsChart {
+    /// Sets the values to draw between `start` and `end`. Consecutive values that are more than
+    /// `gap` seconds apart are not connected, e.g. while a container was stopped.
+    pub(crate) fn set_values(
+        &self,
+        (start, end): (i64, i64),
+        gap: i64,
+        primary: Vec<(i64, f64)>,
+        secondary: Vec<(i64, f64)>,
+    ) {
+        let imp = self.imp();
+
+        imp.range.set((start, end));
+        imp.gap.set(gap);
+        imp.primary.replace(primary);
+        imp.secondary.replace(secondary);
+
+        self.queue_draw();
+    }
+}